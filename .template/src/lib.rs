@@ -0,0 +1,20 @@
+use advent::prelude::*;
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(input.lines().map(str::to_string).collect())
+    }
+
+    fn part1(_parsed: &Self::Parsed) -> Result<Answer> {
+        Ok(0.into())
+    }
+
+    fn part2(_parsed: &Self::Parsed) -> Result<Answer> {
+        Ok(0.into())
+    }
+}
+
@@ -1,38 +0,0 @@
-use advent::prelude::*;
-
-const INPUT: &str = include_str!("../input.txt");
-
-fn main() -> Result<()> {
-    println!("## Part 1");
-    println!(" > {}", part1(INPUT)?);
-
-    println!("## Part 2");
-    println!(" > {}", part2(INPUT)?);
-
-    Ok(())
-}
-
-fn part1(input: &str) -> Result<u32> {
-    Ok(0)
-}
-
-fn part2(input: &str) -> Result<u32> {
-    Ok(0)
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const TEST_INPUT: &str = include_str!("../test.txt");
-
-    #[test]
-    fn test_part1() {
-        assert_eq!(part1(TEST_INPUT).unwrap(), 0);
-    }
-
-    #[test]
-    fn test_part2() {
-        assert_eq!(part2(TEST_INPUT).unwrap(), 0);
-    }
-}
@@ -0,0 +1,206 @@
+use crate::prelude::Result;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+
+/// A sequence of values that eventually repeats
+///
+/// The first `start` values (mu) lead into the cycle, which then repeats every `length` (lambda)
+/// steps forever
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle<T> {
+    pub start: usize,
+    pub length: usize,
+    history: Vec<T>,
+}
+
+impl<T> Cycle<T> {
+    /// The earliest step that is equivalent to step `n`
+    pub fn index(&self, n: usize) -> usize {
+        reduce(self.start, self.length, n)
+    }
+
+    /// The value at step `n`, without having to simulate up to it
+    pub fn at(&self, n: usize) -> &T {
+        &self.history[self.index(n)]
+    }
+
+    /// All the values from the first step up to the end of the first pass through the cycle
+    pub fn history(&self) -> &[T] {
+        &self.history
+    }
+}
+
+fn reduce(start: usize, length: usize, n: usize) -> usize {
+    if n < start {
+        n
+    } else {
+        start + (n - start) % length
+    }
+}
+
+/// Hash a value down to a u64
+///
+/// Useful as a fingerprint for [`find_cycle_by`] when states are too large to keep around
+pub fn fingerprint<T: Hash>(value: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Find the cycle reached by repeatedly applying `step` to `initial`
+///
+/// Every state is stored, so [`Cycle::at`] returns the state after any number of steps
+pub fn find_cycle<S>(initial: S, step: impl FnMut(&S) -> Result<S>) -> Result<Cycle<S>>
+where
+    S: Clone + Eq + Hash,
+{
+    find_cycle_by(initial, step, S::clone, S::clone)
+}
+
+/// Find the cycle reached by repeatedly applying `step` to `initial`, without storing the states
+///
+/// States are identified by their `fingerprint` and only the `metric` of each state is stored, so
+/// [`Cycle::at`] returns the metric after any number of steps
+pub fn find_cycle_by<S, K, T>(
+    initial: S,
+    mut step: impl FnMut(&S) -> Result<S>,
+    mut fingerprint: impl FnMut(&S) -> K,
+    mut metric: impl FnMut(&S) -> T,
+) -> Result<Cycle<T>>
+where
+    K: Eq + Hash,
+{
+    let mut seen: HashMap<K, usize> = HashMap::new();
+    let mut history = Vec::new();
+    let mut state = initial;
+
+    loop {
+        let key = fingerprint(&state);
+        if let Some(&start) = seen.get(&key) {
+            return Ok(Cycle {
+                start,
+                length: history.len() - start,
+                history,
+            });
+        }
+        seen.insert(key, history.len());
+        history.push(metric(&state));
+        state = step(&state)?;
+    }
+}
+
+/// Find the start (mu) and length (lambda) of a cycle with Brent's algorithm
+///
+/// Only a couple of states are kept in memory at any time, at the cost of running the step
+/// function more often than [`find_cycle`] does
+pub fn brent<S>(initial: &S, mut step: impl FnMut(&S) -> Result<S>) -> Result<(usize, usize)>
+where
+    S: Clone + PartialEq,
+{
+    // Find the length by letting the hare run ahead in increasing powers of two, until it meets
+    // the tortoise waiting at the start of the current power
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(initial)?;
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = step(&hare)?;
+        length += 1;
+    }
+
+    // With the hare a full cycle ahead, they meet at the start of the cycle
+    let mut tortoise = initial.clone();
+    let mut hare = initial.clone();
+    for _ in 0..length {
+        hare = step(&hare)?;
+    }
+    let mut start = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise)?;
+        hare = step(&hare)?;
+        start += 1;
+    }
+
+    Ok((start, length))
+}
+
+/// The state after `n` steps, simulating at most until the first repeat of the cycle
+pub fn state_at<S>(initial: S, mut step: impl FnMut(&S) -> Result<S>, n: usize) -> Result<S>
+where
+    S: Clone + PartialEq,
+{
+    let (start, length) = brent(&initial, &mut step)?;
+
+    let mut state = initial;
+    for _ in 0..reduce(start, length, n) {
+        state = step(&state)?;
+    }
+
+    Ok(state)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error_handling::AdventError;
+
+    // 0 -> 1 -> 2 -> 3 -> 4 -> 5 -> 2 -> ...
+    fn step(n: &u32) -> Result<u32> {
+        Ok(if *n == 5 { 2 } else { n + 1 })
+    }
+
+    #[test]
+    fn test_find_cycle() {
+        let cycle = find_cycle(0, step).unwrap();
+
+        assert_eq!(cycle.start, 2);
+        assert_eq!(cycle.length, 4);
+        assert_eq!(*cycle.at(0), 0);
+        assert_eq!(*cycle.at(5), 5);
+        assert_eq!(*cycle.at(6), 2);
+        assert_eq!(*cycle.at(1_000_000_001), 5);
+        assert_eq!(cycle.index(10), 2);
+        assert_eq!(cycle.history(), &[0, 1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_find_cycle_by() {
+        let cycle = find_cycle_by(0, step, fingerprint, |n| n * 10).unwrap();
+
+        assert_eq!((cycle.start, cycle.length), (2, 4));
+        assert_eq!(*cycle.at(7), 30);
+    }
+
+    #[test]
+    fn test_brent() {
+        assert_eq!(brent(&0, step).unwrap(), (2, 4));
+        assert_eq!(brent(&3, step).unwrap(), (0, 4));
+        assert_eq!(brent(&7, |_| Ok(7)).unwrap(), (0, 1));
+    }
+
+    #[test]
+    fn test_state_at() {
+        assert_eq!(state_at(0, step, 1).unwrap(), 1);
+        assert_eq!(state_at(0, step, 1_000_000_000).unwrap(), 4);
+    }
+
+    #[test]
+    fn test_step_error() {
+        let failing = |n: &u32| {
+            if *n == 3 {
+                Err(error!("Step failed"))
+            } else {
+                step(n)
+            }
+        };
+
+        assert!(find_cycle(0, failing).is_err());
+        assert!(brent(&0, failing).is_err());
+    }
+}
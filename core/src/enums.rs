@@ -1,4 +1,11 @@
-#[derive(Debug, Ord, PartialOrd, Eq, PartialEq, Hash, Clone)]
+/// Anything that moves a point a single step on a grid
+///
+/// The delta is (row, col), where north/up is a negative row
+pub trait Delta {
+    fn delta(&self) -> (isize, isize);
+}
+
+#[derive(Debug, Ord, PartialOrd, Eq, PartialEq, Hash, Clone, Copy)]
 pub enum CardinalDirection {
     North,
     South,
@@ -6,7 +13,7 @@ pub enum CardinalDirection {
     East,
 }
 
-#[derive(Debug, Ord, PartialOrd, Eq, PartialEq, Hash, Clone)]
+#[derive(Debug, Ord, PartialOrd, Eq, PartialEq, Hash, Clone, Copy)]
 pub enum OrdinalDirection {
     Up,
     Down,
@@ -14,7 +21,28 @@ pub enum OrdinalDirection {
     Right,
 }
 
+/// The eight directions of a compass, including the diagonals
+#[derive(Debug, Ord, PartialOrd, Eq, PartialEq, Hash, Clone, Copy)]
+pub enum CompassDirection {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
 impl CardinalDirection {
+    /// All directions, clockwise from north
+    pub const ALL: [CardinalDirection; 4] = [
+        CardinalDirection::North,
+        CardinalDirection::East,
+        CardinalDirection::South,
+        CardinalDirection::West,
+    ];
+
     pub fn opposite(&self) -> Self {
         match self {
             CardinalDirection::North => CardinalDirection::South,
@@ -23,9 +51,54 @@ impl CardinalDirection {
             CardinalDirection::East => CardinalDirection::West,
         }
     }
+
+    /// Turn 90 degrees counter clockwise
+    pub fn turn_left(&self) -> Self {
+        match self {
+            CardinalDirection::North => CardinalDirection::West,
+            CardinalDirection::West => CardinalDirection::South,
+            CardinalDirection::South => CardinalDirection::East,
+            CardinalDirection::East => CardinalDirection::North,
+        }
+    }
+
+    /// Turn 90 degrees clockwise
+    pub fn turn_right(&self) -> Self {
+        self.turn_left().opposite()
+    }
+
+    /// From a single character, N/S/E/W
+    pub fn from_nsew(c: &str) -> Option<Self> {
+        match c {
+            "N" => Some(CardinalDirection::North),
+            "S" => Some(CardinalDirection::South),
+            "E" => Some(CardinalDirection::East),
+            "W" => Some(CardinalDirection::West),
+            _ => None,
+        }
+    }
+}
+
+impl Delta for CardinalDirection {
+    fn delta(&self) -> (isize, isize) {
+        match self {
+            CardinalDirection::North => (-1, 0),
+            CardinalDirection::South => (1, 0),
+            CardinalDirection::West => (0, -1),
+            CardinalDirection::East => (0, 1),
+        }
+    }
 }
 
 impl OrdinalDirection {
+    /// All directions, clockwise from up
+    pub const ALL: [OrdinalDirection; 4] = [
+        OrdinalDirection::Up,
+        OrdinalDirection::Right,
+        OrdinalDirection::Down,
+        OrdinalDirection::Left,
+    ];
+
     pub fn opposite(&self) -> Self {
         match self {
             OrdinalDirection::Up => OrdinalDirection::Down,
@@ -35,6 +108,16 @@ impl OrdinalDirection {
         }
     }
 
+    /// Turn 90 degrees counter clockwise
+    pub fn turn_left(&self) -> Self {
+        CardinalDirection::from(*self).turn_left().into()
+    }
+
+    /// Turn 90 degrees clockwise
+    pub fn turn_right(&self) -> Self {
+        CardinalDirection::from(*self).turn_right().into()
+    }
+
     /// From a single character, U/D/L/R
     pub fn from_udlr(c: &str) -> Option<Self> {
         match c {
@@ -46,14 +129,243 @@ impl OrdinalDirection {
         }
     }
 
-    /// From a single symbol, ^/v/</>
+    /// From a single symbol, ^/v/</> or one of the ↑/↓/←/→ arrows
     pub fn from_symbol(s: &str) -> Option<Self> {
         match s {
-            "^" => Some(OrdinalDirection::Up),
-            "v" => Some(OrdinalDirection::Down),
-            "<" => Some(OrdinalDirection::Left),
-            ">" => Some(OrdinalDirection::Right),
+            "^" | "↑" => Some(OrdinalDirection::Up),
+            "v" | "↓" => Some(OrdinalDirection::Down),
+            "<" | "←" => Some(OrdinalDirection::Left),
+            ">" | "→" => Some(OrdinalDirection::Right),
+            _ => None,
+        }
+    }
+
+    /// From a single digit, where 0 = R, 1 = D, 2 = L and 3 = U
+    pub fn from_digit(s: &str) -> Option<Self> {
+        match s {
+            "0" => Some(OrdinalDirection::Right),
+            "1" => Some(OrdinalDirection::Down),
+            "2" => Some(OrdinalDirection::Left),
+            "3" => Some(OrdinalDirection::Up),
+            _ => None,
+        }
+    }
+}
+
+impl Delta for OrdinalDirection {
+    fn delta(&self) -> (isize, isize) {
+        CardinalDirection::from(*self).delta()
+    }
+}
+
+impl From<OrdinalDirection> for CardinalDirection {
+    fn from(direction: OrdinalDirection) -> Self {
+        match direction {
+            OrdinalDirection::Up => CardinalDirection::North,
+            OrdinalDirection::Down => CardinalDirection::South,
+            OrdinalDirection::Left => CardinalDirection::West,
+            OrdinalDirection::Right => CardinalDirection::East,
+        }
+    }
+}
+
+impl From<CardinalDirection> for OrdinalDirection {
+    fn from(direction: CardinalDirection) -> Self {
+        match direction {
+            CardinalDirection::North => OrdinalDirection::Up,
+            CardinalDirection::South => OrdinalDirection::Down,
+            CardinalDirection::West => OrdinalDirection::Left,
+            CardinalDirection::East => OrdinalDirection::Right,
+        }
+    }
+}
+
+impl CompassDirection {
+    /// All directions, clockwise from north
+    pub const ALL: [CompassDirection; 8] = [
+        CompassDirection::North,
+        CompassDirection::NorthEast,
+        CompassDirection::East,
+        CompassDirection::SouthEast,
+        CompassDirection::South,
+        CompassDirection::SouthWest,
+        CompassDirection::West,
+        CompassDirection::NorthWest,
+    ];
+
+    fn index(&self) -> usize {
+        Self::ALL
+            .iter()
+            .position(|direction| direction == self)
+            .expect("All directions are listed in ALL")
+    }
+
+    /// Rotate clockwise by the given number of 45 degree steps
+    fn rotate(&self, steps: usize) -> Self {
+        Self::ALL[(self.index() + steps) % Self::ALL.len()]
+    }
+
+    pub fn opposite(&self) -> Self {
+        self.rotate(4)
+    }
+
+    /// Turn 90 degrees counter clockwise
+    pub fn turn_left(&self) -> Self {
+        self.rotate(6)
+    }
+
+    /// Turn 90 degrees clockwise
+    pub fn turn_right(&self) -> Self {
+        self.rotate(2)
+    }
+
+    pub fn is_diagonal(&self) -> bool {
+        self.index() % 2 == 1
+    }
+
+    /// From a compass abbreviation, such as N or SW
+    pub fn from_abbreviation(s: &str) -> Option<Self> {
+        match s {
+            "N" => Some(CompassDirection::North),
+            "NE" => Some(CompassDirection::NorthEast),
+            "E" => Some(CompassDirection::East),
+            "SE" => Some(CompassDirection::SouthEast),
+            "S" => Some(CompassDirection::South),
+            "SW" => Some(CompassDirection::SouthWest),
+            "W" => Some(CompassDirection::West),
+            "NW" => Some(CompassDirection::NorthWest),
             _ => None,
         }
     }
 }
+
+impl Delta for CompassDirection {
+    fn delta(&self) -> (isize, isize) {
+        match self {
+            CompassDirection::North => (-1, 0),
+            CompassDirection::NorthEast => (-1, 1),
+            CompassDirection::East => (0, 1),
+            CompassDirection::SouthEast => (1, 1),
+            CompassDirection::South => (1, 0),
+            CompassDirection::SouthWest => (1, -1),
+            CompassDirection::West => (0, -1),
+            CompassDirection::NorthWest => (-1, -1),
+        }
+    }
+}
+
+impl From<CardinalDirection> for CompassDirection {
+    fn from(direction: CardinalDirection) -> Self {
+        match direction {
+            CardinalDirection::North => CompassDirection::North,
+            CardinalDirection::South => CompassDirection::South,
+            CardinalDirection::West => CompassDirection::West,
+            CardinalDirection::East => CompassDirection::East,
+        }
+    }
+}
+
+impl From<OrdinalDirection> for CompassDirection {
+    fn from(direction: OrdinalDirection) -> Self {
+        CardinalDirection::from(direction).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cardinal_turns() {
+        for direction in CardinalDirection::ALL {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(direction.turn_right().turn_right(), direction.opposite());
+        }
+        assert_eq!(
+            CardinalDirection::North.turn_right(),
+            CardinalDirection::East
+        );
+        assert_eq!(
+            CardinalDirection::North.turn_left(),
+            CardinalDirection::West
+        );
+    }
+
+    #[test]
+    fn test_ordinal_turns() {
+        assert_eq!(OrdinalDirection::Up.turn_right(), OrdinalDirection::Right);
+        assert_eq!(OrdinalDirection::Left.turn_left(), OrdinalDirection::Down);
+    }
+
+    #[test]
+    fn test_compass_turns() {
+        assert_eq!(
+            CompassDirection::NorthEast.turn_right(),
+            CompassDirection::SouthEast
+        );
+        assert_eq!(CompassDirection::North.turn_left(), CompassDirection::West);
+        assert_eq!(
+            CompassDirection::SouthWest.opposite(),
+            CompassDirection::NorthEast
+        );
+        assert!(CompassDirection::NorthWest.is_diagonal());
+        assert!(!CompassDirection::South.is_diagonal());
+    }
+
+    #[test]
+    fn test_deltas() {
+        let sum = CompassDirection::ALL
+            .iter()
+            .map(|direction| direction.delta())
+            .fold((0, 0), |acc, (dr, dc)| (acc.0 + dr, acc.1 + dc));
+        assert_eq!(sum, (0, 0));
+
+        for direction in CardinalDirection::ALL {
+            assert_eq!(direction.delta(), OrdinalDirection::from(direction).delta());
+            assert_eq!(direction.delta(), CompassDirection::from(direction).delta());
+        }
+    }
+
+    #[test]
+    fn test_conversions() {
+        for direction in CardinalDirection::ALL {
+            assert_eq!(
+                CardinalDirection::from(OrdinalDirection::from(direction)),
+                direction
+            );
+        }
+    }
+
+    #[test]
+    fn test_parsers() {
+        assert_eq!(
+            CardinalDirection::from_nsew("E"),
+            Some(CardinalDirection::East)
+        );
+        assert_eq!(CardinalDirection::from_nsew("U"), None);
+
+        assert_eq!(
+            OrdinalDirection::from_udlr("L"),
+            Some(OrdinalDirection::Left)
+        );
+        assert_eq!(
+            OrdinalDirection::from_symbol("v"),
+            Some(OrdinalDirection::Down)
+        );
+        assert_eq!(
+            OrdinalDirection::from_symbol("→"),
+            Some(OrdinalDirection::Right)
+        );
+        assert_eq!(
+            OrdinalDirection::from_digit("3"),
+            Some(OrdinalDirection::Up)
+        );
+        assert_eq!(OrdinalDirection::from_digit("4"), None);
+
+        assert_eq!(
+            CompassDirection::from_abbreviation("SW"),
+            Some(CompassDirection::SouthWest)
+        );
+        assert_eq!(CompassDirection::from_abbreviation("X"), None);
+    }
+}
@@ -1,3 +1,7 @@
+use std::fmt;
+use std::ops::Range;
+use std::path::PathBuf;
+use std::str::FromStr;
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -18,6 +22,233 @@ pub enum AdventError {
     ParseIntError(#[from] std::num::ParseIntError),
     #[error("Invalid coordinate: ({row}, {col})")]
     InvalidCoordinate { row: usize, col: usize },
+    #[error("No input found for day {day} at {}", path.display())]
+    MissingInput { day: u32, path: PathBuf },
+    #[error("The session token was rejected, it has probably expired")]
+    SessionExpired,
+    #[error("Day {day} hasn't been released yet")]
+    Unreleased { day: u32 },
+    #[error("IO error: {0}")]
+    IoError(#[from] std::io::Error),
+    #[error("{source} at {span}")]
+    Located {
+        span: Span,
+        source: Box<AdventError>,
+    },
+    #[error("{context}: {source}")]
+    Context {
+        context: String,
+        source: Box<AdventError>,
+    },
+}
+
+/// Where in the input something went wrong
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Span {
+    /// The line number, starting from 1
+    pub line: usize,
+    /// The character columns within the line, starting from 0
+    pub columns: Range<usize>,
+    /// The whole line the span is on
+    pub text: String,
+}
+
+impl Span {
+    pub fn new(line: usize, columns: Range<usize>, text: impl Into<String>) -> Self {
+        Self {
+            line,
+            columns,
+            text: text.into(),
+        }
+    }
+
+    /// A span covering a whole line
+    pub fn line(line: usize, text: &str) -> Self {
+        Self::new(line, 0..text.chars().count(), text)
+    }
+
+    /// Find the span of `fragment`, which has to be a slice of `input`
+    ///
+    /// The position is worked out from where the fragment is in memory, so this returns None for a
+    /// string that just happens to be equal to part of the input
+    pub fn locate(input: &str, fragment: &str) -> Option<Self> {
+        let start = (fragment.as_ptr() as usize).checked_sub(input.as_ptr() as usize)?;
+        if start + fragment.len() > input.len() {
+            return None;
+        }
+
+        let before = &input[..start];
+        let line_start = before.rfind('\n').map_or(0, |idx| idx + 1);
+        let line_end = input[start..]
+            .find('\n')
+            .map_or(input.len(), |idx| start + idx);
+        let text = input[line_start..line_end].trim_end_matches('\r');
+
+        // A fragment spanning multiple lines is cut off at the end of the first one
+        let column = before[line_start..].chars().count();
+        let length = fragment.lines().next().unwrap_or("").chars().count();
+
+        Some(Self::new(
+            before.matches('\n').count() + 1,
+            column..column + length,
+            text,
+        ))
+    }
+}
+
+impl fmt::Display for Span {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, columns {}-{}",
+            self.line,
+            self.columns.start + 1,
+            self.columns.end.max(self.columns.start + 1)
+        )
+    }
+}
+
+impl AdventError {
+    /// Point the error at a span of the input
+    ///
+    /// An error that already has a span keeps it, since the innermost span is the most precise
+    pub fn with_span(self, span: Span) -> Self {
+        match self {
+            AdventError::Context { context, source } => AdventError::Context {
+                context,
+                source: Box::new(source.with_span(span)),
+            },
+            AdventError::Located { .. } => self,
+            _ => AdventError::Located {
+                span,
+                source: Box::new(self),
+            },
+        }
+    }
+
+    /// Point the error at `fragment`, which has to be a slice of `input`
+    pub fn locate(self, input: &str, fragment: &str) -> Self {
+        match Span::locate(input, fragment) {
+            Some(span) => self.with_span(span),
+            None => self,
+        }
+    }
+
+    /// Move the error from parsing `fragment` on its own to where the fragment is in `input`
+    ///
+    /// Any span the error has is shifted along with the fragment, and errors without a span get
+    /// one covering the whole fragment
+    pub fn relocate(self, input: &str, fragment: &str) -> Self {
+        match Span::locate(input, fragment) {
+            Some(outer) => self.shift(outer),
+            None => self,
+        }
+    }
+
+    fn shift(self, outer: Span) -> Self {
+        match self {
+            AdventError::Context { context, source } => AdventError::Context {
+                context,
+                source: Box::new(source.shift(outer)),
+            },
+            AdventError::Located { mut span, source } => {
+                // Only the first line of the fragment can start part way into a line
+                if span.line == 1 {
+                    let offset = outer.columns.start;
+                    span.columns = span.columns.start + offset..span.columns.end + offset;
+                    span.text = outer.text;
+                }
+                span.line += outer.line - 1;
+                AdventError::Located { span, source }
+            }
+            _ => self.with_span(outer),
+        }
+    }
+
+    /// Add context to the error, describing what was being done when it happened
+    pub fn context(self, context: impl Into<String>) -> Self {
+        AdventError::Context {
+            context: context.into(),
+            source: Box::new(self),
+        }
+    }
+
+    /// The span of the input the error points at, if any
+    pub fn span(&self) -> Option<&Span> {
+        match self {
+            AdventError::Located { span, .. } => Some(span),
+            AdventError::Context { source, .. } => source.span(),
+            _ => None,
+        }
+    }
+
+    /// Render the error, with a snippet of the offending line and a caret under the span
+    ///
+    /// ```text
+    /// error: Unable to parse: Unknown colour: purple at line 2, columns 11-16
+    ///   |
+    /// 2 | Game 2: 1 purple
+    ///   |           ^^^^^^
+    /// ```
+    pub fn render(&self) -> String {
+        let mut output = format!("error: {}", self);
+
+        if let Some(span) = self.span() {
+            let gutter = " ".repeat(span.line.to_string().len());
+            let carets = "^".repeat(span.columns.len().max(1));
+            output.push_str(&format!(
+                "\n{} |\n{} | {}\n{} | {}{}",
+                gutter,
+                span.line,
+                span.text,
+                gutter,
+                " ".repeat(span.columns.start),
+                carets
+            ));
+        }
+
+        output
+    }
+}
+
+/// Add context to the error of a result, in the style of `.context("parsing workflow")`
+pub trait Context<T> {
+    fn context(self, context: impl Into<String>) -> Result<T, AdventError>;
+
+    /// Like [`Context::context`], but only builds the context if there is an error
+    fn with_context<C: Into<String>>(self, f: impl FnOnce() -> C) -> Result<T, AdventError>;
+}
+
+impl<T, E: Into<AdventError>> Context<T> for Result<T, E> {
+    fn context(self, context: impl Into<String>) -> Result<T, AdventError> {
+        self.map_err(|e| e.into().context(context))
+    }
+
+    fn with_context<C: Into<String>>(self, f: impl FnOnce() -> C) -> Result<T, AdventError> {
+        self.map_err(|e| e.into().context(f()))
+    }
+}
+
+/// Parse each line of the input, pointing any error at the line it came from
+pub fn parse_lines<T, E: Into<AdventError>>(
+    input: &str,
+    mut parse: impl FnMut(&str) -> Result<T, E>,
+) -> Result<Vec<T>, AdventError> {
+    input
+        .lines()
+        .map(|line| parse(line).map_err(|e| e.into().relocate(input, line)))
+        .collect()
+}
+
+/// Parse `fragment`, a slice of `input`, pointing any error at where it is in the input
+pub fn parse_at<T>(input: &str, fragment: &str) -> Result<T, AdventError>
+where
+    T: FromStr,
+    T::Err: Into<AdventError>,
+{
+    fragment
+        .parse()
+        .map_err(|e: T::Err| e.into().locate(input, fragment))
 }
 
 #[macro_export]
@@ -27,11 +258,15 @@ macro_rules! invalid_coordinate {
     };
 }
 
+/// Create a parse error, optionally pointing at `fragment`, a slice of `input`
 #[macro_export]
 macro_rules! parse_error {
     ($e:expr) => {
         AdventError::ParseError($e.to_string())
     };
+    ($e:expr, $input:expr, $fragment:expr) => {
+        AdventError::ParseError($e.to_string()).locate($input, $fragment)
+    };
 }
 
 #[macro_export]
@@ -40,3 +275,103 @@ macro_rules! error {
         AdventError::Error(format!($($t)*))
     };
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_span_locate() {
+        let input = "first line\nsecond line\r\nthird";
+        let fragment = &input[18..22];
+
+        assert_eq!(fragment, "line");
+        assert_eq!(
+            Span::locate(input, fragment),
+            Some(Span::new(2, 7..11, "second line"))
+        );
+        assert_eq!(
+            Span::locate(input, &input[24..]),
+            Some(Span::new(3, 0..5, "third"))
+        );
+        assert_eq!(Span::locate(input, "line"), None);
+    }
+
+    #[test]
+    fn test_parse_error_span() {
+        let input = "a: 1\nb: x";
+        let err = parse_error!("Not a number", input, &input[8..9]);
+
+        assert_eq!(err.span(), Some(&Span::new(2, 3..4, "b: x")));
+        assert_eq!(
+            err.to_string(),
+            "Unable to parse: Not a number at line 2, columns 4-4"
+        );
+        assert!(parse_error!("No span").span().is_none());
+    }
+
+    #[test]
+    fn test_context() {
+        let result: Result<u32, _> = "x".parse::<u32>();
+        let err = result.context("parsing number").unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "parsing number: Unable to parse: invalid digit found in string"
+        );
+
+        let ok: Result<u32, AdventError> = Ok(1);
+        assert_eq!(ok.with_context(|| -> String { unreachable!() }).unwrap(), 1);
+    }
+
+    #[test]
+    fn test_parse_lines() {
+        let input = "1 2\n3 x\n5 6";
+        let err = parse_lines(input, |line| {
+            line.split(' ')
+                .map(|n| parse_at::<u32>(line, n))
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .unwrap_err();
+
+        assert_eq!(err.span(), Some(&Span::new(2, 2..3, "3 x")));
+
+        let err = parse_lines(input, |line| line.replace(' ', "").parse::<u32>())
+            .context("parsing numbers")
+            .unwrap_err();
+        assert_eq!(err.span(), Some(&Span::line(2, "3 x")));
+
+        assert_eq!(parse_lines("1\n2", str::parse::<u32>).unwrap(), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_relocate() {
+        let input = "name: a=1,b=x\nother: c=3";
+        let fragment = &input[6..13];
+        let value = &fragment[6..7];
+
+        let err = parse_error!("Not a number", fragment, value).relocate(input, fragment);
+        assert_eq!(err.span(), Some(&Span::new(1, 12..13, "name: a=1,b=x")));
+
+        let err = error!("Bad fragment").relocate(input, &input[21..]);
+        assert_eq!(err.span(), Some(&Span::new(2, 7..10, "other: c=3")));
+    }
+
+    #[test]
+    fn test_render() {
+        let input = "Game 1: 3 blue\nGame 2: 1 purple";
+        let err = parse_error!("Unknown colour", input, &input[25..]).context("parsing game");
+
+        assert_eq!(
+            err.render(),
+            [
+                "error: parsing game: Unable to parse: Unknown colour at line 2, columns 11-16",
+                "  |",
+                "2 | Game 2: 1 purple",
+                "  |           ^^^^^^",
+            ]
+            .join("\n")
+        );
+        assert_eq!(error!("Oops").render(), "error: Error: Oops");
+    }
+}
@@ -0,0 +1,342 @@
+use crate::enums::Delta;
+use crate::math::gcd;
+use crate::point::Point;
+
+/// Where a point is in relation to a polygon
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Location {
+    Inside,
+    Boundary,
+    Outside,
+}
+
+/// A simple polygon on integer coordinates
+///
+/// The vertices are listed in order around the polygon, either clockwise or counter clockwise,
+/// and the last vertex connects back to the first one
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Polygon {
+    vertices: Vec<Point<i64>>,
+}
+
+impl Polygon {
+    pub fn new(vertices: Vec<Point<i64>>) -> Self {
+        Self { vertices }
+    }
+
+    /// Trace out a polygon by following instructions of a direction and a distance
+    ///
+    /// A vertex is added where each instruction ends, so the instructions should lead back to
+    /// the start
+    pub fn from_instructions<D: Delta>(
+        start: Point<i64>,
+        instructions: impl IntoIterator<Item = (D, i64)>,
+    ) -> Self {
+        Self::checked_from_instructions(start, instructions).expect("Polygon vertex overflowed")
+    }
+
+    /// Trace out a polygon like `from_instructions`, or None if a vertex doesn't fit in an i64
+    pub fn checked_from_instructions<D: Delta>(
+        start: Point<i64>,
+        instructions: impl IntoIterator<Item = (D, i64)>,
+    ) -> Option<Self> {
+        let mut current = start;
+        let vertices = instructions
+            .into_iter()
+            .map(|(direction, distance)| {
+                let (dr, dc) = direction.delta();
+                let step = |from: i64, delta: isize| {
+                    (delta as i64).checked_mul(distance)?.checked_add(from)
+                };
+                current = Point::new(step(current.row, dr)?, step(current.col, dc)?);
+                Some(current)
+            })
+            .collect::<Option<_>>()?;
+
+        Some(Self::new(vertices))
+    }
+
+    pub fn vertices(&self) -> &[Point<i64>] {
+        &self.vertices
+    }
+
+    /// All the edges of the polygon, including the one from the last vertex back to the first
+    pub fn edges(&self) -> impl Iterator<Item = (Point<i64>, Point<i64>)> + '_ {
+        self.vertices
+            .iter()
+            .zip(self.vertices.iter().cycle().skip(1))
+            .map(|(a, b)| (*a, *b))
+    }
+
+    /// Twice the signed area, using the shoelace formula
+    ///
+    /// The area of a polygon on integer coordinates can be a half, so doubling it keeps it an
+    /// integer. The sign depends on which way around the vertices go.
+    pub fn double_signed_area(&self) -> i64 {
+        self.checked_double_signed_area()
+            .expect("Polygon area overflowed")
+    }
+
+    /// Twice the signed area, or None if it overflows along the way
+    pub fn checked_double_signed_area(&self) -> Option<i64> {
+        self.edges().try_fold(0i64, |sum, (a, b)| {
+            let cross = a
+                .col
+                .checked_mul(b.row)?
+                .checked_sub(b.col.checked_mul(a.row)?)?;
+            sum.checked_add(cross)
+        })
+    }
+
+    /// The area of the polygon, rounded down if it is a half
+    pub fn area(&self) -> i64 {
+        self.double_signed_area().abs() / 2
+    }
+
+    /// The length of the boundary, measured along the grid
+    pub fn perimeter(&self) -> i64 {
+        self.edges().map(|(a, b)| a.manhattan(b)).sum()
+    }
+
+    /// The number of integer points on the boundary of the polygon
+    pub fn boundary_points(&self) -> i64 {
+        self.checked_boundary_points()
+            .expect("Polygon boundary overflowed")
+    }
+
+    /// The number of integer points on the boundary, or None if it overflows
+    pub fn checked_boundary_points(&self) -> Option<i64> {
+        self.edges().try_fold(0i64, |sum, (a, b)| {
+            let points = gcd(a.row.abs_diff(b.row), a.col.abs_diff(b.col));
+            sum.checked_add(i64::try_from(points).ok()?)
+        })
+    }
+
+    /// The number of integer points strictly inside the polygon
+    ///
+    /// Pick's theorem relates the area A to the number of points inside, i, and on the boundary,
+    /// b, as A = i + b / 2 - 1
+    pub fn interior_points(&self) -> i64 {
+        self.checked_interior_points()
+            .expect("Polygon interior overflowed")
+    }
+
+    /// The number of integer points strictly inside, or None if it overflows
+    pub fn checked_interior_points(&self) -> Option<i64> {
+        let area = self.checked_double_signed_area()?.checked_abs()?;
+        Some(
+            area.checked_sub(self.checked_boundary_points()?)?
+                .checked_add(2)?
+                / 2,
+        )
+    }
+
+    /// The number of integer points inside the polygon or on its boundary
+    pub fn lattice_points(&self) -> i64 {
+        self.checked_lattice_points()
+            .expect("Polygon lattice points overflowed")
+    }
+
+    /// The number of integer points inside or on the boundary, or None if it overflows
+    pub fn checked_lattice_points(&self) -> Option<i64> {
+        self.checked_interior_points()?
+            .checked_add(self.checked_boundary_points()?)
+    }
+
+    /// Find out if a point is inside, outside or on the boundary of the polygon
+    ///
+    /// Casts a ray from the point towards increasing columns and counts how many edges it
+    /// crosses, where an odd number means the point is inside
+    pub fn locate(&self, point: Point<i64>) -> Location {
+        self.checked_locate(point)
+            .expect("Polygon location overflowed")
+    }
+
+    /// Find out where a point is in relation to the polygon, or None if it overflows
+    pub fn checked_locate(&self, point: Point<i64>) -> Option<Location> {
+        let mut inside = false;
+
+        for (a, b) in self.edges() {
+            if on_segment(point, a, b)? {
+                return Some(Location::Boundary);
+            }
+            if (a.row > point.row) == (b.row > point.row) {
+                // The edge is entirely above or below the ray
+                continue;
+            }
+
+            // The ray crosses if the point is to the left of where the edge meets its row, which
+            // is compared with multiplication to stay in integers
+            let lhs = diff(point.col, a.col).checked_mul(diff(b.row, a.row))?;
+            let rhs = diff(point.row, a.row).checked_mul(diff(b.col, a.col))?;
+            if (b.row > a.row && lhs < rhs) || (b.row < a.row && lhs > rhs) {
+                inside = !inside;
+            }
+        }
+
+        if inside {
+            Some(Location::Inside)
+        } else {
+            Some(Location::Outside)
+        }
+    }
+
+    /// Check if a point is strictly inside the polygon
+    pub fn contains(&self, point: Point<i64>) -> bool {
+        self.locate(point) == Location::Inside
+    }
+}
+
+/// The difference between two coordinates, which always fits in an i128
+fn diff(a: i64, b: i64) -> i128 {
+    i128::from(a) - i128::from(b)
+}
+
+/// Whether the point is on the segment from a to b, or None if it overflows
+fn on_segment(point: Point<i64>, a: Point<i64>, b: Point<i64>) -> Option<bool> {
+    let cross = diff(b.col, a.col)
+        .checked_mul(diff(point.row, a.row))?
+        .checked_sub(diff(b.row, a.row).checked_mul(diff(point.col, a.col))?)?;
+
+    Some(
+        cross == 0
+            && point.row >= a.row.min(b.row)
+            && point.row <= a.row.max(b.row)
+            && point.col >= a.col.min(b.col)
+            && point.col <= a.col.max(b.col),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::enums::OrdinalDirection;
+
+    fn square() -> Polygon {
+        Polygon::new(vec![
+            Point::new(0, 0),
+            Point::new(0, 4),
+            Point::new(4, 4),
+            Point::new(4, 0),
+        ])
+    }
+
+    #[test]
+    fn test_from_instructions() {
+        let polygon = Polygon::from_instructions(
+            Point::new(0, 0),
+            [
+                (OrdinalDirection::Right, 4),
+                (OrdinalDirection::Down, 4),
+                (OrdinalDirection::Left, 4),
+                (OrdinalDirection::Up, 4),
+            ],
+        );
+
+        assert_eq!(
+            polygon.vertices(),
+            &[
+                Point::new(0, 4),
+                Point::new(4, 4),
+                Point::new(4, 0),
+                Point::new(0, 0)
+            ]
+        );
+        assert_eq!(polygon.area(), square().area());
+    }
+
+    #[test]
+    fn test_area() {
+        let polygon = square();
+
+        assert_eq!(polygon.area(), 16);
+        assert_eq!(polygon.double_signed_area(), 32);
+
+        // Going the other way around flips the sign
+        let reversed = Polygon::new(polygon.vertices().iter().rev().copied().collect());
+        assert_eq!(reversed.double_signed_area(), -32);
+        assert_eq!(reversed.area(), 16);
+
+        let triangle = Polygon::new(vec![Point::new(0, 0), Point::new(0, 1), Point::new(1, 0)]);
+        assert_eq!(triangle.double_signed_area().abs(), 1);
+    }
+
+    #[test]
+    fn test_perimeter_and_points() {
+        let polygon = square();
+
+        assert_eq!(polygon.perimeter(), 16);
+        assert_eq!(polygon.boundary_points(), 16);
+        assert_eq!(polygon.interior_points(), 9);
+        assert_eq!(polygon.lattice_points(), 25);
+
+        // A diagonal edge only passes through some integer points
+        let triangle = Polygon::new(vec![Point::new(0, 0), Point::new(0, 4), Point::new(2, 0)]);
+        assert_eq!(triangle.boundary_points(), 4 + 2 + 2);
+        assert_eq!(triangle.interior_points(), 1);
+    }
+
+    #[test]
+    fn test_checked_overflow() {
+        let far = Polygon::checked_from_instructions(
+            Point::new(0, 0),
+            [
+                (OrdinalDirection::Right, i64::MAX),
+                (OrdinalDirection::Right, 1),
+            ],
+        );
+        assert_eq!(far, None);
+
+        let huge = Polygon::new(vec![
+            Point::new(0, 0),
+            Point::new(0, i64::MAX / 2),
+            Point::new(i64::MAX / 2, i64::MAX / 2),
+            Point::new(i64::MAX / 2, 0),
+        ]);
+        assert_eq!(huge.checked_double_signed_area(), None);
+        assert_eq!(huge.checked_lattice_points(), None);
+        assert_eq!(square().checked_lattice_points(), Some(25));
+
+        // Locating works past where the products fit in an i64
+        let far = i64::MAX / 2;
+        assert_eq!(huge.locate(Point::new(far / 2, far / 2)), Location::Inside);
+        assert_eq!(huge.locate(Point::new(far, 1)), Location::Boundary);
+        assert_eq!(huge.locate(Point::new(-far, far)), Location::Outside);
+
+        // But not past an i128, with edges and points across the whole range
+        let widest = Polygon::new(vec![
+            Point::new(i64::MIN, i64::MIN),
+            Point::new(i64::MIN, i64::MAX),
+            Point::new(i64::MAX, i64::MAX),
+            Point::new(i64::MAX, i64::MIN),
+        ]);
+        assert_eq!(widest.checked_locate(Point::new(i64::MAX, 0)), None);
+        assert_eq!(
+            square().checked_locate(Point::new(2, 2)),
+            Some(Location::Inside)
+        );
+    }
+
+    #[test]
+    fn test_locate() {
+        // An L shape
+        let polygon = Polygon::new(vec![
+            Point::new(0, 0),
+            Point::new(0, 2),
+            Point::new(2, 2),
+            Point::new(2, 4),
+            Point::new(4, 4),
+            Point::new(4, 0),
+        ]);
+
+        assert_eq!(polygon.locate(Point::new(1, 1)), Location::Inside);
+        assert_eq!(polygon.locate(Point::new(3, 3)), Location::Inside);
+        assert_eq!(polygon.locate(Point::new(1, 3)), Location::Outside);
+        assert_eq!(polygon.locate(Point::new(2, 3)), Location::Boundary);
+        assert_eq!(polygon.locate(Point::new(0, 0)), Location::Boundary);
+        assert_eq!(polygon.locate(Point::new(5, 1)), Location::Outside);
+        assert_eq!(polygon.locate(Point::new(2, -1)), Location::Outside);
+        assert!(polygon.contains(Point::new(2, 1)));
+        assert!(!polygon.contains(Point::new(0, 1)));
+    }
+}
@@ -0,0 +1,516 @@
+use crate::enums::Delta;
+use crate::error_handling::AdventError;
+use crate::point::Point;
+use crate::types::Coordinate;
+use std::fmt;
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+/// A rectangular grid of cells
+///
+/// Points are (row, col), with (0, 0) being the upper left corner. Anywhere a point is taken, a
+/// (row, col) tuple can be used as well. The cells are stored in a single vector, row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    height: usize,
+    width: usize,
+}
+
+impl<T> Grid<T> {
+    /// Create a grid of the given size, with every cell set to `value`
+    pub fn new(height: usize, width: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            cells: vec![value; height * width],
+            height,
+            width,
+        }
+    }
+
+    /// Create a grid from a list of rows
+    ///
+    /// All rows need to be of the same length
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, AdventError> {
+        let height = rows.len();
+        let width = rows.first().map(|row| row.len()).unwrap_or(0);
+
+        let mut cells = Vec::with_capacity(height * width);
+        for (idx, row) in rows.into_iter().enumerate() {
+            if row.len() != width {
+                return Err(parse_error!(format!(
+                    "Row {} has {} cells, expected {}",
+                    idx,
+                    row.len(),
+                    width
+                )));
+            }
+            cells.extend(row);
+        }
+
+        Ok(Self {
+            cells,
+            height,
+            width,
+        })
+    }
+
+    /// Parse a grid from text, converting each character with `f`
+    ///
+    /// Each line of the input is a row in the grid
+    pub fn parse_with<F>(input: &str, f: F) -> Result<Self, AdventError>
+    where
+        F: Fn(char) -> Result<T, AdventError>,
+    {
+        let rows = input
+            .lines()
+            .map(|line| {
+                line.char_indices()
+                    .map(|(idx, c)| {
+                        f(c).map_err(|e| e.locate(input, &line[idx..idx + c.len_utf8()]))
+                    })
+                    .collect::<Result<Vec<T>, AdventError>>()
+            })
+            .collect::<Result<Vec<Vec<T>>, AdventError>>()?;
+
+        if rows.is_empty() || rows[0].is_empty() {
+            return Err(parse_error!("Unable to parse an empty grid"));
+        }
+
+        let width = rows[0].len();
+        if let Some((idx, line)) = input
+            .lines()
+            .enumerate()
+            .find(|&(idx, _)| rows[idx].len() != width)
+        {
+            return Err(parse_error!(
+                format!(
+                    "Row {} has {} cells, expected {}",
+                    idx,
+                    rows[idx].len(),
+                    width
+                ),
+                input,
+                line
+            ));
+        }
+
+        Self::from_rows(rows)
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Check if the point is within the grid
+    pub fn contains(&self, point: impl Into<Point<usize>>) -> bool {
+        let point = point.into();
+        point.row < self.height && point.col < self.width
+    }
+
+    pub fn get(&self, point: impl Into<Point<usize>>) -> Option<&T> {
+        let point = point.into();
+        if self.contains(point) {
+            self.cells.get(point.row * self.width + point.col)
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, point: impl Into<Point<usize>>) -> Option<&mut T> {
+        let point = point.into();
+        if self.contains(point) {
+            self.cells.get_mut(point.row * self.width + point.col)
+        } else {
+            None
+        }
+    }
+
+    /// Step from the point in a direction, as long as the new point is still within the grid
+    pub fn step(
+        &self,
+        point: impl Into<Point<usize>>,
+        direction: impl Delta,
+    ) -> Option<Point<usize>> {
+        point
+            .into()
+            .step(direction, 1)
+            .filter(|next| self.contains(*next))
+    }
+
+    pub fn row(&self, row: usize) -> Option<&[T]> {
+        if row < self.height {
+            Some(&self.cells[row * self.width..(row + 1) * self.width])
+        } else {
+            None
+        }
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Iterate over the cells in a single column, from top to bottom
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        let height = if col < self.width { self.height } else { 0 };
+        (0..height).map(move |row| &self.cells[row * self.width + col])
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |col| self.column(col))
+    }
+
+    /// Iterate over all the cells, row by row, along with their points
+    pub fn iter(&self) -> impl Iterator<Item = (Point<usize>, &T)> {
+        let width = self.width;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(idx, cell)| (Point::new(idx / width, idx % width), cell))
+    }
+
+    /// Find the point of the first cell, row by row, that matches the predicate
+    pub fn position<P>(&self, predicate: P) -> Option<Point<usize>>
+    where
+        P: Fn(&T) -> bool,
+    {
+        self.cells
+            .iter()
+            .position(predicate)
+            .map(|idx| Point::new(idx / self.width, idx % self.width))
+    }
+
+    /// The points directly above, below, left and right of the point
+    ///
+    /// Only points within the grid are returned
+    pub fn neighbours4(
+        &self,
+        point: impl Into<Point<usize>>,
+    ) -> impl Iterator<Item = Point<usize>> {
+        self.neighbours(point.into(), &[(-1, 0), (0, 1), (1, 0), (0, -1)])
+    }
+
+    /// The eight points surrounding the point, including diagonals
+    ///
+    /// Only points within the grid are returned
+    pub fn neighbours8(
+        &self,
+        point: impl Into<Point<usize>>,
+    ) -> impl Iterator<Item = Point<usize>> {
+        self.neighbours(
+            point.into(),
+            &[
+                (-1, 0),
+                (-1, 1),
+                (0, 1),
+                (1, 1),
+                (1, 0),
+                (1, -1),
+                (0, -1),
+                (-1, -1),
+            ],
+        )
+    }
+
+    fn neighbours(
+        &self,
+        point: Point<usize>,
+        deltas: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = Point<usize>> {
+        let (height, width) = (self.height, self.width);
+        deltas.iter().filter_map(move |(dr, dc)| {
+            let row = point.row.checked_add_signed(*dr)?;
+            let col = point.col.checked_add_signed(*dc)?;
+            if row < height && col < width {
+                Some(Point::new(row, col))
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Create a new grid by converting every cell
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: Fn(&T) -> U,
+    {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            height: self.height,
+            width: self.width,
+        }
+    }
+
+    /// Build a new grid of the given size, where each cell is taken from `source` in this grid
+    fn remap<F>(&self, height: usize, width: usize, source: F) -> Self
+    where
+        T: Clone,
+        F: Fn(usize, usize) -> Coordinate<usize>,
+    {
+        let cells = (0..height)
+            .flat_map(|row| (0..width).map(move |col| (row, col)))
+            .map(|(row, col)| self[source(row, col)].clone())
+            .collect();
+
+        Self {
+            cells,
+            height,
+            width,
+        }
+    }
+
+    /// Swap the rows and columns
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.remap(self.width, self.height, |row, col| (col, row))
+    }
+
+    pub fn rotate_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        let height = self.height;
+        self.remap(self.width, self.height, |row, col| (height - 1 - col, row))
+    }
+
+    pub fn rotate_counter_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        let width = self.width;
+        self.remap(self.width, self.height, |row, col| (col, width - 1 - row))
+    }
+
+    /// Mirror the grid left to right
+    pub fn flip_horizontal(&self) -> Self
+    where
+        T: Clone,
+    {
+        let width = self.width;
+        self.remap(self.height, self.width, |row, col| (row, width - 1 - col))
+    }
+
+    /// Mirror the grid top to bottom
+    pub fn flip_vertical(&self) -> Self
+    where
+        T: Clone,
+    {
+        let height = self.height;
+        self.remap(self.height, self.width, |row, col| (height - 1 - row, col))
+    }
+}
+
+impl<T> Index<Point<usize>> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point<usize>) -> &Self::Output {
+        self.get(point)
+            .unwrap_or_else(|| panic!("Point {} is outside the grid", point))
+    }
+}
+
+impl<T> IndexMut<Point<usize>> for Grid<T> {
+    fn index_mut(&mut self, point: Point<usize>) -> &mut Self::Output {
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("Point {} is outside the grid", point))
+    }
+}
+
+impl<T> Index<Coordinate<usize>> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, coord: Coordinate<usize>) -> &Self::Output {
+        &self[Point::from(coord)]
+    }
+}
+
+impl<T> IndexMut<Coordinate<usize>> for Grid<T> {
+    fn index_mut(&mut self, coord: Coordinate<usize>) -> &mut Self::Output {
+        &mut self[Point::from(coord)]
+    }
+}
+
+impl<T> FromStr for Grid<T>
+where
+    T: TryFrom<char>,
+    T::Error: fmt::Display,
+{
+    type Err = AdventError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(s, |c| T::try_from(c).map_err(|e| parse_error!(e)))
+    }
+}
+
+/// Display the grid as lines of cells, which round trips with the input the grid was parsed from
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (idx, row) in self.rows().enumerate() {
+            if idx > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::enums::CardinalDirection;
+    use crate::error_handling::Span;
+
+    const INPUT: &str = "abc\ndef";
+
+    #[test]
+    fn test_grid_from_str() {
+        let grid: Grid<char> = INPUT.parse().unwrap();
+
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.get((0, 0)), Some(&'a'));
+        assert_eq!(grid.get((1, 2)), Some(&'f'));
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.get((0, 3)), None);
+    }
+
+    #[test]
+    fn test_grid_from_str_invalid() {
+        assert!("ab\nc".parse::<Grid<char>>().is_err());
+        assert!("".parse::<Grid<char>>().is_err());
+    }
+
+    #[test]
+    fn test_grid_parse_error_span() {
+        let parse = |c| match c {
+            '.' | '#' => Ok(c),
+            _ => Err(parse_error!(format!("Unknown cell: {}", c))),
+        };
+
+        let err = Grid::parse_with("..#\n.x.", parse).unwrap_err();
+        assert_eq!(err.span(), Some(&Span::new(2, 1..2, ".x.")));
+
+        let err = Grid::parse_with("..#\n..\n...", parse).unwrap_err();
+        assert_eq!(err.span(), Some(&Span::new(2, 0..2, "..")));
+    }
+
+    #[test]
+    fn test_grid_display_round_trips() {
+        let grid: Grid<char> = INPUT.parse().unwrap();
+
+        assert_eq!(grid.to_string(), INPUT);
+    }
+
+    #[test]
+    fn test_grid_get_mut() {
+        let mut grid: Grid<char> = INPUT.parse().unwrap();
+
+        *grid.get_mut((1, 1)).unwrap() = 'x';
+        grid[(0, 0)] = 'y';
+
+        assert_eq!(grid.to_string(), "ybc\ndxf");
+        assert!(grid.get_mut((5, 5)).is_none());
+    }
+
+    #[test]
+    fn test_grid_rows_and_columns() {
+        let grid: Grid<char> = INPUT.parse().unwrap();
+
+        let rows: Vec<String> = grid.rows().map(|row| row.iter().collect()).collect();
+        assert_eq!(rows, vec!["abc", "def"]);
+
+        let cols: Vec<String> = grid.columns().map(|col| col.collect()).collect();
+        assert_eq!(cols, vec!["ad", "be", "cf"]);
+
+        assert_eq!(grid.column(3).count(), 0);
+    }
+
+    #[test]
+    fn test_grid_iter_and_position() {
+        let grid: Grid<char> = INPUT.parse().unwrap();
+
+        assert_eq!(grid.iter().nth(4), Some((Point::new(1, 1), &'e')));
+        assert_eq!(grid.position(|&c| c == 'f'), Some(Point::new(1, 2)));
+        assert_eq!(grid.position(|&c| c == 'z'), None);
+    }
+
+    #[test]
+    fn test_grid_neighbours() {
+        let grid: Grid<char> = "...\n...\n...".parse().unwrap();
+
+        let points = |coords: &[Coordinate<usize>]| {
+            coords.iter().map(|&c| Point::from(c)).collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            grid.neighbours4((1, 1)).collect::<Vec<_>>(),
+            points(&[(0, 1), (1, 2), (2, 1), (1, 0)])
+        );
+        assert_eq!(
+            grid.neighbours4((0, 0)).collect::<Vec<_>>(),
+            points(&[(0, 1), (1, 0)])
+        );
+        assert_eq!(grid.neighbours8((1, 1)).count(), 8);
+        assert_eq!(
+            grid.neighbours8((2, 2)).collect::<Vec<_>>(),
+            points(&[(1, 2), (2, 1), (1, 1)])
+        );
+    }
+
+    #[test]
+    fn test_grid_step() {
+        let grid: Grid<char> = INPUT.parse().unwrap();
+
+        assert_eq!(
+            grid.step((0, 0), CardinalDirection::East),
+            Some(Point::new(0, 1))
+        );
+        assert_eq!(
+            grid.step((0, 0), CardinalDirection::South),
+            Some(Point::new(1, 0))
+        );
+        assert_eq!(grid.step((0, 0), CardinalDirection::North), None);
+        assert_eq!(grid.step((1, 2), CardinalDirection::East), None);
+        assert_eq!(grid.step((1, 2), CardinalDirection::South), None);
+    }
+
+    #[test]
+    fn test_grid_transformations() {
+        let grid: Grid<char> = INPUT.parse().unwrap();
+
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate_counter_clockwise().to_string(), "cf\nbe\nad");
+        assert_eq!(grid.flip_horizontal().to_string(), "cba\nfed");
+        assert_eq!(grid.flip_vertical().to_string(), "def\nabc");
+
+        assert_eq!(
+            grid.rotate_clockwise()
+                .rotate_clockwise()
+                .rotate_clockwise()
+                .rotate_clockwise(),
+            grid
+        );
+    }
+
+    #[test]
+    fn test_grid_map() {
+        let grid: Grid<u32> = Grid::parse_with("12\n34", |c| {
+            c.to_digit(10).ok_or(AdventError::InvalidDigit(c))
+        })
+        .unwrap();
+
+        assert_eq!(grid.map(|n| n * 2).to_string(), "24\n68");
+    }
+}
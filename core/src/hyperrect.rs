@@ -0,0 +1,251 @@
+use crate::interval::Interval;
+use crate::point::Integer;
+use std::fmt;
+
+/// A comparison of a value against a threshold
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Comparison {
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Eq,
+}
+
+impl Comparison {
+    /// From the operator symbol, such as < or >=
+    pub fn from_symbol(s: &str) -> Option<Self> {
+        match s {
+            "<" => Some(Comparison::Lt),
+            "<=" => Some(Comparison::Le),
+            ">" => Some(Comparison::Gt),
+            ">=" => Some(Comparison::Ge),
+            "==" => Some(Comparison::Eq),
+            _ => None,
+        }
+    }
+
+    /// Check if the comparison holds for a value
+    pub fn holds<T: Ord>(&self, value: T, threshold: T) -> bool {
+        match self {
+            Comparison::Lt => value < threshold,
+            Comparison::Le => value <= threshold,
+            Comparison::Gt => value > threshold,
+            Comparison::Ge => value >= threshold,
+            Comparison::Eq => value == threshold,
+        }
+    }
+
+    /// Split an interval into the part where the comparison holds and the parts where it doesn't
+    ///
+    /// Only an equality check can leave two non-matching parts, one on either side
+    pub fn split<T: Integer>(
+        &self,
+        interval: &Interval<T>,
+        threshold: T,
+    ) -> (Option<Interval<T>>, Vec<Interval<T>>) {
+        // Every value in an interval is below its end, so when there's nothing above the
+        // threshold, all of the interval is at or below it
+        let whole = (!interval.is_empty()).then_some(*interval);
+        let above = threshold.checked_add(T::ONE);
+
+        let (matching, rest) = match (self, above) {
+            (Comparison::Lt, _) => interval.split_at(threshold),
+            (Comparison::Le, Some(above)) => interval.split_at(above),
+            (Comparison::Le, None) => (whole, None),
+            (Comparison::Gt, Some(above)) => {
+                let (below, above) = interval.split_at(above);
+                (above, below)
+            }
+            (Comparison::Gt, None) => (None, whole),
+            (Comparison::Ge, _) => {
+                let (below, above) = interval.split_at(threshold);
+                (above, below)
+            }
+            (Comparison::Eq, Some(above)) => {
+                let value = Interval::new(threshold, above);
+                let (below, above) = interval.difference(&value);
+                return (
+                    interval.intersection(&value),
+                    below.into_iter().chain(above).collect(),
+                );
+            }
+            (Comparison::Eq, None) => (None, whole),
+        };
+
+        (matching, rest.into_iter().collect())
+    }
+}
+
+impl fmt::Display for Comparison {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let symbol = match self {
+            Comparison::Lt => "<",
+            Comparison::Le => "<=",
+            Comparison::Gt => ">",
+            Comparison::Ge => ">=",
+            Comparison::Eq => "==",
+        };
+        write!(f, "{}", symbol)
+    }
+}
+
+/// An N dimensional box, made up of an interval along each axis
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HyperRect<T, const N: usize> {
+    pub axes: [Interval<T>; N],
+}
+
+impl<T: Integer, const N: usize> HyperRect<T, N> {
+    pub fn new(axes: [Interval<T>; N]) -> Self {
+        Self { axes }
+    }
+
+    /// A box with the same interval along every axis
+    pub fn cube(interval: Interval<T>) -> Self {
+        Self::new([interval; N])
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.axes.iter().any(|axis| axis.is_empty())
+    }
+
+    pub fn contains(&self, point: [T; N]) -> bool {
+        self.axes
+            .iter()
+            .zip(point)
+            .all(|(axis, value)| axis.contains(value))
+    }
+
+    /// Split the box along an axis into the part where the comparison holds and the parts where
+    /// it doesn't
+    pub fn split(
+        &self,
+        axis: usize,
+        comparison: Comparison,
+        threshold: T,
+    ) -> (Option<Self>, Vec<Self>) {
+        let (matching, rest) = comparison.split(&self.axes[axis], threshold);
+        let with_axis = |interval| {
+            let mut rect = *self;
+            rect.axes[axis] = interval;
+            rect
+        };
+
+        (
+            matching.map(with_axis),
+            rest.into_iter().map(with_axis).collect(),
+        )
+    }
+}
+
+impl<T: Integer + TryInto<u128>, const N: usize> HyperRect<T, N> {
+    /// The number of integer points inside the box
+    pub fn volume(&self) -> u128 {
+        self.axes
+            .iter()
+            .map(|axis| axis.len().try_into().ok().unwrap_or(0))
+            .product()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_comparison() {
+        assert_eq!(Comparison::from_symbol(">="), Some(Comparison::Ge));
+        assert_eq!(Comparison::from_symbol("!="), None);
+        assert!(Comparison::Le.holds(3, 3));
+        assert!(!Comparison::Lt.holds(3, 3));
+        assert!(Comparison::Eq.holds(3, 3));
+        assert_eq!(Comparison::Ge.to_string(), ">=");
+    }
+
+    #[test]
+    fn test_comparison_split() {
+        let interval = Interval::new(1, 11);
+
+        assert_eq!(
+            Comparison::Lt.split(&interval, 5),
+            (Some(Interval::new(1, 5)), vec![Interval::new(5, 11)])
+        );
+        assert_eq!(
+            Comparison::Le.split(&interval, 5),
+            (Some(Interval::new(1, 6)), vec![Interval::new(6, 11)])
+        );
+        assert_eq!(
+            Comparison::Gt.split(&interval, 5),
+            (Some(Interval::new(6, 11)), vec![Interval::new(1, 6)])
+        );
+        assert_eq!(
+            Comparison::Ge.split(&interval, 5),
+            (Some(Interval::new(5, 11)), vec![Interval::new(1, 5)])
+        );
+        assert_eq!(
+            Comparison::Eq.split(&interval, 5),
+            (
+                Some(Interval::new(5, 6)),
+                vec![Interval::new(1, 5), Interval::new(6, 11)]
+            )
+        );
+        assert_eq!(Comparison::Gt.split(&interval, 20), (None, vec![interval]));
+    }
+
+    #[test]
+    fn test_comparison_split_at_max() {
+        let interval = Interval::new(1, u32::MAX);
+
+        assert_eq!(
+            Comparison::Le.split(&interval, u32::MAX),
+            (Some(interval), vec![])
+        );
+        assert_eq!(
+            Comparison::Gt.split(&interval, u32::MAX),
+            (None, vec![interval])
+        );
+        assert_eq!(
+            Comparison::Eq.split(&interval, u32::MAX),
+            (None, vec![interval])
+        );
+        assert_eq!(
+            Comparison::Ge.split(&interval, u32::MAX),
+            (None, vec![interval])
+        );
+        assert_eq!(
+            Comparison::Lt.split(&interval, u32::MAX),
+            (Some(interval), vec![])
+        );
+    }
+
+    #[test]
+    fn test_hyperrect_volume() {
+        let rect: HyperRect<u32, 4> = HyperRect::cube(Interval::new(1, 4001));
+
+        assert_eq!(rect.volume(), 4000u128.pow(4));
+        assert_eq!(
+            HyperRect::new([Interval::new(0, 3), Interval::new(5, 5)]).volume(),
+            0
+        );
+        assert!(HyperRect::new([Interval::new(0, 3), Interval::new(5, 5)]).is_empty());
+    }
+
+    #[test]
+    fn test_hyperrect_split() {
+        let rect: HyperRect<i64, 3> = HyperRect::cube(Interval::new(0, 10));
+
+        let (matching, rest) = rect.split(1, Comparison::Eq, 4);
+        let matching = matching.unwrap();
+
+        assert_eq!(matching.axes[1], Interval::new(4, 5));
+        assert_eq!(matching.volume(), 100);
+        assert_eq!(rest.len(), 2);
+        assert_eq!(
+            rest.iter().map(|rect| rect.volume()).sum::<u128>() + matching.volume(),
+            rect.volume()
+        );
+        assert!(matching.contains([0, 4, 9]));
+        assert!(!matching.contains([0, 5, 9]));
+    }
+}
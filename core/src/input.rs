@@ -0,0 +1,118 @@
+use crate::error_handling::AdventError;
+use crate::prelude::Result;
+use std::io::{ErrorKind, Read};
+use std::path::{Path, PathBuf};
+
+/// The environment variable pointing at a directory of inputs, named by day like `17.txt`
+pub const INPUTS_DIR_VAR: &str = "ADVENT_INPUTS";
+
+/// Where to read the puzzle input for a day from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// Work out where the input for a day is
+    ///
+    /// In order, this is the `--input` argument if given, where `-` means stdin, then the inputs
+    /// directory from the `ADVENT_INPUTS` environment variable, and finally `probN/input.txt` in
+    /// the workspace
+    pub fn resolve(day: u32, input: Option<&str>) -> Self {
+        Self::resolve_with(day, input, inputs_dir().as_deref())
+    }
+
+    /// Like [`InputSource::resolve`], with the inputs directory passed in rather than read from
+    /// the environment
+    pub fn resolve_with(day: u32, input: Option<&str>, inputs_dir: Option<&Path>) -> Self {
+        match input {
+            Some("-") => InputSource::Stdin,
+            Some(path) => InputSource::File(PathBuf::from(path)),
+            None => InputSource::File(stored_path(day, inputs_dir)),
+        }
+    }
+
+    /// Read the whole input
+    pub fn read(&self, day: u32) -> Result<String> {
+        match self {
+            InputSource::Stdin => {
+                let mut input = String::new();
+                std::io::stdin().read_to_string(&mut input)?;
+                Ok(input)
+            }
+            InputSource::File(path) => std::fs::read_to_string(path).map_err(|e| {
+                if e.kind() == ErrorKind::NotFound {
+                    AdventError::MissingInput {
+                        day,
+                        path: path.clone(),
+                    }
+                } else {
+                    e.into()
+                }
+            }),
+        }
+    }
+}
+
+/// The inputs directory from the `ADVENT_INPUTS` environment variable, if it is set
+pub fn inputs_dir() -> Option<PathBuf> {
+    std::env::var_os(INPUTS_DIR_VAR).map(PathBuf::from)
+}
+
+/// Where the input for a day is kept, which is also where fetched inputs are saved
+///
+/// This is `<int>.txt` in the inputs directory if there is one, and `probN/input.txt` otherwise
+pub fn stored_path(day: u32, inputs_dir: Option<&Path>) -> PathBuf {
+    match inputs_dir {
+        Some(dir) => dir.join(format!("{}.txt", day)),
+        None => default_path(day),
+    }
+}
+
+/// The default location of the input for a day, `probN/input.txt` in the workspace
+pub fn default_path(day: u32) -> PathBuf {
+    // The core crate is in the root of the workspace, next to the days
+    let workspace = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .unwrap_or(Path::new(".."));
+    workspace.join(format!("prob{}", day)).join("input.txt")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve() {
+        let dir = Path::new("/inputs");
+
+        assert_eq!(
+            InputSource::resolve_with(17, Some("my_input.txt"), Some(dir)),
+            InputSource::File(PathBuf::from("my_input.txt"))
+        );
+        assert_eq!(
+            InputSource::resolve_with(17, Some("-"), Some(dir)),
+            InputSource::Stdin
+        );
+        assert_eq!(
+            InputSource::resolve_with(17, None, Some(dir)),
+            InputSource::File(PathBuf::from("/inputs/17.txt"))
+        );
+        assert_eq!(
+            InputSource::resolve_with(17, None, None),
+            InputSource::File(default_path(17))
+        );
+        assert!(default_path(17).ends_with("prob17/input.txt"));
+    }
+
+    #[test]
+    fn test_read_missing_input() {
+        let source = InputSource::File(PathBuf::from("/does/not/exist/17.txt"));
+
+        let err = source.read(17).unwrap_err();
+
+        assert!(matches!(err, AdventError::MissingInput { day: 17, .. }));
+        assert!(err.to_string().contains("/does/not/exist/17.txt"));
+    }
+}
@@ -0,0 +1,387 @@
+use crate::point::Integer;
+use std::fmt;
+
+/// A half open interval of integers, including `start` but not `end`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Interval<T> {
+    pub start: T,
+    pub end: T,
+}
+
+impl<T: Integer> Interval<T> {
+    pub fn new(start: T, end: T) -> Self {
+        Self { start, end }
+    }
+
+    /// Create an interval of `length` values, starting at `start`
+    pub fn from_length(start: T, length: T) -> Self {
+        Self::new(start, start + length)
+    }
+
+    /// The number of values in the interval
+    pub fn len(&self) -> T {
+        if self.is_empty() {
+            T::ZERO
+        } else {
+            self.end - self.start
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start >= self.end
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    /// The values that are in both intervals, if any
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let interval = Self::new(self.start.max(other.start), self.end.min(other.end));
+        (!interval.is_empty()).then_some(interval)
+    }
+
+    /// Split the interval into the values below `at` and the values from `at` and up
+    pub fn split_at(&self, at: T) -> (Option<Self>, Option<Self>) {
+        let below = Self::new(self.start, self.end.min(at));
+        let above = Self::new(self.start.max(at), self.end);
+
+        (
+            (!below.is_empty()).then_some(below),
+            (!above.is_empty()).then_some(above),
+        )
+    }
+
+    /// The values that are not in `other`, which can be a part below it and a part above it
+    pub fn difference(&self, other: &Self) -> (Option<Self>, Option<Self>) {
+        if !self.overlaps(other) {
+            return (Some(*self).filter(|interval| !interval.is_empty()), None);
+        }
+        let (below, _) = self.split_at(other.start);
+        let (_, above) = self.split_at(other.end);
+
+        (below, above)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Interval<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}, {})", self.start, self.end)
+    }
+}
+
+/// A set of integers, stored as sorted intervals that neither overlap nor touch
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct IntervalSet<T> {
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T: Integer> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self {
+            intervals: Vec::new(),
+        }
+    }
+
+    /// Add all the values of an interval to the set
+    pub fn insert(&mut self, interval: Interval<T>) {
+        if interval.is_empty() {
+            return;
+        }
+        self.intervals.push(interval);
+        self.normalise();
+    }
+
+    /// Sort the intervals and merge any that overlap or touch
+    fn normalise(&mut self) {
+        self.intervals.retain(|interval| !interval.is_empty());
+        self.intervals.sort();
+
+        let mut merged: Vec<Interval<T>> = Vec::with_capacity(self.intervals.len());
+        for interval in self.intervals.drain(..) {
+            match merged.last_mut() {
+                Some(last) if interval.start <= last.end => {
+                    last.end = last.end.max(interval.end);
+                }
+                _ => merged.push(interval),
+            }
+        }
+        self.intervals = merged;
+    }
+
+    pub fn intervals(&self) -> &[Interval<T>] {
+        &self.intervals
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Interval<T>> {
+        self.intervals.iter()
+    }
+
+    /// The number of values in the set
+    pub fn len(&self) -> T {
+        self.intervals
+            .iter()
+            .fold(T::ZERO, |total, interval| total + interval.len())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.intervals
+            .iter()
+            .any(|interval| interval.contains(value))
+    }
+
+    /// The smallest value in the set
+    pub fn min(&self) -> Option<T> {
+        self.intervals.first().map(|interval| interval.start)
+    }
+
+    /// The largest value in the set
+    pub fn max(&self) -> Option<T> {
+        self.intervals.last().map(|interval| interval.end - T::ONE)
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        self.intervals
+            .iter()
+            .chain(other.intervals.iter())
+            .copied()
+            .collect()
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        self.intervals
+            .iter()
+            .flat_map(|a| other.intervals.iter().filter_map(|b| a.intersection(b)))
+            .collect()
+    }
+
+    /// The values in this set that are not in `other`
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut intervals = Vec::new();
+
+        for interval in &self.intervals {
+            // Walk over the intervals of the other set, which are sorted, keeping the parts in
+            // between them
+            let mut start = interval.start;
+            for other in other
+                .intervals
+                .iter()
+                .filter(|other| interval.overlaps(other))
+            {
+                if other.start > start {
+                    intervals.push(Interval::new(start, other.start));
+                }
+                start = start.max(other.end);
+            }
+            if start < interval.end {
+                intervals.push(Interval::new(start, interval.end));
+            }
+        }
+
+        intervals.into_iter().collect()
+    }
+
+    /// Split the set into the values below `at` and the values from `at` and up
+    pub fn split_at(&self, at: T) -> (Self, Self) {
+        let (below, above): (Vec<_>, Vec<_>) = self
+            .intervals
+            .iter()
+            .map(|interval| interval.split_at(at))
+            .unzip();
+
+        (
+            below.into_iter().flatten().collect(),
+            above.into_iter().flatten().collect(),
+        )
+    }
+}
+
+impl<T: Integer> FromIterator<Interval<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(iter: I) -> Self {
+        let mut set = Self {
+            intervals: iter.into_iter().collect(),
+        };
+        set.normalise();
+        set
+    }
+}
+
+impl<T: Integer> From<Interval<T>> for IntervalSet<T> {
+    fn from(interval: Interval<T>) -> Self {
+        std::iter::once(interval).collect()
+    }
+}
+
+/// A piecewise linear mapping of integers
+///
+/// Each piece maps a source interval onto an interval of the same length starting at a
+/// destination. Values outside of all the pieces map to themselves. If pieces overlap, the one
+/// inserted first wins.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct RangeMap<T> {
+    pieces: Vec<(Interval<T>, T)>,
+}
+
+impl<T: Integer> RangeMap<T> {
+    pub fn new() -> Self {
+        Self { pieces: Vec::new() }
+    }
+
+    /// Map the values of `source` onto the values starting at `destination`
+    pub fn insert(&mut self, source: Interval<T>, destination: T) {
+        self.pieces.push((source, destination));
+    }
+
+    /// Map a single value
+    pub fn map_value(&self, value: T) -> T {
+        self.pieces
+            .iter()
+            .find(|(source, _)| source.contains(value))
+            .map_or(value, |(source, destination)| {
+                *destination + (value - source.start)
+            })
+    }
+
+    /// Map every value in a set, one interval at a time
+    pub fn map_set(&self, set: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut remaining = set.clone();
+        let mut mapped = Vec::new();
+
+        for (source, destination) in &self.pieces {
+            let source_set = IntervalSet::from(*source);
+            for interval in remaining.intersection(&source_set).iter() {
+                let offset = interval.start - source.start;
+                mapped.push(Interval::from_length(*destination + offset, interval.len()));
+            }
+            remaining = remaining.difference(&source_set);
+        }
+
+        mapped.into_iter().chain(remaining.intervals).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(intervals: &[(i64, i64)]) -> IntervalSet<i64> {
+        intervals
+            .iter()
+            .map(|&(start, end)| Interval::new(start, end))
+            .collect()
+    }
+
+    #[test]
+    fn test_interval() {
+        let interval = Interval::from_length(5, 10);
+
+        assert_eq!(interval, Interval::new(5, 15));
+        assert_eq!(interval.len(), 10);
+        assert!(interval.contains(5));
+        assert!(!interval.contains(15));
+        assert!(Interval::new(3, 3).is_empty());
+        assert_eq!(Interval::new(4, 3).len(), 0);
+        assert_eq!(interval.to_string(), "[5, 15)");
+    }
+
+    #[test]
+    fn test_interval_intersection() {
+        let a = Interval::new(0, 10);
+
+        assert_eq!(
+            a.intersection(&Interval::new(5, 20)),
+            Some(Interval::new(5, 10))
+        );
+        assert_eq!(a.intersection(&Interval::new(10, 20)), None);
+        assert!(a.overlaps(&Interval::new(9, 10)));
+    }
+
+    #[test]
+    fn test_interval_split_and_difference() {
+        let a = Interval::new(0, 10);
+
+        assert_eq!(
+            a.split_at(4),
+            (Some(Interval::new(0, 4)), Some(Interval::new(4, 10)))
+        );
+        assert_eq!(a.split_at(0), (None, Some(a)));
+        assert_eq!(
+            a.difference(&Interval::new(3, 5)),
+            (Some(Interval::new(0, 3)), Some(Interval::new(5, 10)))
+        );
+        assert_eq!(
+            a.difference(&Interval::new(-5, 5)),
+            (None, Some(Interval::new(5, 10)))
+        );
+        assert_eq!(a.difference(&Interval::new(20, 30)), (Some(a), None));
+        assert_eq!(a.difference(&Interval::new(-5, 15)), (None, None));
+    }
+
+    #[test]
+    fn test_interval_set_merges() {
+        let set = set(&[(5, 8), (0, 3), (3, 4), (7, 10), (20, 20)]);
+
+        assert_eq!(
+            set.intervals(),
+            &[Interval::new(0, 4), Interval::new(5, 10)]
+        );
+        assert_eq!(set.len(), 9);
+        assert_eq!(set.min(), Some(0));
+        assert_eq!(set.max(), Some(9));
+        assert!(set.contains(3));
+        assert!(!set.contains(4));
+    }
+
+    #[test]
+    fn test_interval_set_operations() {
+        let a = set(&[(0, 10), (20, 30)]);
+        let b = set(&[(5, 25)]);
+
+        assert_eq!(a.union(&b), set(&[(0, 30)]));
+        assert_eq!(a.intersection(&b), set(&[(5, 10), (20, 25)]));
+        assert_eq!(a.difference(&b), set(&[(0, 5), (25, 30)]));
+        assert_eq!(b.difference(&a), set(&[(10, 20)]));
+        assert_eq!(
+            a.split_at(25),
+            (set(&[(0, 10), (20, 25)]), set(&[(25, 30)]))
+        );
+    }
+
+    #[test]
+    fn test_range_map() {
+        let mut map = RangeMap::new();
+        map.insert(Interval::from_length(98, 2), 50);
+        map.insert(Interval::from_length(50, 48), 52);
+
+        assert_eq!(map.map_value(79), 81);
+        assert_eq!(map.map_value(99), 51);
+        assert_eq!(map.map_value(10), 10);
+
+        assert_eq!(
+            map.map_set(&set(&[(45, 55), (97, 101)])),
+            set(&[(45, 50), (50, 52), (52, 57), (99, 100), (100, 101)])
+        );
+    }
+
+    #[test]
+    fn test_range_map_first_piece_wins() {
+        let mut map = RangeMap::new();
+        map.insert(Interval::new(0, 10), 100);
+        map.insert(Interval::new(5, 15), 200);
+
+        assert_eq!(map.map_value(7), 107);
+        assert_eq!(map.map_value(12), 207);
+        assert_eq!(
+            map.map_set(&set(&[(0, 15)])),
+            set(&[(100, 110), (205, 210)])
+        );
+    }
+}
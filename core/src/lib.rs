@@ -1,6 +1,17 @@
 #[macro_use]
 pub mod error_handling;
+pub mod cycle;
 pub mod enums;
+pub mod geometry;
+pub mod grid;
+pub mod hyperrect;
+pub mod input;
+pub mod interval;
+pub mod math;
+pub mod parallel;
+pub mod point;
 pub mod prelude;
+pub mod search;
+pub mod solution;
 pub mod types;
 pub mod utils;
@@ -0,0 +1,159 @@
+use crate::error_handling::AdventError;
+use crate::prelude::Result;
+
+macro_rules! impl_gcd_lcm {
+    ($t:ty, $gcd:ident, $lcm:ident, $checked_lcm:ident) => {
+        /// Find the greatest common divisor of two numbers
+        pub fn $gcd(a: $t, b: $t) -> $t {
+            if b == 0 {
+                a
+            } else {
+                $gcd(b, a % b)
+            }
+        }
+
+        /// Find the least common multiple of two numbers
+        pub fn $lcm(a: $t, b: $t) -> $t {
+            $checked_lcm(a, b).expect("Least common multiple overflowed")
+        }
+
+        /// Find the least common multiple of two numbers, or None if it overflows
+        pub fn $checked_lcm(a: $t, b: $t) -> Option<$t> {
+            if a == 0 || b == 0 {
+                return Some(0);
+            }
+            (a / $gcd(a, b)).checked_mul(b)
+        }
+    };
+}
+
+impl_gcd_lcm!(u64, gcd, lcm, checked_lcm);
+impl_gcd_lcm!(u128, gcd_u128, lcm_u128, checked_lcm_u128);
+
+/// Find the least common multiple of all the numbers, or None if it overflows
+///
+/// The least common multiple of no numbers is 1
+pub fn lcm_all(numbers: impl IntoIterator<Item = u64>) -> Option<u64> {
+    numbers.into_iter().try_fold(1, checked_lcm)
+}
+
+/// The extended Euclidean algorithm
+///
+/// Returns (g, x, y) where g is the greatest common divisor of a and b, and a * x + b * y = g
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    if b == 0 {
+        (a, 1, 0)
+    } else {
+        let (g, x, y) = extended_gcd(b, a % b);
+        (g, y, x - (a / b) * y)
+    }
+}
+
+/// Find x such that a * x = 1 (mod m), if it exists
+pub fn mod_inverse(a: i128, m: i128) -> Option<i128> {
+    let (g, x, _) = extended_gcd(a.rem_euclid(m), m);
+    if g != 1 {
+        return None;
+    }
+    Some(x.rem_euclid(m))
+}
+
+/// Solve a system of congruences with the Chinese remainder theorem
+///
+/// Each congruence is a (remainder, modulus) pair, meaning x = remainder (mod modulus). The moduli
+/// do not have to be coprime. Returns the smallest non-negative solution together with the
+/// modulus it repeats with, or None if the congruences contradict each other. A solution too big
+/// to work out is an error, since it's there but can't be found.
+pub fn crt(congruences: &[(i128, i128)]) -> Result<Option<(i128, i128)>> {
+    let overflow = || error!("Solving the congruences overflows");
+    let mut solution = (0, 1);
+    for &(r2, m2) in congruences {
+        let (r1, m1) = solution;
+        // We need r1 + m1 * k = r2 (mod m2), which only has a solution when the difference is
+        // divisible by the gcd of the moduli
+        let (g, _, _) = extended_gcd(m1, m2);
+        let diff = r2.checked_sub(r1).ok_or_else(overflow)?;
+        if diff % g != 0 {
+            return Ok(None);
+        }
+        let m2_g = m2 / g;
+        // The moduli divided by their gcd are coprime, so the inverse is always there
+        let inverse = mod_inverse(m1 / g, m2_g).ok_or_else(overflow)?;
+        let k = (diff / g)
+            .rem_euclid(m2_g)
+            .checked_mul(inverse)
+            .ok_or_else(overflow)?
+            % m2_g;
+        let modulus = (m1 / g).checked_mul(m2).ok_or_else(overflow)?;
+        let remainder = m1
+            .checked_mul(k)
+            .and_then(|product| product.checked_add(r1))
+            .ok_or_else(overflow)?
+            .rem_euclid(modulus);
+
+        solution = (remainder, modulus);
+    }
+
+    Ok(Some(solution))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gcd_lcm() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(7, 0), 7);
+        assert_eq!(lcm(4, 6), 12);
+        assert_eq!(lcm(0, 6), 0);
+        assert_eq!(gcd_u128(1 << 70, 1 << 65), 1 << 65);
+        assert_eq!(lcm_u128(1 << 70, 3), 3 << 70);
+    }
+
+    #[test]
+    fn test_checked_lcm() {
+        assert_eq!(checked_lcm(u64::MAX, u64::MAX - 1), None);
+        assert_eq!(checked_lcm(1 << 40, 1 << 41), Some(1 << 41));
+        assert_eq!(checked_lcm_u128(u128::MAX, 2), None);
+    }
+
+    #[test]
+    fn test_lcm_all() {
+        assert_eq!(lcm_all([2, 3, 4]), Some(12));
+        assert_eq!(lcm_all([]), Some(1));
+        assert_eq!(lcm_all([u64::MAX, 2]), None);
+    }
+
+    #[test]
+    fn test_extended_gcd() {
+        let (g, x, y) = extended_gcd(240, 46);
+
+        assert_eq!(g, 2);
+        assert_eq!(240 * x + 46 * y, 2);
+    }
+
+    #[test]
+    fn test_mod_inverse() {
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(-3, 11), Some(7));
+        assert_eq!(mod_inverse(6, 9), None);
+    }
+
+    #[test]
+    fn test_crt() {
+        // Coprime moduli
+        assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]).unwrap(), Some((23, 105)));
+        // Moduli sharing a factor
+        assert_eq!(crt(&[(2, 6), (4, 8)]).unwrap(), Some((20, 24)));
+        // Contradicting congruences
+        assert_eq!(crt(&[(1, 4), (2, 6)]).unwrap(), None);
+        assert_eq!(crt(&[]).unwrap(), Some((0, 1)));
+        // A solution exists, but its modulus doesn't fit
+        let large = (1 << 100) + 1;
+        assert_eq!(
+            crt(&[(1, large), (2, large + 2)]).unwrap_err().to_string(),
+            "Error: Solving the congruences overflows"
+        );
+    }
+}
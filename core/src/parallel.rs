@@ -0,0 +1,60 @@
+//! Runs independent work across threads with the `parallel` feature, and one item at a time
+//! without it
+//!
+//! Either way the results come back in the order of the items, so anything that folds over them
+//! gets the same answer, and the same first error, as the serial path.
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Apply `f` to every item, yielding the results in the order of the items
+///
+/// With the `parallel` feature every item is worked on up front across threads, otherwise each one
+/// is worked on as the results are consumed.
+#[cfg(feature = "parallel")]
+pub fn map<'a, T, R, F>(items: &'a [T], f: F) -> impl Iterator<Item = R> + 'a
+where
+    T: Sync,
+    R: Send + 'a,
+    F: Fn(&'a T) -> R + Sync + Send + 'a,
+{
+    items.par_iter().map(f).collect::<Vec<_>>().into_iter()
+}
+
+/// Apply `f` to every item, yielding the results in the order of the items
+///
+/// With the `parallel` feature every item is worked on up front across threads, otherwise each one
+/// is worked on as the results are consumed.
+#[cfg(not(feature = "parallel"))]
+pub fn map<'a, T, R, F>(items: &'a [T], f: F) -> impl Iterator<Item = R> + 'a
+where
+    T: Sync,
+    R: Send + 'a,
+    F: Fn(&'a T) -> R + Sync + Send + 'a,
+{
+    items.iter().map(f)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_map_keeps_the_order() {
+        let items: Vec<u64> = (0..1000).collect();
+
+        let squares: Vec<u64> = map(&items, |n| n * n).collect();
+
+        assert_eq!(squares, items.iter().map(|n| n * n).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_map_first_error() {
+        let items = ["1", "x", "3", "y"];
+
+        let parsed: std::result::Result<Vec<u32>, _> =
+            map(&items, |s| s.parse::<u32>().map_err(|_| *s)).collect();
+
+        assert_eq!(parsed, Err("x"));
+    }
+}
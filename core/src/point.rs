@@ -0,0 +1,235 @@
+use crate::enums::Delta;
+use crate::types::Coordinate;
+use std::fmt;
+use std::ops::{Add, Mul, Sub};
+
+/// Integer types that can be used for the components of a point
+pub trait Integer:
+    Copy + Ord + fmt::Debug + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn checked_add(self, other: Self) -> Option<Self>;
+    fn checked_sub(self, other: Self) -> Option<Self>;
+
+    /// The absolute difference between two values
+    fn abs_diff(self, other: Self) -> Self {
+        if self > other {
+            self - other
+        } else {
+            other - self
+        }
+    }
+}
+
+macro_rules! impl_integer {
+    ($($t:ty),*) => {
+        $(
+            impl Integer for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn checked_add(self, other: Self) -> Option<Self> {
+                    <$t>::checked_add(self, other)
+                }
+
+                fn checked_sub(self, other: Self) -> Option<Self> {
+                    <$t>::checked_sub(self, other)
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+/// A point on a grid
+///
+/// The point is stored as a row and a column, where the row grows downwards and the column grows
+/// to the right. When working with x/y coordinates, x is the column and y is the row.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point<T> {
+    pub row: T,
+    pub col: T,
+}
+
+/// A point used as an offset between two points
+pub type Vector<T> = Point<T>;
+
+impl<T> Point<T> {
+    pub const fn new(row: T, col: T) -> Self {
+        Self { row, col }
+    }
+
+    /// Create a point from x/y coordinates, where x is the column and y is the row
+    pub const fn from_xy(x: T, y: T) -> Self {
+        Self { row: y, col: x }
+    }
+}
+
+impl<T: Integer> Point<T> {
+    pub fn x(&self) -> T {
+        self.col
+    }
+
+    pub fn y(&self) -> T {
+        self.row
+    }
+
+    /// Take `n` steps in a direction
+    ///
+    /// Returns None if the point would go out of bounds of the integer type, such as stepping
+    /// north from row 0 on an unsigned point
+    pub fn step(&self, direction: impl Delta, n: T) -> Option<Self> {
+        let (dr, dc) = direction.delta();
+        Some(Self::new(shift(self.row, dr, n)?, shift(self.col, dc, n)?))
+    }
+
+    /// Calculate the manhattan distance to another point
+    pub fn manhattan(&self, other: Self) -> T {
+        self.row.abs_diff(other.row) + self.col.abs_diff(other.col)
+    }
+
+    /// Calculate the chebyshev distance to another point, where diagonal steps count as one
+    pub fn chebyshev(&self, other: Self) -> T {
+        self.row
+            .abs_diff(other.row)
+            .max(self.col.abs_diff(other.col))
+    }
+}
+
+/// Move a single component `n` steps in the direction of the sign of `delta`
+fn shift<T: Integer>(value: T, delta: isize, n: T) -> Option<T> {
+    match delta.signum() {
+        -1 => value.checked_sub(n),
+        1 => value.checked_add(n),
+        _ => Some(value),
+    }
+}
+
+impl<T: Integer> Add for Point<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        Self::new(self.row + other.row, self.col + other.col)
+    }
+}
+
+impl<T: Integer> Sub for Point<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self::Output {
+        Self::new(self.row - other.row, self.col - other.col)
+    }
+}
+
+impl<T: Integer> Mul<T> for Point<T> {
+    type Output = Self;
+
+    fn mul(self, scalar: T) -> Self::Output {
+        Self::new(self.row * scalar, self.col * scalar)
+    }
+}
+
+/// Convert from the (row, col) tuple form
+impl<T> From<Coordinate<T>> for Point<T> {
+    fn from((row, col): Coordinate<T>) -> Self {
+        Self::new(row, col)
+    }
+}
+
+/// Convert to the (row, col) tuple form
+impl<T> From<Point<T>> for Coordinate<T> {
+    fn from(point: Point<T>) -> Self {
+        (point.row, point.col)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Point<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.row, self.col)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::enums::{CardinalDirection, CompassDirection, OrdinalDirection};
+
+    #[test]
+    fn test_point_from_xy() {
+        let point = Point::from_xy(3, 7);
+
+        assert_eq!(point, Point::new(7, 3));
+        assert_eq!(point.x(), 3);
+        assert_eq!(point.y(), 7);
+    }
+
+    #[test]
+    fn test_point_arithmetic() {
+        let a = Point::new(1, 2);
+        let b = Point::new(3, -4);
+
+        assert_eq!(a + b, Point::new(4, -2));
+        assert_eq!(a - b, Point::new(-2, 6));
+        assert_eq!(b * 3, Point::new(9, -12));
+    }
+
+    #[test]
+    fn test_point_step() {
+        let point: Point<usize> = Point::new(1, 1);
+
+        assert_eq!(
+            point.step(CardinalDirection::North, 1),
+            Some(Point::new(0, 1))
+        );
+        assert_eq!(point.step(CardinalDirection::North, 2), None);
+        assert_eq!(
+            point.step(CardinalDirection::South, 5),
+            Some(Point::new(6, 1))
+        );
+        assert_eq!(
+            point.step(CardinalDirection::West, 1),
+            Some(Point::new(1, 0))
+        );
+        assert_eq!(point.step(CardinalDirection::West, 2), None);
+        assert_eq!(
+            point.step(CardinalDirection::East, 3),
+            Some(Point::new(1, 4))
+        );
+
+        let point: Point<i64> = Point::new(0, 0);
+        assert_eq!(
+            point.step(CardinalDirection::North, 3),
+            Some(Point::new(-3, 0))
+        );
+        assert_eq!(
+            point.step(OrdinalDirection::Right, 2),
+            Some(Point::new(0, 2))
+        );
+        assert_eq!(
+            point.step(CompassDirection::SouthWest, 4),
+            Some(Point::new(4, -4))
+        );
+    }
+
+    #[test]
+    fn test_point_distances() {
+        let a: Point<u32> = Point::new(12, 71);
+        let b: Point<u32> = Point::new(125, 51);
+
+        assert_eq!(a.manhattan(b), 133);
+        assert_eq!(b.manhattan(a), 133);
+        assert_eq!(a.chebyshev(b), 113);
+    }
+
+    #[test]
+    fn test_point_tuple_conversion() {
+        let point: Point<usize> = (2, 5).into();
+        assert_eq!(point, Point::new(2, 5));
+
+        let coord: Coordinate<usize> = point.into();
+        assert_eq!(coord, (2, 5));
+    }
+}
@@ -1,5 +1,8 @@
-pub use crate::enums::{CardinalDirection, OrdinalDirection};
-pub use crate::error_handling::AdventError;
+pub use crate::enums::{CardinalDirection, CompassDirection, Delta, OrdinalDirection};
+pub use crate::error_handling::{parse_at, parse_lines, AdventError, Context, Span};
+pub use crate::grid::Grid;
+pub use crate::point::{Point, Vector};
+pub use crate::solution::{Answer, Params, Solution};
 pub use crate::types::Coordinate;
 pub use crate::utils::manhattan_distance;
 pub use crate::{error, invalid_coordinate, parse_error};
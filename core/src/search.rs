@@ -0,0 +1,380 @@
+use crate::point::Integer;
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+
+/// The result of a successful search
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchResult<S, C> {
+    /// The total cost of the path
+    pub cost: C,
+    /// Every state on the path, from the start up to and including the goal
+    pub path: Vec<S>,
+}
+
+impl<S, C> SearchResult<S, C> {
+    /// The goal state that was reached
+    pub fn goal(&self) -> &S {
+        self.path
+            .last()
+            .expect("A path always contains at least one state")
+    }
+}
+
+/// Bookkeeping for all the states discovered during a search
+///
+/// Each state is stored once, together with the best known cost to reach it and the index of the
+/// state it was reached from, so the path can be reconstructed once a goal is found
+struct Visited<S, C> {
+    states: Vec<(S, Option<usize>, C)>,
+    index: HashMap<S, usize>,
+}
+
+impl<S: Clone + Eq + Hash, C: Integer> Visited<S, C> {
+    fn new() -> Self {
+        Self {
+            states: Vec::new(),
+            index: HashMap::new(),
+        }
+    }
+
+    /// Record reaching a state with a cost
+    ///
+    /// Returns the index of the state if this is the first or the cheapest way of reaching it,
+    /// otherwise None
+    fn relax(&mut self, state: S, parent: Option<usize>, cost: C) -> Option<usize> {
+        match self.index.entry(state) {
+            Entry::Occupied(entry) => {
+                let idx = *entry.get();
+                if cost < self.states[idx].2 {
+                    self.states[idx].1 = parent;
+                    self.states[idx].2 = cost;
+                    Some(idx)
+                } else {
+                    None
+                }
+            }
+            Entry::Vacant(entry) => {
+                let idx = self.states.len();
+                self.states.push((entry.key().clone(), parent, cost));
+                entry.insert(idx);
+                Some(idx)
+            }
+        }
+    }
+
+    fn cost(&self, idx: usize) -> C {
+        self.states[idx].2
+    }
+
+    fn state(&self, idx: usize) -> &S {
+        &self.states[idx].0
+    }
+
+    /// Walk the parents back to the start to build the path to a state
+    fn result(&self, idx: usize) -> SearchResult<S, C> {
+        let mut path = Vec::new();
+        let mut current = Some(idx);
+        while let Some(idx) = current {
+            path.push(self.states[idx].0.clone());
+            current = self.states[idx].1;
+        }
+        path.reverse();
+
+        SearchResult {
+            cost: self.states[idx].2,
+            path,
+        }
+    }
+}
+
+/// Find the cheapest path from any of the start states to a state matching `is_goal`
+///
+/// The `successors` function returns the states reachable from a state, together with the cost
+/// of each step. Costs must not be negative. The search exits as soon as the first goal is
+/// reached, so multiple goals can be expressed through the predicate.
+pub fn dijkstra<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<SearchResult<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Integer,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(starts, successors, |_| C::ZERO, is_goal)
+}
+
+/// Find the cheapest path like [`dijkstra`], guided by a heuristic
+///
+/// The heuristic estimates the remaining cost from a state to the nearest goal. It has to be
+/// admissible, meaning it never overestimates the cost, for the returned path to be the cheapest.
+pub fn astar<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<SearchResult<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Integer,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut visited = Visited::new();
+    let mut heap = BinaryHeap::new();
+
+    for start in starts {
+        let estimate = heuristic(&start);
+        if let Some(idx) = visited.relax(start, None, C::ZERO) {
+            heap.push(Reverse((estimate, C::ZERO, idx)));
+        }
+    }
+
+    while let Some(Reverse((_, cost, idx))) = heap.pop() {
+        if cost > visited.cost(idx) {
+            // A cheaper way to this state has been found since this was queued
+            continue;
+        }
+
+        let state = visited.state(idx).clone();
+        if is_goal(&state) {
+            return Some(visited.result(idx));
+        }
+
+        for (next, step_cost) in successors(&state) {
+            let next_cost = cost + step_cost;
+            let estimate = next_cost + heuristic(&next);
+            if let Some(next_idx) = visited.relax(next, Some(idx), next_cost) {
+                heap.push(Reverse((estimate, next_cost, next_idx)));
+            }
+        }
+    }
+
+    None
+}
+
+/// Find the shortest path when every step costs the same
+///
+/// The cost of the result is the number of steps taken
+pub fn bfs<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<SearchResult<S, usize>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut visited = Visited::new();
+    let mut queue = VecDeque::new();
+
+    for start in starts {
+        if let Some(idx) = visited.relax(start, None, 0) {
+            queue.push_back(idx);
+        }
+    }
+
+    while let Some(idx) = queue.pop_front() {
+        let state = visited.state(idx).clone();
+        if is_goal(&state) {
+            return Some(visited.result(idx));
+        }
+
+        let cost = visited.cost(idx);
+        for next in successors(&state) {
+            // The first time a state is reached is always the shortest, so later visits are
+            // ignored by only queueing newly discovered states
+            if visited.index.contains_key(&next) {
+                continue;
+            }
+            if let Some(next_idx) = visited.relax(next, Some(idx), cost + 1) {
+                queue.push_back(next_idx);
+            }
+        }
+    }
+
+    None
+}
+
+/// Find the cheapest path when every step costs either 0 or 1
+///
+/// Free steps are explored before the others, which avoids the overhead of a heap
+pub fn zero_one_bfs<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<SearchResult<S, usize>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    let mut visited = Visited::new();
+    let mut queue = VecDeque::new();
+
+    for start in starts {
+        if let Some(idx) = visited.relax(start, None, 0) {
+            queue.push_back((0, idx));
+        }
+    }
+
+    while let Some((cost, idx)) = queue.pop_front() {
+        if cost > visited.cost(idx) {
+            continue;
+        }
+
+        let state = visited.state(idx).clone();
+        if is_goal(&state) {
+            return Some(visited.result(idx));
+        }
+
+        for (next, step_cost) in successors(&state) {
+            debug_assert!(step_cost <= 1, "0-1 BFS only supports step costs of 0 or 1");
+            let next_cost = cost + step_cost;
+            if let Some(next_idx) = visited.relax(next, Some(idx), next_cost) {
+                if step_cost == 0 {
+                    queue.push_front((next_cost, next_idx));
+                } else {
+                    queue.push_back((next_cost, next_idx));
+                }
+            }
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::enums::CardinalDirection;
+    use crate::grid::Grid;
+    use crate::point::Point;
+
+    fn grid() -> Grid<u32> {
+        "131\n191\n111"
+            .parse::<Grid<char>>()
+            .unwrap()
+            .map(|c| c.to_digit(10).unwrap())
+    }
+
+    fn neighbours(grid: &Grid<u32>, point: Point<usize>) -> Vec<(Point<usize>, u32)> {
+        grid.neighbours4(point).map(|p| (p, grid[p])).collect()
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let grid = grid();
+        let goal = Point::new(2, 2);
+
+        let result = dijkstra(
+            [Point::new(0, 0)],
+            |&p| neighbours(&grid, p),
+            |&p| p == goal,
+        )
+        .unwrap();
+
+        assert_eq!(result.cost, 4);
+        assert_eq!(result.path.len(), 5);
+        assert_eq!(result.path[0], Point::new(0, 0));
+        assert_eq!(result.goal(), &goal);
+        assert_eq!(result.path[1], Point::new(1, 0));
+    }
+
+    #[test]
+    fn test_astar() {
+        let grid = grid();
+        let goal = Point::new(0, 2);
+
+        let result = astar(
+            [Point::new(2, 0)],
+            |&p| neighbours(&grid, p),
+            |p| p.manhattan(goal) as u32,
+            |&p| p == goal,
+        )
+        .unwrap();
+
+        assert_eq!(result.cost, 4);
+        assert_eq!(
+            result.path,
+            vec![
+                Point::new(2, 0),
+                Point::new(2, 1),
+                Point::new(2, 2),
+                Point::new(1, 2),
+                Point::new(0, 2),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_multiple_starts_and_goals() {
+        let grid = grid();
+
+        // The nearest goal is picked, from whichever start reaches it cheapest
+        let result = dijkstra(
+            [Point::new(0, 0), Point::new(0, 2)],
+            |&p| neighbours(&grid, p),
+            |p| p.row == 2,
+        )
+        .unwrap();
+
+        assert_eq!(result.cost, 2);
+        assert_eq!(result.path.len(), 3);
+    }
+
+    #[test]
+    fn test_unreachable_goal() {
+        let grid = grid();
+
+        assert!(dijkstra([Point::new(0, 0)], |&p| neighbours(&grid, p), |_| false).is_none());
+        assert!(bfs([Point::new(0, 0)], |&p| grid.neighbours4(p), |_| false).is_none());
+    }
+
+    #[test]
+    fn test_start_is_goal() {
+        let result = bfs([0], |&n| [n + 1], |&n| n == 0).unwrap();
+
+        assert_eq!(result.cost, 0);
+        assert_eq!(result.path, vec![0]);
+    }
+
+    #[test]
+    fn test_bfs() {
+        let grid = grid();
+
+        // The weights are ignored, so the path around the 9 is as short as any other
+        let result = bfs(
+            [Point::new(0, 0)],
+            |&p| grid.neighbours4(p).filter(|&n| grid[n] != 9),
+            |&p| p == Point::new(2, 2),
+        )
+        .unwrap();
+
+        assert_eq!(result.cost, 4);
+        assert_eq!(result.path.len(), 5);
+    }
+
+    #[test]
+    fn test_zero_one_bfs() {
+        // Walking east is free, turning costs one
+        let result = zero_one_bfs(
+            [(Point::new(0usize, 0usize), CardinalDirection::East)],
+            |&(p, direction)| {
+                let mut next = vec![
+                    ((p, direction.turn_left()), 1),
+                    ((p, direction.turn_right()), 1),
+                ];
+                if let Some(p) = p.step(direction, 1).filter(|p| p.row < 3 && p.col < 3) {
+                    next.push(((p, direction), 0));
+                }
+                next
+            },
+            |&(p, _)| p == Point::new(2, 2),
+        )
+        .unwrap();
+
+        assert_eq!(result.cost, 1);
+    }
+}
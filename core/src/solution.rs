@@ -0,0 +1,239 @@
+use crate::error_handling::AdventError;
+use crate::prelude::Result;
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// A day of the puzzle, where the input is parsed once and shared by both parts
+pub trait Solution {
+    /// What the input is parsed into
+    type Parsed;
+
+    fn parse(input: &str) -> Result<Self::Parsed>;
+    fn part1(parsed: &Self::Parsed) -> Result<Answer>;
+    fn part2(parsed: &Self::Parsed) -> Result<Answer>;
+
+    /// Solve part 1 with parameters that change the puzzle, which days without any reject
+    fn part1_with(parsed: &Self::Parsed, params: &Params) -> Result<Answer> {
+        params.allow(&[])?;
+        Self::part1(parsed)
+    }
+
+    /// Solve part 2 with parameters that change the puzzle, which days without any reject
+    fn part2_with(parsed: &Self::Parsed, params: &Params) -> Result<Answer> {
+        params.allow(&[])?;
+        Self::part2(parsed)
+    }
+
+    /// Describe the parsed input for debugging, like a graph of it, if the day has a way to
+    fn explain(_parsed: &Self::Parsed) -> Result<Option<String>> {
+        Ok(None)
+    }
+
+    /// Parse the input and solve part 1
+    fn solve_part1(input: &str) -> Result<Answer> {
+        Self::part1(&Self::parse(input)?)
+    }
+
+    /// Parse the input and solve part 2
+    fn solve_part2(input: &str) -> Result<Answer> {
+        Self::part2(&Self::parse(input)?)
+    }
+}
+
+/// Named numbers that change a puzzle, for examples that are a smaller version of it
+///
+/// Like prob11's examples, which expand the image by 10 or 100 rather than a million
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Params {
+    values: BTreeMap<String, i64>,
+}
+
+impl Params {
+    /// The value of a parameter, or `default` if it isn't set
+    pub fn get_or<T: TryFrom<i64>>(&self, name: &str, default: T) -> Result<T> {
+        match self.values.get(name) {
+            Some(&value) => T::try_from(value)
+                .map_err(|_| error!("Parameter {} is out of range: {}", name, value)),
+            None => Ok(default),
+        }
+    }
+
+    /// Check that no other parameters than these are set, so a misspelt one isn't ignored
+    pub fn allow(&self, names: &[&str]) -> Result<()> {
+        match self
+            .values
+            .keys()
+            .find(|name| !names.contains(&name.as_str()))
+        {
+            Some(name) => Err(error!("Unknown parameter: {}", name)),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<K: Into<String>> FromIterator<(K, i64)> for Params {
+    fn from_iter<I: IntoIterator<Item = (K, i64)>>(iter: I) -> Self {
+        Self {
+            values: iter
+                .into_iter()
+                .map(|(name, value)| (name.into(), value))
+                .collect(),
+        }
+    }
+}
+
+/// The answer to a part, whatever type it was worked out as
+///
+/// Numbers compare by value, so `Signed(5)`, `Unsigned(5)` and `Big(5)` are all equal
+#[derive(Debug, Clone)]
+pub enum Answer {
+    Signed(i64),
+    Unsigned(u64),
+    Big(u128),
+    Text(String),
+}
+
+impl Answer {
+    /// The sign and magnitude of a number, so answers of different types can be compared
+    fn as_number(&self) -> Option<(bool, u128)> {
+        match *self {
+            Answer::Signed(n) => Some((n < 0, u128::from(n.unsigned_abs()))),
+            Answer::Unsigned(n) => Some((false, u128::from(n))),
+            Answer::Big(n) => Some((false, n)),
+            Answer::Text(_) => None,
+        }
+    }
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Answer::Text(a), Answer::Text(b)) => a == b,
+            _ => match (self.as_number(), other.as_number()) {
+                (Some(a), Some(b)) => a == b,
+                _ => false,
+            },
+        }
+    }
+}
+
+impl Eq for Answer {}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Signed(n) => write!(f, "{}", n),
+            Answer::Unsigned(n) => write!(f, "{}", n),
+            Answer::Big(n) => write!(f, "{}", n),
+            Answer::Text(text) => write!(f, "{}", text),
+        }
+    }
+}
+
+macro_rules! impl_answer_from {
+    ($variant:ident, $as:ty: $($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::$variant(value as $as)
+                }
+            }
+
+            impl PartialEq<$t> for Answer {
+                fn eq(&self, other: &$t) -> bool {
+                    self.as_number() == Answer::$variant(*other as $as).as_number()
+                }
+            }
+        )*
+    };
+}
+
+impl_answer_from!(Signed, i64: i32, i64, isize);
+impl_answer_from!(Unsigned, u64: u32, u64, usize);
+impl_answer_from!(Big, u128: u128);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+impl PartialEq<&str> for Answer {
+    fn eq(&self, other: &&str) -> bool {
+        matches!(self, Answer::Text(text) if text == other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Parsed = Vec<i64>;
+
+        fn parse(input: &str) -> Result<Self::Parsed> {
+            Ok(input
+                .split(',')
+                .map(str::parse)
+                .collect::<std::result::Result<_, _>>()?)
+        }
+
+        fn part1(parsed: &Self::Parsed) -> Result<Answer> {
+            Ok(parsed.iter().sum::<i64>().into())
+        }
+
+        fn part2(parsed: &Self::Parsed) -> Result<Answer> {
+            Ok(format!("{} numbers", parsed.len()).into())
+        }
+    }
+
+    #[test]
+    fn test_solve() {
+        assert_eq!(Sum::solve_part1("1,2,-5").unwrap(), -2);
+        assert_eq!(Sum::solve_part2("1,2,-5").unwrap(), "3 numbers");
+        assert!(Sum::solve_part1("1,x").is_err());
+    }
+
+    #[test]
+    fn test_params() {
+        let params: Params = [("scale", 10), ("steps", -1)].into_iter().collect();
+
+        assert_eq!(params.get_or("scale", 2usize).unwrap(), 10);
+        assert_eq!(params.get_or("other", 2usize).unwrap(), 2);
+        assert!(params.get_or("steps", 2usize).is_err());
+        assert!(params.allow(&["scale", "steps"]).is_ok());
+        assert_eq!(
+            params.allow(&["scale"]).unwrap_err().to_string(),
+            "Error: Unknown parameter: steps"
+        );
+
+        // Days without parameters reject any
+        assert!(Sum::part1_with(&vec![1, 2], &Params::default()).is_ok());
+        assert!(Sum::part1_with(&vec![1, 2], &params).is_err());
+    }
+
+    #[test]
+    fn test_answers_compare_by_value() {
+        assert_eq!(Answer::Signed(5), Answer::Unsigned(5));
+        assert_eq!(Answer::Big(5), 5u32);
+        assert_eq!(Answer::from(0i64), Answer::Unsigned(0));
+        assert_ne!(Answer::Signed(-5), Answer::Unsigned(5));
+        assert_ne!(Answer::Unsigned(5), Answer::Text("5".to_string()));
+        assert_eq!(Answer::Big(167_409_079_868_000), 167_409_079_868_000usize);
+    }
+
+    #[test]
+    fn test_answer_display() {
+        assert_eq!(Answer::Signed(-12).to_string(), "-12");
+        assert_eq!(Answer::Big(u128::MAX).to_string(), u128::MAX.to_string());
+        assert_eq!(Answer::from("abc").to_string(), "abc");
+    }
+}
@@ -1,24 +1,14 @@
-use crate::types::Coordinate;
-use std::cmp::PartialOrd;
-use std::ops::{Add, Neg, Sub};
+use crate::point::{Integer, Point};
 
 /// Calculate the manhattan distance between two coordinates
-pub fn manhattan_distance<T>(from: Coordinate<T>, to: Coordinate<T>) -> T
+///
+/// The coordinates can either be points or (row, col) tuples
+pub fn manhattan_distance<T, P>(from: P, to: P) -> T
 where
-    T: Sub<Output = T> + Add<Output = T> + Neg<Output = T> + PartialOrd + Copy,
+    T: Integer,
+    P: Into<Point<T>>,
 {
-    let x = if from.0 > to.0 {
-        from.0 - to.0
-    } else {
-        to.0 - from.0
-    };
-    let y = if from.1 > to.1 {
-        from.1 - to.1
-    } else {
-        to.1 - from.1
-    };
-
-    x + y
+    from.into().manhattan(to.into())
 }
 
 #[cfg(test)]
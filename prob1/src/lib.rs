@@ -1,17 +1,5 @@
 use advent::prelude::*;
 
-const INPUT: &str = include_str!("../input.txt");
-
-fn main() -> Result<()> {
-    println!("## Part 1");
-    println!(" > {}", part1(INPUT)?);
-
-    println!("## Part 2");
-    println!(" > {}", part2(INPUT)?);
-
-    Ok(())
-}
-
 // Create a text to digit map, converting digits "one" to "nine" to 1 to 9
 const TEXT_TO_DIGIT_MAP: [(&str, u32); 9] = [
     ("one", 1),
@@ -25,8 +13,26 @@ const TEXT_TO_DIGIT_MAP: [(&str, u32); 9] = [
     ("nine", 9),
 ];
 
-fn part1(input: &str) -> Result<u32> {
-    input.lines().try_fold(0, |acc, line| {
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(input.lines().map(str::to_string).collect())
+    }
+
+    fn part1(lines: &Self::Parsed) -> Result<Answer> {
+        Ok(part1(lines)?.into())
+    }
+
+    fn part2(lines: &Self::Parsed) -> Result<Answer> {
+        Ok(part2(lines)?.into())
+    }
+}
+
+fn part1(lines: &[String]) -> Result<u32> {
+    lines.iter().try_fold(0, |acc, line| {
         // Get the left most and right most number in the string
         let numbers: Result<Vec<u32>> = line
             .chars()
@@ -45,10 +51,10 @@ fn part1(input: &str) -> Result<u32> {
     })
 }
 
-fn part2(input: &str) -> Result<u32> {
-    input.lines().try_fold(0, |acc, line| {
+fn part2(lines: &[String]) -> Result<u32> {
+    lines.iter().try_fold(0, |acc, line| {
         let mut numbers: Vec<u32> = Vec::new();
-        for (idx, char) in line.chars().enumerate() {
+        for (idx, char) in line.char_indices() {
             if char.is_ascii_digit() {
                 numbers.push(char.to_digit(10).ok_or(AdventError::ConversionError)?);
                 continue;
@@ -68,21 +74,3 @@ fn part2(input: &str) -> Result<u32> {
             + numbers.last().ok_or(AdventError::NoNumbers)?)
     })
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const PART_1_TEST_INPUT: &str = include_str!("../part_1_test.txt");
-    const PART_2_TEST_INPUT: &str = include_str!("../part_2_test.txt");
-
-    #[test]
-    fn test_part1() {
-        assert_eq!(part1(PART_1_TEST_INPUT).unwrap(), 142);
-    }
-
-    #[test]
-    fn test_part2() {
-        assert_eq!(part2(PART_2_TEST_INPUT).unwrap(), 281);
-    }
-}
@@ -0,0 +1,296 @@
+use advent::geometry::Polygon;
+use advent::prelude::*;
+
+#[derive(Debug, PartialEq, Clone)]
+enum Pipe {
+    Horizontal,
+    Vertical,
+    CornerNorthEast,
+    CornerNorthWest,
+    CornerSouthEast,
+    CornerSouthWest,
+    Start,
+    None,
+}
+
+impl TryFrom<char> for Pipe {
+    type Error = AdventError;
+
+    fn try_from(c: char) -> std::result::Result<Self, Self::Error> {
+        match c {
+            '-' => Ok(Self::Horizontal),
+            '|' => Ok(Self::Vertical),
+            'L' => Ok(Self::CornerNorthEast),
+            'J' => Ok(Self::CornerNorthWest),
+            'F' => Ok(Self::CornerSouthEast),
+            '7' => Ok(Self::CornerSouthWest),
+            'S' => Ok(Self::Start),
+            '.' => Ok(Self::None),
+            _ => Err(error!("Invalid pipe: {}", c)),
+        }
+    }
+}
+
+impl Pipe {
+    fn connects_to(&self) -> HashSet<CardinalDirection> {
+        match self {
+            Pipe::Horizontal => vec![CardinalDirection::East, CardinalDirection::West],
+            Pipe::Vertical => vec![CardinalDirection::North, CardinalDirection::South],
+            Pipe::CornerNorthEast => vec![CardinalDirection::North, CardinalDirection::East],
+            Pipe::CornerNorthWest => vec![CardinalDirection::North, CardinalDirection::West],
+            Pipe::CornerSouthEast => vec![CardinalDirection::South, CardinalDirection::East],
+            Pipe::CornerSouthWest => vec![CardinalDirection::South, CardinalDirection::West],
+            _ => vec![],
+        }
+        .into_iter()
+        .collect()
+    }
+}
+
+// Coordinates are (row, col)
+pub struct PipeMap {
+    nodes: Grid<Pipe>,
+}
+
+impl FromStr for PipeMap {
+    type Err = AdventError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(Self { nodes: s.parse()? })
+    }
+}
+
+impl PipeMap {
+    /// Find the start node and return the coordinate
+    ///
+    /// The start node is the 'S' node
+    fn find_start(&self) -> Result<Point<usize>> {
+        self.nodes
+            .position(|node| *node == Pipe::Start)
+            .ok_or(error!("Start node not found"))
+    }
+
+    /// Get the node at the given coordinate
+    fn get_node(&self, coord: Point<usize>) -> Result<&Pipe> {
+        self.nodes
+            .get(coord)
+            .ok_or(error!("Invalid coordinate: {}", coord))
+    }
+
+    /// Look at the nodes around to see which node is the next one
+    fn get_next_node(
+        &self,
+        current_node_coord: Point<usize>,
+        came_from_direction: CardinalDirection,
+    ) -> Result<(Point<usize>, CardinalDirection)> {
+        let current_node = self.get_node(current_node_coord)?;
+        if *current_node == Pipe::None || *current_node == Pipe::Start {
+            return Err(error!("Invalid node: {:?}", current_node));
+        }
+
+        // Get the next direction for this pipe
+        let binding = &vec![came_from_direction].into_iter().collect();
+        let connects_to = current_node.connects_to();
+        let next_direction = connects_to
+            .difference(binding)
+            .next()
+            .ok_or_else(|| error!("No next direction found for {:?}", current_node))?;
+
+        // Check if that next direction is valid
+        let next_node_coord =
+            self.nodes
+                .step(current_node_coord, *next_direction)
+                .ok_or(error!(
+                    "Invalid next node from {} going {:?}",
+                    current_node_coord, next_direction
+                ))?;
+
+        let next_node = self.get_node(next_node_coord)?;
+        if *next_node == Pipe::None {
+            return Err(error!("Invalid next node: {:?}", next_node));
+        }
+
+        Ok((next_node_coord, next_direction.opposite()))
+    }
+
+    // Get the two directions that connect to the start
+    fn get_start_directions(&self) -> Result<Vec<CardinalDirection>> {
+        let start_coord = self.find_start()?;
+        let mut directions = vec![];
+
+        // Check the four nodes around to see if any of them connect back
+
+        // North node
+        if let Some(north_coord) = self.nodes.step(start_coord, CardinalDirection::North) {
+            let north_node = self.get_node(north_coord)?;
+            if north_node.connects_to().contains(&CardinalDirection::South) {
+                directions.push(CardinalDirection::North);
+            }
+        }
+
+        // South node
+        if let Some(south_coord) = self.nodes.step(start_coord, CardinalDirection::South) {
+            let south_node = self.get_node(south_coord)?;
+            if south_node.connects_to().contains(&CardinalDirection::North) {
+                directions.push(CardinalDirection::South);
+            }
+        }
+
+        // West node
+        if let Some(west_coord) = self.nodes.step(start_coord, CardinalDirection::West) {
+            let west_node = self.get_node(west_coord)?;
+            if west_node.connects_to().contains(&CardinalDirection::East) {
+                directions.push(CardinalDirection::West);
+            }
+        }
+
+        // East node
+        if let Some(east_coord) = self.nodes.step(start_coord, CardinalDirection::East) {
+            let east_node = self.get_node(east_coord)?;
+            if east_node.connects_to().contains(&CardinalDirection::West) {
+                directions.push(CardinalDirection::East);
+            }
+        }
+
+        if directions.len() != 2 {
+            return Err(error!(
+                "Invalid number of directions found: {:?} - expected 2!",
+                directions
+            ));
+        }
+
+        Ok(directions)
+    }
+
+    /// Follow the loop from the start node until we get back to it
+    ///
+    /// Returns the coordinates of every node in the loop, in order, starting with the start node
+    fn find_loop(&self) -> Result<Vec<Point<usize>>> {
+        let start_coord = self.find_start()?;
+        let direction = self.get_start_directions()?[0]; // We'll just pick one direction
+
+        let mut tiles = vec![start_coord];
+        let mut current_coord = self
+            .nodes
+            .step(start_coord, direction)
+            .ok_or(error!("Invalid start coordinate: {}", start_coord))?;
+        let mut from_direction = direction.opposite();
+
+        while current_coord != start_coord {
+            tiles.push(current_coord);
+            (current_coord, from_direction) = self.get_next_node(current_coord, from_direction)?;
+        }
+
+        Ok(tiles)
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = PipeMap;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        input.parse()
+    }
+
+    fn part1(map: &Self::Parsed) -> Result<Answer> {
+        Ok(part1(map)?.into())
+    }
+
+    fn part2(map: &Self::Parsed) -> Result<Answer> {
+        Ok(part2(map)?.into())
+    }
+}
+
+fn part1(map: &PipeMap) -> Result<u32> {
+    let start_coord = map.find_start()?;
+    let start_directions = map.get_start_directions()?;
+    let mut steps = 1;
+
+    let mut a_coord = map
+        .nodes
+        .step(start_coord, start_directions[0])
+        .ok_or(error!("Invalid start coordinate: {}", start_coord))?;
+    let mut a_from_direction = start_directions[0].opposite();
+
+    let mut b_coord = map
+        .nodes
+        .step(start_coord, start_directions[1])
+        .ok_or(error!("Invalid start coordinate: {}", start_coord))?;
+    let mut b_from_direction = start_directions[1].opposite();
+
+    // We'll continue stepping in each direction until they converge
+    while a_coord != b_coord {
+        (a_coord, a_from_direction) = map.get_next_node(a_coord, a_from_direction)?;
+        (b_coord, b_from_direction) = map.get_next_node(b_coord, b_from_direction)?;
+
+        steps += 1;
+    }
+    Ok(steps)
+}
+
+fn part2(map: &PipeMap) -> Result<usize> {
+    // The loop is a polygon with a vertex at every tile, so the enclosed tiles are the points
+    // strictly inside it
+    let polygon = Polygon::new(
+        map.find_loop()?
+            .into_iter()
+            .map(|tile| Point::new(tile.row as i64, tile.col as i64))
+            .collect(),
+    );
+
+    Ok(polygon.interior_points() as usize)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PART_1_TEST_INPUT: &str = include_str!("../part_1_test.txt");
+
+    #[test]
+    fn test_pipemap_find_loop() {
+        let map: PipeMap = PART_1_TEST_INPUT.parse().unwrap();
+
+        let tiles = map.find_loop().unwrap();
+
+        assert_eq!(tiles.len(), 16);
+        assert_eq!(tiles[0], Point::new(2, 0));
+        assert_eq!(tiles[1], Point::new(3, 0));
+    }
+
+    #[test]
+    fn test_pipemap_find_start() {
+        let map: PipeMap = PART_1_TEST_INPUT.parse().unwrap();
+
+        assert_eq!(map.find_start().unwrap(), Point::new(2, 0));
+    }
+
+    #[test]
+    fn test_pipemap_get_next_node() {
+        let map: PipeMap = PART_1_TEST_INPUT.parse().unwrap();
+
+        let node = Point::new(1, 1);
+
+        let (next_node, came_from) = map.get_next_node(node, CardinalDirection::South).unwrap();
+        assert_eq!(next_node, Point::new(1, 2));
+        assert_eq!(came_from, CardinalDirection::West);
+
+        let (next_node, came_from) = map.get_next_node(node, CardinalDirection::East).unwrap();
+        assert_eq!(next_node, Point::new(2, 1));
+        assert_eq!(came_from, CardinalDirection::North);
+    }
+
+    #[test]
+    fn test_get_start_directions() {
+        let map: PipeMap = PART_1_TEST_INPUT.parse().unwrap();
+
+        let directions = map.get_start_directions().unwrap();
+
+        assert_eq!(
+            directions,
+            vec![CardinalDirection::South, CardinalDirection::East]
+        );
+    }
+}
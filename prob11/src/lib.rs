@@ -1,20 +1,12 @@
 use advent::prelude::*;
 
-const INPUT: &str = include_str!("../input.txt");
-
-#[derive(Debug, PartialEq, Clone, Copy)]
-struct Node {
-    galaxy: bool,
-    distance: usize,
-}
-
 #[derive(Debug, Default)]
 struct DistanceMap {
-    map: HashMap<(Coordinate<usize>, Coordinate<usize>), usize>,
+    map: HashMap<(Point<usize>, Point<usize>), usize>,
 }
 
 impl DistanceMap {
-    fn insert(&mut self, from: Coordinate<usize>, to: Coordinate<usize>, distance: usize) {
+    fn insert(&mut self, from: Point<usize>, to: Point<usize>, distance: usize) {
         if from > to {
             self.map.insert((to, from), distance);
         } else {
@@ -24,7 +16,8 @@ impl DistanceMap {
 }
 
 /// An image is a list of strings, which contain "." for empty space and "#" for a galaxy
-struct Image {
+#[derive(Clone)]
+pub struct Image {
     map: Vec<Vec<bool>>,
     row_scale: Vec<usize>,
     column_scale: Vec<usize>,
@@ -53,11 +46,11 @@ impl Image {
         let mut distance_map = DistanceMap::default();
 
         // Find all the galaxies
-        let mut galaxy_coords: Vec<Coordinate<usize>> = vec![];
+        let mut galaxy_coords: Vec<Point<usize>> = vec![];
         for (y, row) in self.map.iter().enumerate() {
             for (x, &galaxy) in row.iter().enumerate() {
                 if galaxy {
-                    galaxy_coords.push((x, y));
+                    galaxy_coords.push(Point::from_xy(x, y));
                 }
             }
         }
@@ -75,20 +68,20 @@ impl Image {
                 // Check if we would cross any nodes that are scaled
                 let mut distance = 0;
 
-                let (mut x, x_end) = if left_coord.0 < right_coord.0 {
-                    (left_coord.0, right_coord.0)
+                let (mut x, x_end) = if left_coord.x() < right_coord.x() {
+                    (left_coord.x(), right_coord.x())
                 } else {
-                    (right_coord.0, left_coord.0)
+                    (right_coord.x(), left_coord.x())
                 };
                 while x < x_end {
                     distance += self.column_scale[x];
                     x += 1;
                 }
 
-                let (mut y, y_end) = if left_coord.1 < right_coord.1 {
-                    (left_coord.1, right_coord.1)
+                let (mut y, y_end) = if left_coord.y() < right_coord.y() {
+                    (left_coord.y(), right_coord.y())
                 } else {
-                    (right_coord.1, left_coord.1)
+                    (right_coord.y(), left_coord.y())
                 };
                 while y < y_end {
                     distance += self.row_scale[y];
@@ -107,43 +100,87 @@ impl FromStr for Image {
     type Err = AdventError;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
-        let map: Vec<_> = s
+        let map = s
             .lines()
-            .map(|line| line.chars().map(|c| c == '#').collect())
-            .collect();
+            .map(|line| {
+                line.char_indices()
+                    .map(|(idx, c)| match c {
+                        '#' => Ok(true),
+                        '.' => Ok(false),
+                        _ => Err(parse_error!(
+                            format!("Unknown pixel: {}", c),
+                            s,
+                            &line[idx..idx + c.len_utf8()]
+                        )),
+                    })
+                    .collect::<Result<Vec<_>>>()
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let width = map
+            .first()
+            .map(Vec::len)
+            .ok_or(parse_error!("Unable to parse an empty image"))?;
+        if let Some((idx, line)) = s
+            .lines()
+            .enumerate()
+            .find(|&(idx, _)| map[idx].len() != width)
+        {
+            return Err(parse_error!(
+                format!(
+                    "Row {} has {} pixels, expected {}",
+                    idx,
+                    map[idx].len(),
+                    width
+                ),
+                s,
+                line
+            ));
+        }
 
         Ok(Image {
-            map: map.clone(),
             row_scale: vec![1; map.len()],
-            column_scale: vec![1; map[0].len()],
+            column_scale: vec![1; width],
+            map,
         })
     }
 }
 
-fn main() -> Result<()> {
-    println!("## Part 1");
-    println!(" > {}", part1(INPUT)?);
+pub struct Day;
 
-    println!("## Part 2");
-    println!(" > {}", part2(INPUT)?);
+impl Solution for Day {
+    type Parsed = Image;
 
-    Ok(())
-}
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        input.parse()
+    }
 
-fn part1(input: &str) -> Result<usize> {
-    let mut image: Image = input.parse()?;
+    fn part1(image: &Self::Parsed) -> Result<Answer> {
+        Self::part1_with(image, &Params::default())
+    }
 
-    image.set_scale(2);
+    fn part2(image: &Self::Parsed) -> Result<Answer> {
+        Self::part2_with(image, &Params::default())
+    }
 
-    let distance_map = image.get_distance_map()?;
+    fn part1_with(image: &Self::Parsed, params: &Params) -> Result<Answer> {
+        Ok(sum_of_distances(image, expansion(params, 2)?)?.into())
+    }
 
-    Ok(distance_map.map.values().sum())
+    fn part2_with(image: &Self::Parsed, params: &Params) -> Result<Answer> {
+        Ok(sum_of_distances(image, expansion(params, 1000000)?)?.into())
+    }
+}
+
+/// How many times larger each empty row and column gets, which the examples set lower
+fn expansion(params: &Params, default: usize) -> Result<usize> {
+    params.allow(&["expansion"])?;
+    params.get_or("expansion", default)
 }
 
-fn part2(input: &str) -> Result<usize> {
-    let mut image: Image = input.parse()?;
+fn sum_of_distances(image: &Image, expansion: usize) -> Result<usize> {
+    let mut image = image.clone();
 
-    image.set_scale(1000000);
+    image.set_scale(expansion);
 
     let distance_map = image.get_distance_map()?;
 
@@ -156,22 +193,6 @@ mod tests {
 
     const TEST_INPUT: &str = include_str!("../test.txt");
 
-    #[test]
-    fn test_part1() {
-        assert_eq!(part1(TEST_INPUT).unwrap(), 374);
-    }
-
-    #[test]
-    fn test_part2() {
-        let mut image: Image = TEST_INPUT.parse().unwrap();
-
-        image.set_scale(100);
-
-        let distance_map = image.get_distance_map().unwrap();
-
-        assert_eq!(distance_map.map.values().sum::<usize>(), 8410)
-    }
-
     #[test]
     fn test_image_from_str() {
         let image: Image = TEST_INPUT.parse().unwrap();
@@ -183,6 +204,15 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_image_ragged_rows() {
+        let err = "..#\n.#\n...".parse::<Image>().err().unwrap();
+        assert_eq!(err.span(), Some(&Span::new(2, 0..2, ".#")));
+        assert!(err.to_string().contains("Row 1 has 2 pixels, expected 3"));
+
+        assert!(".\n\n".parse::<Image>().is_err());
+    }
+
     #[test]
     fn test_image_expand_base_test() {
         // ...
@@ -216,8 +246,8 @@ mod tests {
     fn test_distance_map_inserts_by_sorted_tuple() {
         let mut distance_map = DistanceMap::default();
 
-        distance_map.insert((0, 0), (1, 0), 1);
-        distance_map.insert((1, 0), (0, 0), 1);
+        distance_map.insert(Point::from_xy(0, 0), Point::from_xy(1, 0), 1);
+        distance_map.insert(Point::from_xy(1, 0), Point::from_xy(0, 0), 1);
 
         assert_eq!(distance_map.map.len(), 1);
     }
@@ -1,7 +1,6 @@
+use advent::parallel;
 use advent::prelude::*;
 
-const INPUT: &str = include_str!("../input.txt");
-
 #[derive(Debug, Clone, PartialEq)]
 enum Condition {
     Operational,
@@ -9,19 +8,21 @@ enum Condition {
     Unknown,
 }
 
-impl From<char> for Condition {
-    fn from(c: char) -> Self {
+impl TryFrom<char> for Condition {
+    type Error = AdventError;
+
+    fn try_from(c: char) -> std::result::Result<Self, Self::Error> {
         match c {
-            '#' => Self::Damaged,
-            '.' => Self::Operational,
-            '?' => Self::Unknown,
-            _ => panic!("Invalid condition: {}", c),
+            '#' => Ok(Self::Damaged),
+            '.' => Ok(Self::Operational),
+            '?' => Ok(Self::Unknown),
+            _ => Err(parse_error!(format!("Invalid condition: {}", c))),
         }
     }
 }
 
-#[derive(Debug)]
-struct ConditionInfo {
+#[derive(Debug, Clone)]
+pub struct ConditionInfo {
     conditions: Vec<Condition>,
     conditions_len: usize,
     counts: Vec<usize>,
@@ -117,20 +118,20 @@ impl FromStr for ConditionInfo {
     ///
     /// which represents the springs conditions on the left and the counts on the right
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
-        let mut parts = s.split_whitespace();
-        let conditions: Vec<Condition> = parts
-            .next()
-            .ok_or(parse_error!("Unable to get springs from line"))?
-            .chars()
-            .map(Condition::from)
-            .collect();
-
-        let counts = parts
-            .next()
-            .ok_or(parse_error!("Unable to get spring counts from line"))?
+        let (springs, counts) =
+            s.split_once(' ')
+                .ok_or(parse_error!("Unable to get spring counts from line", s, s))?;
+        let conditions = springs
+            .char_indices()
+            .map(|(idx, c)| {
+                Condition::try_from(c).map_err(|e| e.locate(s, &springs[idx..idx + c.len_utf8()]))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let counts = counts
             .split(',')
-            .map(|s| s.parse::<usize>())
-            .collect::<std::result::Result<Vec<usize>, _>>()?;
+            .map(|count| parse_at(s, count))
+            .collect::<Result<Vec<usize>>>()?;
         let conditions_len = conditions.len();
 
         Ok(Self {
@@ -142,67 +143,46 @@ impl FromStr for ConditionInfo {
     }
 }
 
-fn main() -> Result<()> {
-    println!("## Part 1");
-    println!(" > {}", part1(INPUT)?);
-
-    println!("## Part 2");
-    println!(" > {}", part2(INPUT)?);
+pub struct Day;
 
-    Ok(())
-}
+impl Solution for Day {
+    type Parsed = Vec<ConditionInfo>;
 
-fn part1(input: &str) -> Result<usize> {
-    let mut infos: Vec<ConditionInfo> = input
-        .lines()
-        .map(|l| l.parse::<ConditionInfo>())
-        .collect::<Result<Vec<_>>>()?;
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        parse_lines(input, str::parse).context("parsing condition records")
+    }
 
-    let sum_of_options = infos
-        .iter_mut()
-        .map(|info| info.backtrack(0, 0, 0))
-        .collect::<Result<Vec<_>>>()?
-        .iter()
-        .sum::<usize>();
+    fn part1(infos: &Self::Parsed) -> Result<Answer> {
+        Ok(part1(infos)?.into())
+    }
 
-    Ok(sum_of_options)
+    fn part2(infos: &Self::Parsed) -> Result<Answer> {
+        Ok(part2(infos)?.into())
+    }
 }
 
-fn part2(input: &str) -> Result<usize> {
-    let mut infos: Vec<ConditionInfo> = input
-        .lines()
-        .map(|l| l.parse::<ConditionInfo>())
-        .collect::<Result<Vec<_>>>()?;
-
-    // Expand all infos by 5
-    infos.iter_mut().for_each(|info| info.expand(5));
+fn part1(infos: &[ConditionInfo]) -> Result<usize> {
+    sum_of_options(infos, 1)
+}
 
-    let sum_of_options = infos
-        .iter_mut()
-        .map(|info| info.backtrack(0, 0, 0))
-        .collect::<Result<Vec<_>>>()?
-        .iter()
-        .sum::<usize>();
+fn part2(infos: &[ConditionInfo]) -> Result<usize> {
+    sum_of_options(infos, 5)
+}
 
-    Ok(sum_of_options)
+/// The sum of the options of every info after expanding it, which are worked out independently
+fn sum_of_options(infos: &[ConditionInfo], times: usize) -> Result<usize> {
+    parallel::map(infos, |info| {
+        let mut info = info.clone();
+        info.expand(times);
+        info.backtrack(0, 0, 0)
+    })
+    .sum()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const TEST_INPUT: &str = include_str!("../test.txt");
-
-    #[test]
-    fn test_part1() {
-        assert_eq!(part1(TEST_INPUT).unwrap(), 21);
-    }
-
-    #[test]
-    fn test_part2() {
-        assert_eq!(part2(TEST_INPUT).unwrap(), 525152);
-    }
-
     #[test]
     fn test_condition_parsing() {
         let input = "????.##.##?.. 2,2,3";
@@ -272,4 +252,11 @@ mod tests {
         );
         assert_eq!(info.counts, vec![1, 1]);
     }
+
+    #[test]
+    fn test_condition_info_parse_error_span() {
+        let err = Day::parse("???.### 1,1,3\n.??.?x 1,1").unwrap_err();
+
+        assert_eq!(err.span(), Some(&Span::new(2, 5..6, ".??.?x 1,1")));
+    }
 }
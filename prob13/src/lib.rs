@@ -0,0 +1,200 @@
+use advent::prelude::*;
+
+/// A pattern of ash and rocks, with true for a rock
+#[derive(Debug)]
+pub struct Pattern {
+    grid: Grid<bool>,
+}
+
+impl FromStr for Pattern {
+    type Err = AdventError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let grid = Grid::parse_with(s, |c| match c {
+            '.' => Ok(false),
+            '#' => Ok(true),
+            _ => Err(parse_error!(format!("Unknown node type: {}", c))),
+        })?;
+
+        Ok(Pattern { grid })
+    }
+}
+
+/// How many nodes differ between two lines of a pattern
+fn differences(left: &[bool], right: &[bool]) -> usize {
+    left.iter().zip(right).filter(|(l, r)| l != r).count()
+}
+
+impl Pattern {
+    fn rows(&self) -> Vec<Vec<bool>> {
+        self.grid.rows().map(<[bool]>::to_vec).collect()
+    }
+
+    fn columns(&self) -> Vec<Vec<bool>> {
+        self.grid
+            .columns()
+            .map(|column| column.copied().collect())
+            .collect()
+    }
+
+    /// Find the line after which the lines mirror each other, with exactly `smudges` nodes
+    /// differing between them
+    fn get_mirror_value(lines: &[Vec<bool>], smudges: usize) -> Option<usize> {
+        (1..lines.len()).find(|&mirror| {
+            // Pair each line before the mirror with the one as far after it, until either edge
+            let before = lines[..mirror].iter().rev();
+            let after = lines[mirror..].iter();
+            before
+                .zip(after)
+                .map(|(left, right)| differences(left, right))
+                .sum::<usize>()
+                == smudges
+        })
+    }
+
+    /// Find the horizontal mirror row
+    ///
+    /// If the pattern is not mirrored horizontally, return None
+    /// otherwise return the row index where the mirror is
+    fn get_horizontal_mirror_value(&self, smudges: usize) -> Option<usize> {
+        Self::get_mirror_value(&self.rows(), smudges).map(|row| row * 100)
+    }
+
+    fn get_vertical_mirror_value(&self, smudges: usize) -> Option<usize> {
+        Self::get_mirror_value(&self.columns(), smudges)
+    }
+}
+
+/// Parse the input of multiple patterns
+///
+/// Each pattern is separated by a blank line
+fn parse_input(input: &str) -> Result<Vec<Pattern>> {
+    let patterns = input
+        .split("\n\n")
+        .enumerate()
+        .map(|(idx, pattern)| {
+            pattern
+                .parse::<Pattern>()
+                .map_err(|e| e.relocate(input, pattern))
+                .with_context(|| format!("parsing pattern {}", idx + 1))
+        })
+        .collect::<Result<Vec<Pattern>>>()?;
+
+    Ok(patterns)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Vec<Pattern>;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        parse_input(input)
+    }
+
+    fn part1(patterns: &Self::Parsed) -> Result<Answer> {
+        Ok(part1(patterns)?.into())
+    }
+
+    fn part2(patterns: &Self::Parsed) -> Result<Answer> {
+        Ok(part2(patterns)?.into())
+    }
+}
+
+fn part1(patterns: &[Pattern]) -> Result<usize> {
+    let total = patterns
+        .iter()
+        .map(|pattern| {
+            pattern.get_vertical_mirror_value(0).unwrap_or(0)
+                + pattern.get_horizontal_mirror_value(0).unwrap_or(0)
+        })
+        .sum::<usize>();
+    Ok(total)
+}
+
+fn part2(patterns: &[Pattern]) -> Result<usize> {
+    let total = patterns
+        .iter()
+        .map(|pattern| {
+            pattern.get_vertical_mirror_value(1).unwrap_or(0)
+                + pattern.get_horizontal_mirror_value(1).unwrap_or(0)
+        })
+        .sum::<usize>();
+    Ok(total)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = include_str!("../test.txt");
+
+    #[test]
+    fn test_pattern_from_str() {
+        let pattern: Pattern = "#.#\n...\n###".parse().unwrap();
+
+        assert_eq!(pattern.grid.height(), 3);
+        assert_eq!(pattern.grid.width(), 3);
+        assert_eq!(pattern.rows()[0], vec![true, false, true]);
+        assert_eq!(pattern.columns()[1], vec![false, false, true]);
+
+        let err = "#.#\n..\n###".parse::<Pattern>().unwrap_err();
+        assert_eq!(err.span(), Some(&Span::new(2, 0..2, "..")));
+    }
+
+    #[test]
+    fn test_parse_input_error_span() {
+        let err = parse_input("#.#\n...\n\n#.#\n.o.").unwrap_err();
+
+        assert_eq!(err.span(), Some(&Span::new(5, 1..2, ".o.")));
+        assert!(err.to_string().starts_with("parsing pattern 2: "));
+    }
+
+    #[test]
+    fn test_parse_input() {
+        let patterns = parse_input(TEST_INPUT).unwrap();
+
+        assert_eq!(patterns.len(), 2);
+    }
+
+    #[test]
+    fn test_get_horizontal_mirror_row() {
+        let pattern: Pattern = "#.#\n...\n...\n###".parse().unwrap();
+        assert_eq!(pattern.get_horizontal_mirror_value(0), None);
+
+        let pattern: Pattern = "#.#\n...\n...\n#.#".parse().unwrap();
+        assert_eq!(pattern.get_horizontal_mirror_value(0), Some(200));
+
+        let test_patterns: Vec<Pattern> = parse_input(TEST_INPUT).unwrap();
+
+        assert_eq!(test_patterns[0].get_horizontal_mirror_value(0), None);
+        assert_eq!(test_patterns[1].get_horizontal_mirror_value(0), Some(400));
+    }
+
+    #[test]
+    fn test_get_vertical_mirror_row() {
+        let pattern: Pattern = "#..#\n....\n....\n#.##".parse().unwrap();
+        assert_eq!(pattern.get_vertical_mirror_value(0), None);
+
+        let pattern: Pattern = "#..#\n....\n....\n#..#".parse().unwrap();
+        assert_eq!(pattern.get_vertical_mirror_value(0), Some(2));
+
+        let test_patterns: Vec<Pattern> = parse_input(TEST_INPUT).unwrap();
+
+        assert_eq!(test_patterns[0].get_vertical_mirror_value(0), Some(5));
+        assert_eq!(test_patterns[1].get_vertical_mirror_value(0), None);
+    }
+
+    #[test]
+    fn test_get_smudged_mirror_row() {
+        let test_patterns: Vec<Pattern> = parse_input(TEST_INPUT).unwrap();
+        assert_eq!(
+            Pattern::get_mirror_value(&test_patterns[0].rows(), 1),
+            Some(3)
+        );
+        assert_eq!(
+            Pattern::get_mirror_value(&test_patterns[1].rows(), 1),
+            Some(1)
+        );
+    }
+}
@@ -0,0 +1,293 @@
+use advent::cycle::{find_cycle_by, fingerprint};
+use advent::prelude::*;
+use std::str::FromStr;
+
+/// A way of turning the platform over
+type Turn = fn(&Grid<Node>) -> Grid<Node>;
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone)]
+pub struct Platform {
+    grid: Grid<Node>,
+}
+
+impl Platform {
+    /// Tilt the platform north, sliding every RoundRock up until it reaches the edge or hits
+    /// another rock
+    fn tilt_north(&mut self) {
+        for col in 0..self.grid.width() {
+            // The row the next RoundRock in this column comes to rest in
+            let mut rest = 0;
+            for row in 0..self.grid.height() {
+                match self.grid[(row, col)] {
+                    Node::CubeRock => rest = row + 1,
+                    Node::RoundRock => {
+                        self.grid[(row, col)] = Node::Space;
+                        self.grid[(rest, col)] = Node::RoundRock;
+                        rest += 1;
+                    }
+                    Node::Space => {}
+                }
+            }
+        }
+    }
+
+    /// Tilt the platform in the given direction.
+    ///
+    /// The platform is turned so that the direction faces north, tilted north and turned back
+    fn tilt_platform(&mut self, direction: &CardinalDirection) {
+        let (turn, turn_back): (Turn, Turn) = match direction {
+            CardinalDirection::North => {
+                self.tilt_north();
+                return;
+            }
+            CardinalDirection::South => (Grid::flip_vertical, Grid::flip_vertical),
+            CardinalDirection::West => (Grid::rotate_clockwise, Grid::rotate_counter_clockwise),
+            CardinalDirection::East => (Grid::rotate_counter_clockwise, Grid::rotate_clockwise),
+        };
+
+        self.grid = turn(&self.grid);
+        self.tilt_north();
+        self.grid = turn_back(&self.grid);
+    }
+
+    /// Run a full spin cycle, tilting the platform north, west, south and then east
+    fn spin_cycle(&self) -> Result<Self> {
+        let mut platform = self.clone();
+        for direction in [
+            CardinalDirection::North,
+            CardinalDirection::West,
+            CardinalDirection::South,
+            CardinalDirection::East,
+        ] {
+            platform.tilt_platform(&direction);
+        }
+
+        Ok(platform)
+    }
+
+    /// Calculate platform load
+    ///
+    /// The load caused by a single RoundRock is equal to the number of rows from the south edge
+    fn get_load(&self) -> usize {
+        self.grid
+            .iter()
+            .filter(|(_, node)| **node == Node::RoundRock)
+            .map(|(point, _)| self.grid.height() - point.y())
+            .sum()
+    }
+}
+
+impl FromStr for Platform {
+    type Err = AdventError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(Platform {
+            grid: Grid::parse_with(s, Node::try_from)?,
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone)]
+enum Node {
+    RoundRock, // O
+    CubeRock,  // #
+    Space,     // .
+}
+
+impl TryFrom<char> for Node {
+    type Error = AdventError;
+
+    fn try_from(c: char) -> std::result::Result<Self, Self::Error> {
+        match c {
+            'O' => Ok(Node::RoundRock),
+            '#' => Ok(Node::CubeRock),
+            '.' => Ok(Node::Space),
+            _ => Err(parse_error!(format!("Invalid node type: {}", c))),
+        }
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Platform;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        input.parse()
+    }
+
+    fn part1(platform: &Self::Parsed) -> Result<Answer> {
+        Ok(part1(platform)?.into())
+    }
+
+    fn part2(platform: &Self::Parsed) -> Result<Answer> {
+        Ok(part2(platform)?.into())
+    }
+}
+
+fn part1(platform: &Platform) -> Result<usize> {
+    let mut platform = platform.clone();
+
+    platform.tilt_platform(&CardinalDirection::North);
+
+    Ok(platform.get_load())
+}
+
+fn part2(platform: &Platform) -> Result<usize> {
+    // The platform ends up in a loop of states after some spin cycles, so we only store the load
+    // of each state and look it up for the billionth cycle
+    let cycle = find_cycle_by(
+        platform.clone(),
+        Platform::spin_cycle,
+        fingerprint,
+        Platform::get_load,
+    )?;
+
+    Ok(*cycle.at(1_000_000_000))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_platform_from_str() {
+        let platform: Platform = "O..#\n....\n#OO#".parse().unwrap();
+
+        assert_eq!(platform.grid.height(), 3);
+        assert_eq!(
+            platform.grid.row(0).unwrap(),
+            vec![Node::RoundRock, Node::Space, Node::Space, Node::CubeRock]
+        );
+        assert_eq!(
+            platform.grid.row(1).unwrap(),
+            vec![Node::Space, Node::Space, Node::Space, Node::Space]
+        );
+        assert_eq!(
+            platform.grid.row(2).unwrap(),
+            vec![
+                Node::CubeRock,
+                Node::RoundRock,
+                Node::RoundRock,
+                Node::CubeRock
+            ]
+        );
+    }
+
+    #[test]
+    fn test_platform_tilt_north() {
+        let mut platform: Platform = "O..#\n....\n#OO#".parse().unwrap();
+        platform.tilt_platform(&CardinalDirection::North);
+
+        assert_eq!(platform.grid.height(), 3);
+        assert_eq!(
+            platform.grid.row(0).unwrap(),
+            vec![
+                Node::RoundRock,
+                Node::RoundRock,
+                Node::RoundRock,
+                Node::CubeRock
+            ]
+        );
+        assert_eq!(
+            platform.grid.row(1).unwrap(),
+            vec![Node::Space, Node::Space, Node::Space, Node::Space]
+        );
+        assert_eq!(
+            platform.grid.row(2).unwrap(),
+            vec![Node::CubeRock, Node::Space, Node::Space, Node::CubeRock]
+        );
+    }
+
+    #[test]
+    fn test_platform_tilt_south() {
+        let mut platform: Platform = "O..#\n....\n#OO#".parse().unwrap();
+        platform.tilt_platform(&CardinalDirection::South);
+
+        assert_eq!(platform.grid.height(), 3);
+        assert_eq!(
+            platform.grid.row(0).unwrap(),
+            vec![Node::Space, Node::Space, Node::Space, Node::CubeRock]
+        );
+        assert_eq!(
+            platform.grid.row(1).unwrap(),
+            vec![Node::RoundRock, Node::Space, Node::Space, Node::Space]
+        );
+        assert_eq!(
+            platform.grid.row(2).unwrap(),
+            vec![
+                Node::CubeRock,
+                Node::RoundRock,
+                Node::RoundRock,
+                Node::CubeRock
+            ]
+        );
+    }
+
+    #[test]
+    fn test_platform_tilt_west() {
+        let mut platform: Platform = ".O.#\n....\n#.O#".parse().unwrap();
+        platform.tilt_platform(&CardinalDirection::West);
+
+        assert_eq!(platform.grid.height(), 3);
+        assert_eq!(
+            platform.grid.row(0).unwrap(),
+            vec![Node::RoundRock, Node::Space, Node::Space, Node::CubeRock]
+        );
+        assert_eq!(
+            platform.grid.row(1).unwrap(),
+            vec![Node::Space, Node::Space, Node::Space, Node::Space]
+        );
+        assert_eq!(
+            platform.grid.row(2).unwrap(),
+            vec![Node::CubeRock, Node::RoundRock, Node::Space, Node::CubeRock]
+        );
+    }
+
+    #[test]
+    fn test_platform_tilt_east() {
+        let mut platform: Platform = "O..#\n....\n#O.#".parse().unwrap();
+        platform.tilt_platform(&CardinalDirection::East);
+
+        assert_eq!(platform.grid.height(), 3);
+        assert_eq!(
+            platform.grid.row(0).unwrap(),
+            vec![Node::Space, Node::Space, Node::RoundRock, Node::CubeRock]
+        );
+        assert_eq!(
+            platform.grid.row(1).unwrap(),
+            vec![Node::Space, Node::Space, Node::Space, Node::Space]
+        );
+        assert_eq!(
+            platform.grid.row(2).unwrap(),
+            vec![Node::CubeRock, Node::Space, Node::RoundRock, Node::CubeRock]
+        );
+    }
+
+    #[test]
+    fn test_platform_spin_cycle() {
+        let platform: Platform = "O.\n..".parse().unwrap();
+        let platform = platform.spin_cycle().unwrap();
+
+        // The rock ends up in the south east corner after the final two tilts
+        assert_eq!(
+            platform.grid,
+            Grid::from_rows(vec![
+                vec![Node::Space, Node::Space],
+                vec![Node::Space, Node::RoundRock]
+            ])
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_platform_load() {
+        let mut platform: Platform = "O..#\n....\n#OO#".parse().unwrap();
+
+        assert_eq!(platform.get_load(), 5);
+
+        platform.tilt_platform(&CardinalDirection::North);
+
+        assert_eq!(platform.get_load(), 9);
+    }
+}
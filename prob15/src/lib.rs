@@ -1,8 +1,6 @@
 use advent::prelude::*;
 
-const INPUT: &str = include_str!("../input.txt");
-
-struct Element {
+pub struct Element {
     data: String,
     hash: u8,
     label: String,
@@ -28,7 +26,7 @@ impl FromStr for Element {
         let operation = match s.chars().find(|c| *c == '-' || *c == '=') {
             Some('-') => Operation::Subtract,
             Some('=') => Operation::Equal,
-            _ => return Err(AdventError::InvalidInput),
+            _ => return Err(parse_error!("Expected an operation of - or =")),
         };
 
         if operation == Operation::Subtract {
@@ -66,38 +64,38 @@ enum Operation {
     Subtract,
 }
 
-fn main() -> Result<()> {
-    println!("## Part 1");
-    println!(" > {}", part1(INPUT)?);
+/// Parse the comma separated elements, pointing any error at the element it came from
+fn parse_elements(input: &str) -> Result<Vec<Element>> {
+    input
+        .trim()
+        .split(',')
+        .map(|e| e.parse::<Element>().map_err(|err| err.relocate(input, e)))
+        .collect()
+}
 
-    println!("## Part 2");
-    println!(" > {}", part2(INPUT)?);
+pub struct Day;
 
-    Ok(())
-}
+impl Solution for Day {
+    type Parsed = Vec<Element>;
 
-fn part1(input: &str) -> Result<u32> {
-    let elements: Vec<Element> = input
-        .trim()
-        .split(',')
-        .map(|e| e.parse::<Element>())
-        .collect::<Result<Vec<Element>>>()?;
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        parse_elements(input)
+    }
 
-    let sum = elements.iter().fold(0, |acc, e| acc + e.hash as u32);
+    fn part1(elements: &Self::Parsed) -> Result<Answer> {
+        Ok(part1(elements).into())
+    }
 
-    Ok(sum)
+    fn part2(elements: &Self::Parsed) -> Result<Answer> {
+        Ok(part2(elements)?.into())
+    }
 }
 
-#[derive(Debug, Clone)]
-struct Lens {}
-
-fn part2(input: &str) -> Result<usize> {
-    let elements: Vec<Element> = input
-        .trim()
-        .split(',')
-        .map(|e| e.parse::<Element>())
-        .collect::<Result<Vec<Element>>>()?;
+fn part1(elements: &[Element]) -> u32 {
+    elements.iter().fold(0, |acc, e| acc + e.hash as u32)
+}
 
+fn part2(elements: &[Element]) -> Result<usize> {
     let mut boxes: Vec<Vec<(String, u8)>> = vec![Vec::new(); 256];
     for element in elements {
         let idx = element.label_hash as usize;
@@ -109,7 +107,7 @@ fn part2(input: &str) -> Result<usize> {
                 let focal_length = element
                     .focal_length
                     .ok_or(error!("Invalid focal length for element {}", element.data))?;
-                let lens = (element.label, focal_length);
+                let lens = (element.label.clone(), focal_length);
                 if let Some(i) = bx.iter().position(|e| e.0 == lens.0) {
                     bx[i] = lens;
                 } else {
@@ -138,18 +136,6 @@ fn part2(input: &str) -> Result<usize> {
 mod tests {
     use super::*;
 
-    const TEST_INPUT: &str = include_str!("../test.txt");
-
-    #[test]
-    fn test_part1() {
-        assert_eq!(part1(TEST_INPUT).unwrap(), 1320);
-    }
-
-    #[test]
-    fn test_part2() {
-        assert_eq!(part2(TEST_INPUT).unwrap(), 145);
-    }
-
     #[test]
     fn test_element_from_string() {
         let element: Element = "rn=1".parse().unwrap();
@@ -161,4 +147,11 @@ mod tests {
         assert_eq!(element.operation, Operation::Equal);
         assert_eq!(element.focal_length, Some(1));
     }
+
+    #[test]
+    fn test_parse_elements_error_span() {
+        let err = parse_elements("rn=1,cm-,qp3,pc=4").err().unwrap();
+
+        assert_eq!(err.span(), Some(&Span::new(1, 9..12, "rn=1,cm-,qp3,pc=4")));
+    }
 }
@@ -1,22 +1,11 @@
+use advent::parallel;
 use advent::prelude::*;
-use rayon::prelude::*;
 
-const INPUT: &str = include_str!("../input.txt");
-
-type Beam = (Coordinate<usize>, Direction);
+type Beam = (Point<usize>, OrdinalDirection);
 
 #[derive(Debug, PartialEq)]
-struct Layout {
-    grid: Vec<Vec<Node>>,
-}
-
-fn get_next_coordinate(beam: Beam) -> Coordinate<usize> {
-    match beam {
-        ((x, y), Direction::Right) => (x, y + 1),
-        ((x, y), Direction::Down) => (x + 1, y),
-        ((x, y), Direction::Left) => (x, y - 1),
-        ((x, y), Direction::Up) => (x - 1, y),
-    }
+pub struct Layout {
+    grid: Grid<Node>,
 }
 
 impl Layout {
@@ -35,89 +24,90 @@ impl Layout {
 
         // Handle the first node separately, as it can contain a mirror already
         let (start_coord, start_dir) = start;
-        let first_node = self.grid[start_coord.0][start_coord.1];
+        let first_node = self.grid[start_coord];
         match (first_node, start_dir) {
             (Node::Empty, _) => {
                 // We just continue in the same direction
                 queue.push_back(start);
             }
-            (Node::Horizontal, Direction::Right) | (Node::Horizontal, Direction::Left) => {
+            (Node::Horizontal, OrdinalDirection::Right)
+            | (Node::Horizontal, OrdinalDirection::Left) => {
                 // We just continue in the same direction
                 queue.push_back(start);
             }
-            (Node::Vertical, Direction::Up) | (Node::Vertical, Direction::Down) => {
+            (Node::Vertical, OrdinalDirection::Up) | (Node::Vertical, OrdinalDirection::Down) => {
                 // We just continue in the same direction
                 queue.push_back(start);
             }
             (Node::Horizontal, _) => {
-                queue.push_back((start_coord, Direction::Left));
-                queue.push_back((start_coord, Direction::Right));
+                queue.push_back((start_coord, OrdinalDirection::Left));
+                queue.push_back((start_coord, OrdinalDirection::Right));
             }
             (Node::Vertical, _) => {
-                queue.push_back((start_coord, Direction::Up));
-                queue.push_back((start_coord, Direction::Down));
+                queue.push_back((start_coord, OrdinalDirection::Up));
+                queue.push_back((start_coord, OrdinalDirection::Down));
             }
             (Node::Up, dir) => {
                 match dir {
-                    Direction::Right => {
-                        if start_coord.0 == 0 {
+                    OrdinalDirection::Right => {
+                        if start_coord.row == 0 {
                             // We are at the top, so we go off the grid immediately
                             return Ok(1);
                         }
-                        queue.push_back((start_coord, Direction::Up));
+                        queue.push_back((start_coord, OrdinalDirection::Up));
                     }
-                    Direction::Left => {
-                        if start_coord.0 >= self.grid.len() - 1 {
+                    OrdinalDirection::Left => {
+                        if start_coord.row >= self.grid.height() - 1 {
                             // We are at the bottom, so we go off the grid immediately
                             return Ok(1);
                         }
-                        queue.push_back((start_coord, Direction::Down));
+                        queue.push_back((start_coord, OrdinalDirection::Down));
                     }
-                    Direction::Down => {
-                        if start_coord.1 == 0 {
+                    OrdinalDirection::Down => {
+                        if start_coord.col == 0 {
                             // We are at the left, so we go off the grid immediately
                             return Ok(1);
                         }
-                        queue.push_back((start_coord, Direction::Left));
+                        queue.push_back((start_coord, OrdinalDirection::Left));
                     }
-                    Direction::Up => {
-                        if start_coord.1 >= self.grid[0].len() - 1 {
+                    OrdinalDirection::Up => {
+                        if start_coord.col >= self.grid.width() - 1 {
                             // We are at the right, so we go off the grid immediately
                             return Ok(1);
                         }
-                        queue.push_back((start_coord, Direction::Right));
+                        queue.push_back((start_coord, OrdinalDirection::Right));
                     }
                 }
             }
             (Node::Down, dir) => {
                 match dir {
-                    Direction::Right => {
-                        if start_coord.0 >= self.grid.len() - 1 {
+                    OrdinalDirection::Right => {
+                        if start_coord.row >= self.grid.height() - 1 {
                             // We are at the bottom, so we go off the grid immediately
                             return Ok(1);
                         }
-                        queue.push_back((start_coord, Direction::Down));
+                        queue.push_back((start_coord, OrdinalDirection::Down));
                     }
-                    Direction::Left => {
-                        if start_coord.0 == 0 {
+                    OrdinalDirection::Left => {
+                        if start_coord.row == 0 {
                             // We are at the top, so we go off the grid immediately
                             return Ok(1);
                         }
-                        queue.push_back((start_coord, Direction::Up));
+                        queue.push_back((start_coord, OrdinalDirection::Up));
                     }
-                    Direction::Down => {
-                        if start_coord.1 >= self.grid[0].len() - 1 {
+                    OrdinalDirection::Down => {
+                        if start_coord.col >= self.grid.width() - 1 {
                             // We are at the right, so we go off the grid immediately
                             return Ok(1);
                         }
-                        queue.push_back((start_coord, Direction::Right));
+                        queue.push_back((start_coord, OrdinalDirection::Right));
                     }
-                    Direction::Up => {
-                        if start_coord.1 == 0 {
+                    OrdinalDirection::Up => {
+                        if start_coord.col == 0 {
                             // We are at the left, so we go off the grid immediately
                             return Ok(1);
                         }
-                        queue.push_back((start_coord, Direction::Left));
+                        queue.push_back((start_coord, OrdinalDirection::Left));
                     }
                 }
             }
@@ -129,75 +119,73 @@ impl Layout {
             }
             paths_taken.insert(beam);
 
-            if self.beam_going_off_grid(beam) {
+            // Get the next node, unless the beam is going off the grid
+            let Some(coord) = self.grid.step(beam.0, beam.1) else {
                 continue;
-            }
-
-            // Get the next node
-            let coord = get_next_coordinate(beam);
-            let node = self.grid[coord.0][coord.1];
+            };
+            let node = self.grid[coord];
             match (node, beam.1) {
                 (Node::Empty, dir) => {
                     // Beam just continues in the same direction
                     queue.push_back((coord, dir));
                 }
                 (Node::Horizontal, dir) => {
-                    if dir == Direction::Right || dir == Direction::Left {
+                    if dir == OrdinalDirection::Right || dir == OrdinalDirection::Left {
                         // We just pass through
                         queue.push_back((coord, dir));
                     } else {
                         // The beam splits and goes left and right
-                        queue.push_back((coord, Direction::Left));
-                        queue.push_back((coord, Direction::Right));
+                        queue.push_back((coord, OrdinalDirection::Left));
+                        queue.push_back((coord, OrdinalDirection::Right));
                     }
                 }
                 (Node::Vertical, dir) => {
-                    if dir == Direction::Up || dir == Direction::Down {
+                    if dir == OrdinalDirection::Up || dir == OrdinalDirection::Down {
                         // We just pass through
                         queue.push_back((coord, dir));
                     } else {
                         // The beam splits and goes up and down
-                        queue.push_back((coord, Direction::Up));
-                        queue.push_back((coord, Direction::Down));
+                        queue.push_back((coord, OrdinalDirection::Up));
+                        queue.push_back((coord, OrdinalDirection::Down));
                     }
                 }
                 (Node::Up, dir) => {
                     match dir {
-                        Direction::Right => {
+                        OrdinalDirection::Right => {
                             // Beam goes up
-                            queue.push_back((coord, Direction::Up));
+                            queue.push_back((coord, OrdinalDirection::Up));
                         }
-                        Direction::Down => {
+                        OrdinalDirection::Down => {
                             // Beam goes left
-                            queue.push_back((coord, Direction::Left));
+                            queue.push_back((coord, OrdinalDirection::Left));
                         }
-                        Direction::Left => {
+                        OrdinalDirection::Left => {
                             // Beam goes down
-                            queue.push_back((coord, Direction::Down));
+                            queue.push_back((coord, OrdinalDirection::Down));
                         }
-                        Direction::Up => {
+                        OrdinalDirection::Up => {
                             // Beam goes right
-                            queue.push_back((coord, Direction::Right));
+                            queue.push_back((coord, OrdinalDirection::Right));
                         }
                     }
                 }
                 (Node::Down, dir) => {
                     match dir {
-                        Direction::Right => {
+                        OrdinalDirection::Right => {
                             // Beam goes down
-                            queue.push_back((coord, Direction::Down));
+                            queue.push_back((coord, OrdinalDirection::Down));
                         }
-                        Direction::Down => {
+                        OrdinalDirection::Down => {
                             // Beam goes right
-                            queue.push_back((coord, Direction::Right));
+                            queue.push_back((coord, OrdinalDirection::Right));
                         }
-                        Direction::Left => {
+                        OrdinalDirection::Left => {
                             // Beam goes up
-                            queue.push_back((coord, Direction::Up));
+                            queue.push_back((coord, OrdinalDirection::Up));
                         }
-                        Direction::Up => {
+                        OrdinalDirection::Up => {
                             // Beam goes left
-                            queue.push_back((coord, Direction::Left));
+                            queue.push_back((coord, OrdinalDirection::Left));
                         }
                     }
                 }
@@ -213,46 +201,27 @@ impl Layout {
 
         /*
         // Debug print the layout
-        for (x, row) in self.grid.iter().enumerate() {
-            for (y, _) in row.iter().enumerate() {
-                if coords.contains(&(x, y)) {
-                    print!("#");
-                } else {
-                    print!(".");
-                }
+        for (coord, _) in self.grid.iter() {
+            if coords.contains(&coord) {
+                print!("#");
+            } else {
+                print!(".");
+            }
+            if coord.col == self.grid.width() - 1 {
+                println!();
             }
-            println!();
         }
         */
 
         Ok(coords.len())
     }
-
-    fn beam_going_off_grid(&self, beam: Beam) -> bool {
-        match beam {
-            ((x, _), Direction::Down) if x >= self.grid.len() - 1 => true,
-            ((_, y), Direction::Right) if y >= self.grid[0].len() - 1 => true,
-            ((0, _), Direction::Up) => true,
-            ((_, 0), Direction::Left) => true,
-            _ => false,
-        }
-    }
 }
 
 impl FromStr for Layout {
     type Err = AdventError;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
-        let grid = s
-            .lines()
-            .map(|line| {
-                line.chars()
-                    .map(Node::try_from)
-                    .collect::<Result<Vec<Node>>>()
-            })
-            .collect::<Result<Vec<Vec<Node>>>>()?;
-
-        Ok(Self { grid })
+        Ok(Self { grid: s.parse()? })
     }
 }
 
@@ -265,14 +234,6 @@ enum Node {
     Down,       // \
 }
 
-#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
-enum Direction {
-    Up,
-    Left,
-    Down,
-    Right,
-}
-
 impl TryFrom<char> for Node {
     type Error = AdventError;
 
@@ -288,62 +249,47 @@ impl TryFrom<char> for Node {
     }
 }
 
-fn main() -> Result<()> {
-    println!("## Part 1");
-    println!(" > {}", part1(INPUT)?);
+pub struct Day;
 
-    println!("## Part 2");
-    println!(" > {}", part2(INPUT)?);
+impl Solution for Day {
+    type Parsed = Layout;
 
-    Ok(())
-}
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        input.parse()
+    }
 
-fn part1(input: &str) -> Result<usize> {
-    let layout: Layout = input.parse()?;
+    fn part1(layout: &Self::Parsed) -> Result<Answer> {
+        Ok(part1(layout)?.into())
+    }
 
-    layout.beam(((0, 0), Direction::Right))
+    fn part2(layout: &Self::Parsed) -> Result<Answer> {
+        Ok(part2(layout)?.into())
+    }
 }
 
-fn part2(input: &str) -> Result<usize> {
-    let layout: Layout = input.parse()?;
+fn part1(layout: &Layout) -> Result<usize> {
+    layout.beam((Point::new(0, 0), OrdinalDirection::Right))
+}
 
+fn part2(layout: &Layout) -> Result<usize> {
+    let (height, width) = (layout.grid.height(), layout.grid.width());
     // We try to send a beam through every edge possible, the top edge will send the beam down,
     // the right edge will send the beam left, etc.
-    let top = (0..layout.grid[0].len())
-        .into_par_iter()
-        .map(|y| layout.beam(((0, y), Direction::Down)));
-    let left = (0..layout.grid.len())
-        .into_par_iter()
-        .map(|x| layout.beam(((x, 0), Direction::Right)));
-    let bottom = (0..layout.grid[0].len())
-        .into_par_iter()
-        .map(|y| layout.beam(((layout.grid.len() - 1, y), Direction::Up)));
-    let right = (0..layout.grid.len())
-        .into_par_iter()
-        .map(|x| layout.beam(((x, layout.grid[0].len() - 1), Direction::Left)));
-
-    top.chain(left)
-        .chain(bottom)
-        .chain(right)
-        .try_reduce(|| 0, |mx, energy| Ok(energy.max(mx)))
+    let top = (0..width).map(|y| (Point::new(0, y), OrdinalDirection::Down));
+    let left = (0..height).map(|x| (Point::new(x, 0), OrdinalDirection::Right));
+    let bottom = (0..width).map(|y| (Point::new(height - 1, y), OrdinalDirection::Up));
+    let right = (0..height).map(|x| (Point::new(x, width - 1), OrdinalDirection::Left));
+    let beams: Vec<Beam> = top.chain(left).chain(bottom).chain(right).collect();
+
+    let max = parallel::map(&beams, |&beam| layout.beam(beam))
+        .try_fold(0, |mx, energy| energy.map(|energy| energy.max(mx)))?;
+    Ok(max)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const TEST_INPUT: &str = include_str!("../test.txt");
-
-    #[test]
-    fn test_part1() {
-        assert_eq!(part1(TEST_INPUT).unwrap(), 46);
-    }
-
-    #[test]
-    fn test_part2() {
-        assert_eq!(part2(TEST_INPUT).unwrap(), 51);
-    }
-
     #[test]
     fn test_layout_from_str() {
         let layout: Layout = ".|.\n-..\n/\\.".parse().unwrap();
@@ -351,11 +297,12 @@ mod tests {
         assert_eq!(
             layout,
             Layout {
-                grid: vec![
+                grid: Grid::from_rows(vec![
                     vec![Node::Empty, Node::Vertical, Node::Empty],
                     vec![Node::Horizontal, Node::Empty, Node::Empty],
                     vec![Node::Up, Node::Down, Node::Empty],
-                ]
+                ])
+                .unwrap()
             }
         );
     }
@@ -364,10 +311,11 @@ mod tests {
     fn test_layout_beam_going_off_grid() {
         let layout: Layout = "...\n...\n...".parse().unwrap();
 
-        assert!(layout.beam_going_off_grid(((0, 0), Direction::Up)));
-        assert!(layout.beam_going_off_grid(((0, 0), Direction::Left)));
-        assert!(layout.beam_going_off_grid(((0, 2), Direction::Right)));
-        assert!(layout.beam_going_off_grid(((2, 0), Direction::Down)));
+        assert!(layout.grid.step((0, 0), OrdinalDirection::Up).is_none());
+        assert!(layout.grid.step((0, 0), OrdinalDirection::Left).is_none());
+        assert!(layout.grid.step((0, 2), OrdinalDirection::Right).is_none());
+        assert!(layout.grid.step((2, 0), OrdinalDirection::Down).is_none());
+        assert!(layout.grid.step((1, 1), OrdinalDirection::Down).is_some());
     }
 
     #[test]
@@ -375,7 +323,12 @@ mod tests {
         let layout: Layout = "...\n...\n...".parse().unwrap();
 
         // Should just pass straight through
-        assert_eq!(layout.beam(((0, 0), Direction::Right)).unwrap(), 3);
+        assert_eq!(
+            layout
+                .beam((Point::new(0, 0), OrdinalDirection::Right))
+                .unwrap(),
+            3
+        );
     }
 
     #[test]
@@ -383,7 +336,12 @@ mod tests {
         let layout: Layout = "..\\\n...\n...".parse().unwrap();
 
         // Should redirect down in the corner
-        assert_eq!(layout.beam(((0, 0), Direction::Right)).unwrap(), 5);
+        assert_eq!(
+            layout
+                .beam((Point::new(0, 0), OrdinalDirection::Right))
+                .unwrap(),
+            5
+        );
     }
 
     #[test]
@@ -391,7 +349,12 @@ mod tests {
         let layout: Layout = ".\\.\n.-.\n...".parse().unwrap();
 
         // Should redirect down in the middle, then split to left and right
-        assert_eq!(layout.beam(((0, 0), Direction::Right)).unwrap(), 5);
+        assert_eq!(
+            layout
+                .beam((Point::new(0, 0), OrdinalDirection::Right))
+                .unwrap(),
+            5
+        );
     }
 
     #[test]
@@ -400,7 +363,12 @@ mod tests {
 
         // The beam will split in the middle and go around in a loop, which should be ignored and
         // all be good
-        assert_eq!(layout.beam(((0, 0), Direction::Right)).unwrap(), 7);
+        assert_eq!(
+            layout
+                .beam((Point::new(0, 0), OrdinalDirection::Right))
+                .unwrap(),
+            7
+        );
     }
 
     #[test]
@@ -408,6 +376,11 @@ mod tests {
         let layout: Layout = "\\/.\n...\n\\..".parse().unwrap();
 
         // The beam should go down immediately, then again in the corner to the right
-        assert_eq!(layout.beam(((0, 0), Direction::Right)).unwrap(), 5);
+        assert_eq!(
+            layout
+                .beam((Point::new(0, 0), OrdinalDirection::Right))
+                .unwrap(),
+            5
+        );
     }
 }
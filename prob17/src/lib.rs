@@ -0,0 +1,251 @@
+use advent::prelude::*;
+use advent::search::dijkstra;
+
+pub struct Layout {
+    nodes: Grid<u8>,
+}
+
+type HeatLoss = i32;
+type StraightRun = i32;
+/// The crucible's position, the direction it is heading and how many steps it has taken in that
+/// direction
+type State = (Point<usize>, CardinalDirection, StraightRun);
+
+impl Layout {
+    /// Find the cheapest path from one coordinate to another
+    ///
+    /// There is a limitation of how long a straight line can be, meaning that if the current path
+    /// has taken a staright line for <max_run> nodes in a row, it _has_ to take a turn next. It
+    /// also has to have gone at least <min_run> nodes in a row before it can turn, or stop at the
+    /// end.
+    ///
+    /// The start node does not incurr a cost, unless the path takes us back over that node
+    fn find_cheapest_path(
+        &self,
+        to: Point<usize>,
+        min_run: StraightRun,
+        max_run: StraightRun,
+    ) -> Result<HeatLoss> {
+        // We start in the upper left corner at (0, 0) and can either go South or East, without
+        // having taken any steps yet
+        let origin = Point::new(0, 0);
+        let starts = [
+            (origin, CardinalDirection::South, 0),
+            (origin, CardinalDirection::East, 0),
+        ];
+
+        let successors = |&(coord, direction, straight_run): &State| {
+            // Continue the path in all directions, except for:
+            //  * Backwards
+            //  * Forwards if we exceed the max distance
+            //  * Turning before we have reached the min distance
+            //  * If we go off the grid
+            CardinalDirection::ALL
+                .into_iter()
+                .filter_map(move |next_direction| {
+                    let next_run = if next_direction == direction {
+                        if straight_run >= max_run {
+                            return None;
+                        }
+                        straight_run + 1
+                    } else {
+                        if next_direction == direction.opposite() || straight_run < min_run {
+                            return None;
+                        }
+                        1
+                    };
+                    let next_coord = self.nodes.step(coord, next_direction)?;
+
+                    Some((
+                        (next_coord, next_direction, next_run),
+                        self.node_heat(next_coord),
+                    ))
+                })
+        };
+
+        dijkstra(starts, successors, |&(coord, _, straight_run)| {
+            coord == to && straight_run >= min_run
+        })
+        .map(|result| result.cost)
+        .ok_or(error!("No path found"))
+    }
+
+    fn node_heat(&self, coord: Point<usize>) -> i32 {
+        self.nodes[coord] as i32
+    }
+}
+
+impl FromStr for Layout {
+    type Err = AdventError;
+
+    // Parse lines of digits into u8
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let nodes = Grid::parse_with(s, |c| {
+            c.to_digit(10)
+                .map(|d| d as u8)
+                .ok_or(AdventError::InvalidDigit(c))
+        })?;
+
+        Ok(Layout { nodes })
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Layout;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        input.parse()
+    }
+
+    fn part1(layout: &Self::Parsed) -> Result<Answer> {
+        Self::part1_with(layout, &Params::default())
+    }
+
+    fn part2(layout: &Self::Parsed) -> Result<Answer> {
+        Self::part2_with(layout, &Params::default())
+    }
+
+    fn part1_with(layout: &Self::Parsed, params: &Params) -> Result<Answer> {
+        let (min_run, max_run) = straight_runs(params, 1, 3)?;
+        Ok(least_heat_loss(layout, min_run, max_run)?.into())
+    }
+
+    fn part2_with(layout: &Self::Parsed, params: &Params) -> Result<Answer> {
+        let (min_run, max_run) = straight_runs(params, 4, 10)?;
+        Ok(least_heat_loss(layout, min_run, max_run)?.into())
+    }
+}
+
+/// The shortest and longest a crucible can go in a straight line, which examples can change
+fn straight_runs(
+    params: &Params,
+    min_run: StraightRun,
+    max_run: StraightRun,
+) -> Result<(StraightRun, StraightRun)> {
+    params.allow(&["min_run", "max_run"])?;
+    let min_run = params.get_or("min_run", min_run)?;
+    let max_run = params.get_or("max_run", max_run)?;
+    if min_run < 1 {
+        return Err(error!(
+            "The crucible has to go at least one block in a line, but min_run is {}",
+            min_run
+        ));
+    }
+    if max_run < min_run {
+        return Err(error!(
+            "The crucible can't go at most {} blocks in a line but at least {}",
+            max_run, min_run
+        ));
+    }
+    Ok((min_run, max_run))
+}
+
+/// The least heat lost on the way from the top left to the bottom right
+fn least_heat_loss(layout: &Layout, min_run: StraightRun, max_run: StraightRun) -> Result<i32> {
+    layout.find_cheapest_path(
+        Point::new(layout.nodes.height() - 1, layout.nodes.width() - 1),
+        min_run,
+        max_run,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_layout_from_str() {
+        let layout: Layout = "123\n890".parse().unwrap();
+
+        assert_eq!(
+            layout.nodes,
+            Grid::from_rows(vec![vec![1, 2, 3], vec![8, 9, 0]]).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_layout_find_cheapest_path_no_min_straight_line() {
+        let layout: Layout = "1456\n1416\n1816\n1111".parse().unwrap();
+
+        assert_eq!(
+            layout.find_cheapest_path(Point::new(3, 3), 1, 4).unwrap(),
+            6
+        );
+        assert_eq!(
+            layout.find_cheapest_path(Point::new(3, 3), 1, 3).unwrap(),
+            6
+        );
+        assert_eq!(
+            layout.find_cheapest_path(Point::new(3, 3), 1, 2).unwrap(),
+            9
+        );
+        assert_eq!(
+            layout.find_cheapest_path(Point::new(3, 3), 1, 1).unwrap(),
+            16
+        );
+    }
+
+    #[test]
+    fn test_straight_runs() {
+        let params = |min_run, max_run| -> Params {
+            [("min_run", min_run), ("max_run", max_run)]
+                .into_iter()
+                .collect()
+        };
+
+        assert_eq!(straight_runs(&Params::default(), 4, 10).unwrap(), (4, 10));
+        assert_eq!(straight_runs(&params(2, 5), 4, 10).unwrap(), (2, 5));
+        assert_eq!(
+            straight_runs(&params(-1, 3), 1, 3).unwrap_err().to_string(),
+            "Error: The crucible has to go at least one block in a line, but min_run is -1"
+        );
+        assert_eq!(
+            straight_runs(&params(4, 3), 1, 3).unwrap_err().to_string(),
+            "Error: The crucible can't go at most 3 blocks in a line but at least 4"
+        );
+
+        let layout: Layout = "1456\n1416\n1816\n1111".parse().unwrap();
+        let params: Params = [("min_run", -1)].into_iter().collect();
+        assert!(Day::part1_with(&layout, &params).is_err());
+    }
+
+    #[test]
+    fn test_layout_node_heat() {
+        let layout: Layout = "1456\n1416\n1816\n1111".parse().unwrap();
+
+        assert_eq!(layout.node_heat(Point::new(0, 0)), 1);
+        assert_eq!(layout.node_heat(Point::new(0, 1)), 4);
+        assert_eq!(layout.node_heat(Point::new(1, 0)), 1);
+        assert_eq!(layout.node_heat(Point::new(2, 1)), 8);
+    }
+
+    #[test]
+    fn test_layout_step() {
+        let layout: Layout = "1456\n1416\n1816\n1111".parse().unwrap();
+
+        let coord = Point::new(1, 1);
+
+        assert_eq!(
+            layout.nodes.step(coord, CardinalDirection::North).unwrap(),
+            Point::new(0, 1)
+        );
+        assert_eq!(
+            layout.nodes.step(coord, CardinalDirection::South).unwrap(),
+            Point::new(2, 1)
+        );
+        assert_eq!(
+            layout.nodes.step(coord, CardinalDirection::West).unwrap(),
+            Point::new(1, 0)
+        );
+        assert_eq!(
+            layout.nodes.step(coord, CardinalDirection::East).unwrap(),
+            Point::new(1, 2)
+        );
+        assert_eq!(
+            layout.nodes.step(Point::new(3, 3), CardinalDirection::East),
+            None
+        );
+    }
+}
@@ -0,0 +1,184 @@
+use advent::geometry::Polygon;
+use advent::prelude::*;
+
+/// An instruction is in the form of:
+///
+/// R 6 (#70c710)
+///
+/// where the first character is U/D/L/R for Up/Down/Left/Right
+/// second character is number of steps
+/// the third part is the hex colour code
+#[derive(Debug)]
+struct Instruction {
+    direction: OrdinalDirection,
+    distance: i64,
+}
+
+impl Instruction {
+    fn from_basic(s: &str) -> Result<Self> {
+        let mut parts = s.split_whitespace();
+        let direction = parts
+            .next()
+            .ok_or(parse_error!("Unable to parse direction"))?;
+        let direction = OrdinalDirection::from_udlr(direction).ok_or(parse_error!(
+            "Unable to parse direction",
+            s,
+            direction
+        ))?;
+        let distance = parse_at(
+            s,
+            parts.next().ok_or(parse_error!("Unable to parse steps"))?,
+        )?;
+
+        Ok(Self {
+            direction,
+            distance,
+        })
+    }
+
+    fn from_hex(s: &str) -> Result<Self> {
+        let mut parts = s.split_whitespace();
+        parts.next(); // Skip the basic direction
+        parts.next(); // Skip the basic distance
+
+        let colour = parts.next().ok_or(parse_error!("Unable to parse colour"))?;
+
+        // colour at this point is in the form: (#aaaaab)
+        // where a is a 5 hex digit number for distance and
+        // b is a direction where:
+        //  * 0 = R
+        //  * 1 = D
+        //  * 2 = L
+        //  * 3 = U
+        let hex = colour
+            .strip_prefix("(#")
+            .and_then(|hex| hex.strip_suffix(')'))
+            .filter(|hex| hex.len() == 6 && hex.is_ascii())
+            .ok_or(parse_error!(
+                "Colour should be in the form of (#aaaaab)",
+                s,
+                colour
+            ))?;
+        let distance = i64::from_str_radix(&hex[..5], 16)
+            .map_err(|e| AdventError::from(e).locate(s, &hex[..5]))?;
+        let direction = OrdinalDirection::from_digit(&hex[5..]).ok_or(parse_error!(
+            "Unable to parse direction",
+            s,
+            &hex[5..]
+        ))?;
+
+        Ok(Self {
+            direction,
+            distance,
+        })
+    }
+}
+
+/// Trace the edge of the lagoon by following the instructions from (0, 0)
+fn get_polygon(instructions: &[Instruction]) -> Result<Polygon> {
+    Polygon::checked_from_instructions(
+        Point::new(0, 0),
+        instructions
+            .iter()
+            .map(|instruction| (instruction.direction, instruction.distance)),
+    )
+    .ok_or(error!("The dig plan goes too far from the start"))
+}
+
+/// The dig plan, read both the way it looks in part 1 and from the colour codes like in part 2
+pub struct DigPlan {
+    basic: Vec<Instruction>,
+    hex: Vec<Instruction>,
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = DigPlan;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(DigPlan {
+            basic: parse_lines(input, Instruction::from_basic).context("parsing instructions")?,
+            hex: parse_lines(input, Instruction::from_hex).context("parsing colour codes")?,
+        })
+    }
+
+    fn part1(plan: &Self::Parsed) -> Result<Answer> {
+        Ok(lagoon_size(&plan.basic)?.into())
+    }
+
+    fn part2(plan: &Self::Parsed) -> Result<Answer> {
+        Ok(lagoon_size(&plan.hex)?.into())
+    }
+}
+
+fn lagoon_size(instructions: &[Instruction]) -> Result<i64> {
+    // The lagoon is dug out along the edge as well as inside it, so we count both
+    get_polygon(instructions)?
+        .checked_lattice_points()
+        .ok_or(error!("The lagoon is too big to count"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_instruction_basic() {
+        let instruction = Instruction::from_basic("R 16 (#70c710)").unwrap();
+
+        assert_eq!(instruction.direction, OrdinalDirection::Right);
+        assert_eq!(instruction.distance, 16);
+    }
+
+    #[test]
+    fn test_parse_instruction_hex() {
+        let instruction = Instruction::from_hex("R 16 (#0dc571)").unwrap();
+
+        assert_eq!(instruction.direction, OrdinalDirection::Down);
+        assert_eq!(instruction.distance, 56407);
+    }
+
+    #[test]
+    fn test_get_polygon() {
+        let instructions: Vec<Instruction> = vec![
+            Instruction::from_basic("R 8 (#70c710)").unwrap(),
+            Instruction::from_basic("D 4 (#70c710)").unwrap(),
+            Instruction::from_basic("L 8 (#70c710)").unwrap(),
+            Instruction::from_basic("U 4 (#70c710)").unwrap(),
+        ];
+
+        let polygon = get_polygon(&instructions).unwrap();
+        let nodes = polygon.vertices();
+
+        assert_eq!(nodes.len(), 4);
+        assert_eq!(nodes[0], Point::new(0, 8));
+        assert_eq!(nodes[1], Point::new(4, 8));
+        assert_eq!(nodes[2], Point::new(4, 0));
+        assert_eq!(nodes[3], Point::new(0, 0));
+        assert_eq!(polygon.lattice_points(), 5 * 9);
+    }
+
+    #[test]
+    fn test_overflow_is_an_error() {
+        let instructions: Vec<Instruction> = vec![
+            Instruction::from_basic("R 9223372036854775807 (#70c710)").unwrap(),
+            Instruction::from_basic("R 1 (#70c710)").unwrap(),
+        ];
+        assert_eq!(
+            lagoon_size(&instructions).unwrap_err().to_string(),
+            "Error: The dig plan goes too far from the start"
+        );
+
+        let instructions: Vec<Instruction> = vec![
+            Instruction::from_basic("R 4611686018427387903 (#70c710)").unwrap(),
+            Instruction::from_basic("D 4611686018427387903 (#70c710)").unwrap(),
+            Instruction::from_basic("L 4611686018427387903 (#70c710)").unwrap(),
+            Instruction::from_basic("U 4611686018427387903 (#70c710)").unwrap(),
+        ];
+        assert_eq!(
+            lagoon_size(&instructions).unwrap_err().to_string(),
+            "Error: The lagoon is too big to count"
+        );
+    }
+}
@@ -0,0 +1,520 @@
+use crate::{Category, Condition, Part, Workflow};
+use advent::hyperrect::{Comparison, HyperRect};
+use advent::interval::Interval;
+use advent::prelude::*;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::rc::Rc;
+
+/// A region of ratings along the x, m, a and s axes
+///
+/// The bounds are u64 so that the region can hold every possible u32 rating
+type Region = HyperRect<u64, 4>;
+
+/// Where the workflows send a part, as a tree of the conditions it's tested against
+#[derive(Debug, PartialEq, Eq)]
+pub enum Tree {
+    Accept,
+    Reject,
+    /// Go to `then` if the rating holds up to the comparison, and to `otherwise` if not
+    Test {
+        category: Category,
+        comparison: Comparison,
+        value: u32,
+        then: Rc<Tree>,
+        otherwise: Rc<Tree>,
+    },
+}
+
+impl Tree {
+    pub fn accepts(&self, part: &Part) -> bool {
+        let mut tree = self;
+        loop {
+            match tree {
+                Tree::Accept => return true,
+                Tree::Reject => return false,
+                Tree::Test {
+                    category,
+                    comparison,
+                    value,
+                    then,
+                    otherwise,
+                } => {
+                    tree = match comparison.holds(part.value(category), *value) {
+                        true => then,
+                        false => otherwise,
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// A rule that can never match, because the rules before it already match every part it would
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeadRule {
+    pub workflow: String,
+    /// Where the rule is in the workflow, counting from 1
+    pub position: usize,
+    pub rule: Condition,
+}
+
+/// The workflows compiled into a single decision tree, along with what simplifying them found
+#[derive(Debug)]
+pub struct Compiled {
+    pub tree: Rc<Tree>,
+    /// The simplified workflows, from `in` onwards in the order they're first sent parts
+    pub workflows: Vec<Workflow>,
+    pub dead_rules: Vec<DeadRule>,
+    /// The workflows that send every part to the same place, and where that is
+    pub collapsed: BTreeMap<String, String>,
+    /// The workflows that no part ever reaches, in order of their names
+    pub unreachable: Vec<String>,
+}
+
+impl Compiled {
+    pub fn new(workflows: &HashMap<String, Workflow>) -> Result<Self> {
+        let order = topological_order(workflows)?;
+
+        // Every possible rating, so that simplifying never changes where any part goes
+        let everything = Region::cube(Interval::new(0, u64::from(u32::MAX) + 1));
+        let flow = Flow::new(workflows, &order, everything)?;
+
+        let mut unreachable: Vec<String> = workflows
+            .keys()
+            .filter(|name| !flow.reached.contains(name.as_str()))
+            .cloned()
+            .collect();
+        unreachable.sort_unstable();
+
+        let mut rules = BTreeMap::new();
+        let mut dead_rules = Vec::new();
+        for &name in &order {
+            if !flow.reached.contains(name) {
+                continue;
+            }
+            let workflow = &workflows[name];
+            let mut live = Vec::new();
+            for (idx, rule) in workflow.conditions.iter().enumerate() {
+                match flow.dead.contains(&(name, idx)) {
+                    true => dead_rules.push(DeadRule {
+                        workflow: name.to_string(),
+                        position: idx + 1,
+                        rule: rule.clone(),
+                    }),
+                    false => live.push(rule.clone()),
+                }
+            }
+            // When the last rule can't be reached, the last one that can always matches
+            if let Some(last) = live.last_mut() {
+                *last = Condition::always(&last.workflow_name);
+            }
+            rules.insert(name.to_string(), live);
+        }
+
+        let collapsed = collapse(&mut rules);
+        let simplified: HashMap<String, Workflow> = rules
+            .into_iter()
+            .map(|(name, conditions)| (name.clone(), Workflow { name, conditions }))
+            .collect();
+
+        let tree = build_tree("in", &simplified, &mut HashMap::new())?;
+        // Only the workflows that are still sent parts are left
+        let workflows = topological_order(&simplified)?
+            .into_iter()
+            .map(|name| simplified[name].clone())
+            .collect();
+
+        Ok(Self {
+            tree,
+            workflows,
+            dead_rules,
+            collapsed,
+            unreachable,
+        })
+    }
+}
+
+impl fmt::Display for Compiled {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for workflow in &self.workflows {
+            writeln!(f, "{}", workflow)?;
+        }
+        if !self.dead_rules.is_empty() {
+            writeln!(f, "\nRules that can never match:")?;
+            for dead in &self.dead_rules {
+                writeln!(
+                    f,
+                    "  {} rule {}: {}",
+                    dead.workflow, dead.position, dead.rule
+                )?;
+            }
+        }
+        if !self.collapsed.is_empty() {
+            writeln!(f, "\nWorkflows that send every part to the same place:")?;
+            for (name, target) in &self.collapsed {
+                writeln!(f, "  {} -> {}", name, target)?;
+            }
+        }
+        if !self.unreachable.is_empty() {
+            writeln!(
+                f,
+                "\nWorkflows no part reaches: {}",
+                self.unreachable.join(", ")
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// The workflows reachable from `in`, where a workflow always comes before the ones it sends to
+///
+/// Fails if a workflow sends to one that doesn't exist, or if the workflows loop.
+fn topological_order(workflows: &HashMap<String, Workflow>) -> Result<Vec<&str>> {
+    fn visit<'a>(
+        name: &'a str,
+        workflows: &'a HashMap<String, Workflow>,
+        path: &mut Vec<&'a str>,
+        done: &mut HashSet<&'a str>,
+        order: &mut Vec<&'a str>,
+    ) -> Result<()> {
+        if name == "A" || name == "R" || done.contains(name) {
+            return Ok(());
+        }
+        if let Some(start) = path.iter().position(|&visiting| visiting == name) {
+            let cycle: Vec<&str> = path[start..].iter().copied().chain([name]).collect();
+            return Err(error!("The workflows loop: {}", cycle.join(" -> ")));
+        }
+        let workflow = workflows
+            .get(name)
+            .ok_or(error!("Unknown workflow: {}", name))?;
+
+        path.push(name);
+        for condition in &workflow.conditions {
+            visit(&condition.workflow_name, workflows, path, done, order)?;
+        }
+        path.pop();
+
+        done.insert(name);
+        order.push(name);
+        Ok(())
+    }
+
+    let mut order = Vec::new();
+    visit(
+        "in",
+        workflows,
+        &mut vec![],
+        &mut HashSet::new(),
+        &mut order,
+    )?;
+    // Each workflow was added after everything it sends to
+    order.reverse();
+    Ok(order)
+}
+
+/// Which ratings reach each workflow and each rule
+struct Flow<'a> {
+    /// The workflows that any rating reaches
+    reached: HashSet<&'a str>,
+    /// The rules no rating matches, as the workflow and the index of the rule
+    dead: HashSet<(&'a str, usize)>,
+}
+
+impl<'a> Flow<'a> {
+    /// Send every rating in `everything` through the workflows, in an order where each workflow
+    /// gets all of its ratings before it sends any on
+    fn new(
+        workflows: &'a HashMap<String, Workflow>,
+        order: &[&'a str],
+        everything: Region,
+    ) -> Result<Self> {
+        let mut incoming: HashMap<&str, Vec<Region>> = HashMap::from([("in", vec![everything])]);
+        let mut reached = HashSet::new();
+        let mut dead = HashSet::new();
+
+        for &name in order {
+            let mut region = incoming.remove(name).unwrap_or_default();
+            // Only sent parts by rules that can never match
+            if region.is_empty() {
+                continue;
+            }
+            reached.insert(name);
+
+            let workflow = &workflows[name];
+            for (idx, rule) in workflow.conditions.iter().enumerate() {
+                let matching = match rule.test(&workflow.name)? {
+                    None => std::mem::take(&mut region),
+                    Some((category, comparison, value)) => {
+                        let mut matching = Vec::new();
+                        let mut rest = Vec::new();
+                        for rect in &region {
+                            let (matched, left) =
+                                rect.split(category.axis(), comparison, u64::from(value));
+                            matching.extend(matched);
+                            rest.extend(left);
+                        }
+                        region = rest;
+                        matching
+                    }
+                };
+                match matching.is_empty() {
+                    true => {
+                        dead.insert((name, idx));
+                    }
+                    false => incoming
+                        .entry(rule.workflow_name.as_str())
+                        .or_default()
+                        .extend(matching),
+                }
+            }
+            if !region.is_empty() {
+                return Err(error!(
+                    "Workflow {} doesn't send every part somewhere, the last rule needs to be \
+                     without a condition",
+                    name
+                ));
+            }
+        }
+
+        Ok(Self { reached, dead })
+    }
+}
+
+/// Simplify the workflows until none of them send every part to the same place, which are
+/// removed and replaced with where they send the parts
+///
+/// Returns the removed workflows and where they sent parts. Doesn't remove `in`, which is where
+/// every part starts.
+fn collapse(rules: &mut BTreeMap<String, Vec<Condition>>) -> BTreeMap<String, String> {
+    let mut collapsed = BTreeMap::new();
+    loop {
+        // A rule going to the same place as the rule after it doesn't need testing
+        for conditions in rules.values_mut() {
+            while let [.., before, last] = &conditions[..] {
+                if before.workflow_name != last.workflow_name {
+                    break;
+                }
+                conditions.remove(conditions.len() - 2);
+            }
+        }
+
+        let Some((name, target)) =
+            rules
+                .iter()
+                .find_map(|(name, conditions)| match &conditions[..] {
+                    [only] if name != "in" => Some((name.clone(), only.workflow_name.clone())),
+                    _ => None,
+                })
+        else {
+            return collapsed;
+        };
+
+        rules.remove(&name);
+        for condition in rules.values_mut().flatten() {
+            if condition.workflow_name == name {
+                condition.workflow_name = target.clone();
+            }
+        }
+        for earlier in collapsed.values_mut() {
+            if *earlier == name {
+                *earlier = target.clone();
+            }
+        }
+        collapsed.insert(name, target);
+    }
+}
+
+/// Build the tree for the workflow `name`, sharing the trees of workflows sent to more than once
+fn build_tree(
+    name: &str,
+    workflows: &HashMap<String, Workflow>,
+    built: &mut HashMap<String, Rc<Tree>>,
+) -> Result<Rc<Tree>> {
+    match name {
+        "A" => return Ok(Rc::new(Tree::Accept)),
+        "R" => return Ok(Rc::new(Tree::Reject)),
+        _ => {}
+    }
+    if let Some(tree) = built.get(name) {
+        return Ok(tree.clone());
+    }
+
+    let workflow = workflows
+        .get(name)
+        .ok_or(error!("Unknown workflow: {}", name))?;
+    let tree = build_rules(name, &workflow.conditions, workflows, built)?;
+    built.insert(name.to_string(), tree.clone());
+    Ok(tree)
+}
+
+fn build_rules(
+    name: &str,
+    conditions: &[Condition],
+    workflows: &HashMap<String, Workflow>,
+    built: &mut HashMap<String, Rc<Tree>>,
+) -> Result<Rc<Tree>> {
+    let Some((rule, rest)) = conditions.split_first() else {
+        return Err(error!("Workflow {} has no rules", name));
+    };
+
+    match rule.test(name)? {
+        None => build_tree(&rule.workflow_name, workflows, built),
+        Some((category, comparison, value)) => {
+            let then = build_tree(&rule.workflow_name, workflows, built)?;
+            let otherwise = build_rules(name, rest, workflows, built)?;
+            // Both ways lead to the same place, so there's nothing to test
+            if then == otherwise {
+                return Ok(then);
+            }
+            Ok(Rc::new(Tree::Test {
+                category,
+                comparison,
+                value,
+                then,
+                otherwise,
+            }))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn workflows(lines: &str) -> HashMap<String, Workflow> {
+        lines
+            .lines()
+            .map(|line| {
+                let workflow: Workflow = line.parse().unwrap();
+                (workflow.name.clone(), workflow)
+            })
+            .collect()
+    }
+
+    fn part(x: u32, m: u32, a: u32, s: u32) -> Part {
+        Part { x, m, a, s }
+    }
+
+    #[test]
+    fn test_compile_example() {
+        let input = include_str!("../test.txt");
+        let (workflows, parts) = crate::parse_input(input).unwrap();
+
+        let compiled = Compiled::new(&workflows).unwrap();
+
+        let accepted: Vec<bool> = parts
+            .iter()
+            .map(|part| compiled.tree.accepts(part))
+            .collect();
+        assert_eq!(accepted, vec![true, false, true, false, true]);
+        assert_eq!(
+            compiled.collapsed,
+            BTreeMap::from([
+                ("gd".to_string(), "R".to_string()),
+                ("lnx".to_string(), "A".to_string()),
+                ("qs".to_string(), "A".to_string()),
+            ])
+        );
+        assert!(compiled.dead_rules.is_empty());
+        assert!(compiled.unreachable.is_empty());
+        assert_eq!(
+            compiled.workflows[0].to_string(),
+            "in{s<1351:px,qqz}",
+            "in comes first"
+        );
+        assert!(compiled
+            .workflows
+            .iter()
+            .any(|workflow| workflow.to_string() == "qqz{s>2770:A,m<1801:hdj,R}"));
+    }
+
+    #[test]
+    fn test_dead_rules() {
+        let compiled = Compiled::new(&workflows(
+            "in{x<10:a,x<5:R,m==3:R,x>20:A,x>=10:a,R}\na{s>100:R,A}\nb{A}",
+        ))
+        .unwrap();
+
+        let dead: Vec<_> = compiled
+            .dead_rules
+            .iter()
+            .map(|dead| (dead.workflow.as_str(), dead.position, dead.rule.to_string()))
+            .collect();
+        // Every x is either below 10 or at least 10, so nothing is left for the last rule
+        assert_eq!(
+            dead,
+            vec![("in", 2, "x<5:R".to_string()), ("in", 6, "R".to_string())]
+        );
+        assert_eq!(
+            compiled.workflows[0].to_string(),
+            "in{x<10:a,m==3:R,x>20:A,a}"
+        );
+        assert_eq!(compiled.unreachable, vec!["b"]);
+
+        assert!(compiled.tree.accepts(&part(1, 1, 1, 1)));
+        assert!(!compiled.tree.accepts(&part(15, 3, 1, 1)));
+        assert!(compiled.tree.accepts(&part(25, 1, 1, 1)));
+        assert!(!compiled.tree.accepts(&part(12, 1, 1, 500)));
+    }
+
+    #[test]
+    fn test_collapse_into_a_single_target() {
+        let compiled = Compiled::new(&workflows(
+            "in{x<10:a,m>5:b,R}\na{s>100:R,R}\nb{a<3:R,c}\nc{m<2:R,R}",
+        ))
+        .unwrap();
+
+        assert_eq!(*compiled.tree, Tree::Reject);
+        assert_eq!(compiled.workflows.len(), 1);
+        assert_eq!(compiled.workflows[0].to_string(), "in{R}");
+        assert_eq!(
+            compiled.collapsed,
+            BTreeMap::from([
+                ("a".to_string(), "R".to_string()),
+                ("b".to_string(), "R".to_string()),
+                ("c".to_string(), "R".to_string()),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_shared_workflows_are_built_once() {
+        let compiled =
+            Compiled::new(&workflows("in{x<10:a,m<10:a,b}\na{s>100:R,A}\nb{a<5:a,A}")).unwrap();
+
+        let Tree::Test {
+            then: x_below,
+            otherwise,
+            ..
+        } = &*compiled.tree
+        else {
+            panic!("Expected in to test x");
+        };
+        let Tree::Test { then: m_below, .. } = &**otherwise else {
+            panic!("Expected in to test m");
+        };
+        assert!(Rc::ptr_eq(x_below, m_below));
+        assert_eq!(compiled.workflows.len(), 3);
+    }
+
+    #[test]
+    fn test_compile_errors() {
+        let err = Compiled::new(&workflows("in{x<10:a,R}\na{m<5:b,A}\nb{in}")).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Error: The workflows loop: in -> a -> b -> in"
+        );
+
+        let err = Compiled::new(&workflows("in{x<10:a,R}")).unwrap_err();
+        assert_eq!(err.to_string(), "Error: Unknown workflow: a");
+
+        let err = Compiled::new(&workflows("a{R}")).unwrap_err();
+        assert_eq!(err.to_string(), "Error: Unknown workflow: in");
+
+        let err = Compiled::new(&workflows("in{x<10:A}")).unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("Error: Workflow in doesn't send every part somewhere"));
+    }
+}
@@ -0,0 +1,697 @@
+use advent::hyperrect::{Comparison, HyperRect};
+use advent::interval::Interval;
+use advent::prelude::*;
+use std::fmt;
+
+mod compile;
+mod parse;
+mod trace;
+
+pub use compile::{Compiled, Tree};
+pub use trace::{Step, Tested, Trace};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Category {
+    X,
+    M,
+    A,
+    S,
+}
+
+impl Category {
+    /// The axis of the category in the ranges of ratings
+    fn axis(&self) -> usize {
+        match self {
+            Category::X => 0,
+            Category::M => 1,
+            Category::A => 2,
+            Category::S => 3,
+        }
+    }
+}
+
+impl fmt::Display for Category {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let c = match self {
+            Category::X => 'x',
+            Category::M => 'm',
+            Category::A => 'a',
+            Category::S => 's',
+        };
+        write!(f, "{}", c)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Part {
+    x: u32,
+    m: u32,
+    a: u32,
+    s: u32,
+}
+
+impl Part {
+    fn value(&self, category: &Category) -> u32 {
+        match category {
+            Category::X => self.x,
+            Category::M => self.m,
+            Category::A => self.a,
+            Category::S => self.s,
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{{x={},m={},a={},s={}}}", self.x, self.m, self.a, self.s)
+    }
+}
+
+impl FromStr for Part {
+    type Err = AdventError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let (x, m, a, s) = parse::parse_all(s, parse::xmas)?;
+
+        Ok(Part { x, m, a, s })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Workflow {
+    name: String,
+    conditions: Vec<Condition>,
+}
+
+impl fmt::Display for Workflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let conditions: Vec<String> = self.conditions.iter().map(|c| c.to_string()).collect();
+        write!(f, "{}{{{}}}", self.name, conditions.join(","))
+    }
+}
+
+impl FromStr for Workflow {
+    type Err = AdventError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let (name, conditions) = parse::parse_all(s, parse::workflow)?;
+        let name = name.to_string();
+        let conditions: Vec<Condition> = conditions
+            .into_iter()
+            .map(|(condition, workflow_name)| match condition {
+                Some((category, comparison, value)) => Condition {
+                    category: Some(category),
+                    value: Some(value),
+                    comparison: Some(comparison),
+                    workflow_name: workflow_name.to_string(),
+                },
+                None => Condition {
+                    category: None,
+                    value: None,
+                    comparison: None,
+                    workflow_name: workflow_name.to_string(),
+                },
+            })
+            .collect();
+
+        Ok(Workflow { name, conditions })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Condition {
+    category: Option<Category>,
+    value: Option<u32>,
+    comparison: Option<Comparison>,
+    workflow_name: String,
+}
+
+impl Condition {
+    /// A condition that every part matches, sending it to the workflow
+    fn always(workflow_name: &str) -> Self {
+        Condition {
+            category: None,
+            value: None,
+            comparison: None,
+            workflow_name: workflow_name.to_string(),
+        }
+    }
+
+    /// What the condition tests, or None if every part matches it
+    fn test(&self, workflow: &str) -> Result<Option<(Category, Comparison, u32)>> {
+        match (self.category, self.comparison, self.value) {
+            (Some(category), Some(comparison), Some(value)) => {
+                Ok(Some((category, comparison, value)))
+            }
+            (None, None, None) => Ok(None),
+            _ => Err(error!("Incomplete condition in workflow {}", workflow)),
+        }
+    }
+}
+
+impl fmt::Display for Condition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.category, self.comparison, self.value) {
+            (Some(category), Some(comparison), Some(value)) => write!(
+                f,
+                "{}{}{}:{}",
+                category, comparison, value, self.workflow_name
+            ),
+            _ => write!(f, "{}", self.workflow_name),
+        }
+    }
+}
+
+impl FromStr for Condition {
+    type Err = AdventError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        // A condition string will look like this:
+        //
+        //   a<2006:qkq
+        //
+        // This is <category><operation><value>:<workflow>
+        match parse::parse_all(s, parse::condition)? {
+            (Some((category, comparison, value)), workflow_name) => Ok(Condition {
+                category: Some(category),
+                value: Some(value),
+                comparison: Some(comparison),
+                workflow_name: workflow_name.to_string(),
+            }),
+            (None, workflow_name) => Ok(Condition {
+                category: None,
+                value: None,
+                comparison: None,
+                workflow_name: workflow_name.to_string(),
+            }),
+        }
+    }
+}
+
+/// The ranges of ratings along the x, m, a and s axes
+type Ranges = HyperRect<u32, 4>;
+
+/// Describe ranges of ratings, like `x [1, 1416), m [1, 4001), a [1, 2006), s [1, 1351)`
+fn describe(ranges: &Ranges) -> String {
+    [Category::X, Category::M, Category::A, Category::S]
+        .iter()
+        .map(|category| format!("{} {}", category, ranges.axes[category.axis()]))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Count the combinations of the ranges that are accepted, starting from the workflow `key`
+fn get_combinations(
+    key: &str,
+    workflows: &HashMap<String, Workflow>,
+    ranges: &Ranges,
+    depth: usize,
+) -> Result<u128> {
+    let mut accepted = Vec::new();
+    accepted_ranges(key, workflows, ranges, depth, &mut accepted)?;
+    Ok(accepted.iter().map(Ranges::volume).sum())
+}
+
+/// Collect the ranges that are accepted, starting from the workflow `key`
+///
+/// The depth is how many workflows have been visited on the way here, and going through more than
+/// there are means the workflows loop
+fn accepted_ranges(
+    key: &str,
+    workflows: &HashMap<String, Workflow>,
+    ranges: &Ranges,
+    depth: usize,
+    accepted: &mut Vec<Ranges>,
+) -> Result<()> {
+    if key == "A" {
+        accepted.push(*ranges);
+        return Ok(());
+    }
+    if key == "R" {
+        return Ok(());
+    }
+    if depth > workflows.len() {
+        return Err(error!("The workflows loop through {}", key));
+    }
+
+    let workflow = workflows
+        .get(key)
+        .ok_or(error!("Unknown workflow: {}", key))?;
+
+    conditions_accepted(&workflow.conditions, workflows, ranges, depth + 1, accepted)
+}
+
+/// Collect the accepted ranges going through a list of conditions
+///
+/// For any condition that has a category, value and comparison, we split up the ranges and recurse
+/// into the condition's workflow with the matching part. The non-matching parts continue through
+/// to the next condition, where an equality check can leave a part on either side of the value.
+///
+/// When we reach the final condition (which will not have a category, value or comparison), we
+/// recurse without splitting the ranges any more
+fn conditions_accepted(
+    conditions: &[Condition],
+    workflows: &HashMap<String, Workflow>,
+    ranges: &Ranges,
+    depth: usize,
+    accepted: &mut Vec<Ranges>,
+) -> Result<()> {
+    let Some((condition, next_conditions)) = conditions.split_first() else {
+        return Ok(());
+    };
+
+    match condition {
+        Condition {
+            category: None,
+            value: None,
+            comparison: None,
+            workflow_name,
+        } => accepted_ranges(workflow_name, workflows, ranges, depth, accepted),
+        Condition {
+            category: Some(category),
+            value: Some(value),
+            comparison: Some(comparison),
+            workflow_name,
+        } => {
+            let (matching, rest) = ranges.split(category.axis(), *comparison, *value);
+            if let Some(matching) = matching {
+                accepted_ranges(workflow_name, workflows, &matching, depth, accepted)?;
+            }
+            for ranges in &rest {
+                conditions_accepted(next_conditions, workflows, ranges, depth, accepted)?;
+            }
+            Ok(())
+        }
+        _ => Err(error!("Incomplete condition")),
+    }
+}
+
+/// Parse the workflows and the parts
+///
+/// Input will be multiple lines of workflows, followed by multiple lines of parts. The two
+/// sections will be split by an empty line
+fn parse_input(input: &str) -> Result<(HashMap<String, Workflow>, Vec<Part>)> {
+    let (workflows, parts) = input.split_once("\n\n").ok_or(parse_error!(
+        "Expected workflows and parts split by an empty line"
+    ))?;
+
+    // A second workflow with the same name would quietly replace the first one
+    let mut names = HashSet::new();
+    let workflows = parse_lines(workflows, |line| {
+        let workflow = line.parse::<Workflow>()?;
+        if !names.insert(workflow.name.clone()) {
+            return Err(parse_error!(
+                format!("Workflow {} is defined more than once", workflow.name),
+                line,
+                &line[..workflow.name.len()]
+            ));
+        }
+        Ok((workflow.name.clone(), workflow))
+    })
+    .context("parsing workflows")?
+    .into_iter()
+    .collect();
+
+    // The parts are parsed line by line on their own, so they need moving to where they are in
+    // the whole input
+    let parts = parts
+        .lines()
+        .map(|line| line.parse::<Part>().map_err(|e| e.relocate(input, line)))
+        .collect::<Result<Vec<Part>>>()
+        .context("parsing parts")?;
+
+    Ok((workflows, parts))
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = (HashMap<String, Workflow>, Vec<Part>);
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        parse_input(input)
+    }
+
+    fn part1((workflows, parts): &Self::Parsed) -> Result<Answer> {
+        Ok(part1(workflows, parts)?.into())
+    }
+
+    fn part2((workflows, _): &Self::Parsed) -> Result<Answer> {
+        Ok(part2(workflows)?.into())
+    }
+
+    fn explain((workflows, parts): &Self::Parsed) -> Result<Option<String>> {
+        let mut explanation = Compiled::new(workflows)?.to_string();
+
+        explanation.push_str("\nParts:\n");
+        for part in parts {
+            explanation.push_str(&part.trace(workflows)?.to_string());
+        }
+
+        explanation.push_str("\nAccepted ratings:\n");
+        for ranges in accepted(workflows)? {
+            explanation.push_str(&format!("  {}\n", describe(&ranges)));
+        }
+        Ok(Some(explanation))
+    }
+}
+
+fn part1(workflows: &HashMap<String, Workflow>, parts: &[Part]) -> Result<u64> {
+    let compiled = Compiled::new(workflows)?;
+    Ok(parts
+        .iter()
+        .filter(|part| compiled.tree.accepts(part))
+        .map(|part| {
+            [part.x, part.m, part.a, part.s]
+                .map(u64::from)
+                .iter()
+                .sum::<u64>()
+        })
+        .sum())
+}
+
+/// Every possible rating a part can have
+fn all_ratings() -> Ranges {
+    Ranges::cube(Interval::new(1, 4001))
+}
+
+/// The ranges of ratings the workflows accept, which don't overlap
+pub fn accepted(workflows: &HashMap<String, Workflow>) -> Result<Vec<Ranges>> {
+    let mut accepted = Vec::new();
+    accepted_ranges("in", workflows, &all_ratings(), 0, &mut accepted)?;
+    Ok(accepted)
+}
+
+fn part2(workflows: &HashMap<String, Workflow>) -> Result<u128> {
+    get_combinations("in", workflows, &all_ratings(), 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_condition_from_str() {
+        let condition: Condition = "x>1:qkq".parse().unwrap();
+
+        assert_eq!(condition.category, Some(Category::X));
+        assert_eq!(condition.value, Some(1));
+        assert_eq!(condition.comparison, Some(Comparison::Gt));
+        assert_eq!(condition.workflow_name, "qkq");
+
+        let condition: Condition = "rgb".parse().unwrap();
+        assert_eq!(condition.category, None);
+        assert_eq!(condition.value, None);
+        assert_eq!(condition.comparison, None);
+        assert_eq!(condition.workflow_name, "rgb");
+    }
+
+    #[test]
+    fn test_workflow_from_str() {
+        let workflow: Workflow = "qkq{x>1:qkq,m<2090:A}".parse().unwrap();
+
+        assert_eq!(workflow.name, "qkq");
+        assert_eq!(workflow.conditions.len(), 2);
+        assert_eq!(
+            workflow.conditions[0],
+            Condition {
+                category: Some(Category::X),
+                value: Some(1),
+                comparison: Some(Comparison::Gt),
+                workflow_name: "qkq".to_string(),
+            }
+        );
+        assert_eq!(
+            workflow.conditions[1],
+            Condition {
+                category: Some(Category::M),
+                value: Some(2090),
+                comparison: Some(Comparison::Lt),
+                workflow_name: "A".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_part_from_str() {
+        let part: Part = "{x=1,m=2090,a=15,s=9152}".parse().unwrap();
+
+        assert_eq!(part.x, 1);
+        assert_eq!(part.m, 2090);
+        assert_eq!(part.a, 15);
+        assert_eq!(part.s, 9152);
+    }
+
+    #[test]
+    fn test_parse_input_error_span() {
+        let input = "in{s<1351:px,R}\npx{a<2006:A,y>2090:A,R}\n\n{x=787,m=2655,a=1222,s=2876}";
+
+        let err = parse_input(input).err().unwrap();
+        assert_eq!(
+            err.span(),
+            Some(&Span::new(2, 13..23, "px{a<2006:A,y>2090:A,R}"))
+        );
+        assert!(err.to_string().starts_with("parsing workflows: "));
+
+        let input = "in{s<1351:A,R}\n\n{x=787,m=2655,a=1222,s=2876}\n{x=1,m=2,a=3}";
+
+        let err = parse_input(input).err().unwrap();
+        assert_eq!(err.span(), Some(&Span::new(4, 12..13, "{x=1,m=2,a=3}")));
+
+        let input = "in{s<1351:px,R}\npx{A}\npx{R}\n\n{x=787,m=2655,a=1222,s=2876}";
+
+        let err = parse_input(input).err().unwrap();
+        assert_eq!(err.span(), Some(&Span::new(3, 0..2, "px{R}")));
+        assert!(err
+            .to_string()
+            .contains("parsing workflows: Unable to parse: Workflow px is defined more than once"));
+    }
+
+    #[test]
+    fn test_part_through_compiled_workflows() {
+        let part: Part = "{x=1,m=194,a=15,s=9152}".parse().unwrap();
+
+        let workflows: HashMap<String, Workflow> = [
+            ("in".to_string(), "in{x>10:abc,m<2090:A,R}".parse().unwrap()),
+            ("abc".to_string(), "abc{R}".parse().unwrap()),
+        ]
+        .into();
+        assert!(Compiled::new(&workflows).unwrap().tree.accepts(&part));
+
+        let workflows: HashMap<String, Workflow> = [
+            ("in".to_string(), "in{x>10:abc,R}".parse().unwrap()),
+            ("abc".to_string(), "abc{A}".parse().unwrap()),
+        ]
+        .into();
+        assert!(!Compiled::new(&workflows).unwrap().tree.accepts(&part));
+    }
+
+    #[test]
+    fn test_workflow_display() {
+        let workflow: Workflow = "qkq{x>1:qkq,m<=2090:A,a==3:R,rfg}".parse().unwrap();
+        assert_eq!(workflow.to_string(), "qkq{x>1:qkq,m<=2090:A,a==3:R,rfg}");
+    }
+
+    #[test]
+    fn test_ranges_combinations() {
+        assert_eq!(
+            Ranges::new([
+                Interval::new(1, 11),
+                Interval::new(1, 11),
+                Interval::new(1, 11),
+                Interval::new(1, 11)
+            ])
+            .volume(),
+            10 * 10 * 10 * 10
+        );
+
+        assert_eq!(
+            Ranges::new([
+                Interval::new(1, 11),
+                Interval::new(1, 11),
+                Interval::new(1, 11),
+                Interval::new(1, 10)
+            ])
+            .volume(),
+            10 * 10 * 10 * 9
+        );
+
+        assert_eq!(
+            Ranges::new([
+                Interval::new(1, 11),
+                Interval::new(1, 11),
+                Interval::new(1, 11),
+                Interval::new(2, 11)
+            ])
+            .volume(),
+            10 * 10 * 10 * 9
+        );
+
+        assert_eq!(
+            Ranges::new([
+                Interval::new(1, 11),
+                Interval::new(1, 11),
+                Interval::new(1, 11),
+                Interval::new(6, 11)
+            ])
+            .volume(),
+            10 * 10 * 10 * 5
+        );
+    }
+
+    #[test]
+    fn test_get_combinations_base_case_rejected() {
+        let combinations = get_combinations(
+            "R",
+            &HashMap::new(),
+            &Ranges::new([
+                Interval::new(1, 4000),
+                Interval::new(1, 4000),
+                Interval::new(1, 4000),
+                Interval::new(1, 4000),
+            ]),
+            0,
+        )
+        .unwrap();
+
+        assert_eq!(combinations, 0);
+    }
+
+    #[test]
+    fn test_get_combinations_base_case_accepted() {
+        let combinations = get_combinations(
+            "A",
+            &HashMap::new(),
+            &Ranges::new([
+                Interval::new(1, 11),
+                Interval::new(1, 11),
+                Interval::new(1, 11),
+                Interval::new(1, 11),
+            ]),
+            0,
+        )
+        .unwrap();
+        assert_eq!(combinations, 10 * 10 * 10 * 10);
+    }
+
+    #[test]
+    fn test_get_combinations_with_one_split_less() {
+        let workflows: HashMap<String, Workflow> = [
+            ("abc".to_string(), "abc{a<5:xyz,R}".parse().unwrap()),
+            ("xyz".to_string(), "xyz{A}".parse().unwrap()),
+        ]
+        .into();
+
+        let combinations = get_combinations(
+            "abc",
+            &workflows,
+            &Ranges::new([
+                Interval::new(1, 11),
+                Interval::new(1, 11),
+                Interval::new(1, 11),
+                Interval::new(1, 11),
+            ]),
+            0,
+        )
+        .unwrap();
+
+        assert_eq!(combinations, 10 * 10 * 10 * 4);
+    }
+
+    #[test]
+    fn test_get_combinations_with_inclusive_and_equal_splits() {
+        let workflows: HashMap<String, Workflow> = [
+            ("abc".to_string(), "abc{a<=5:xyz,m==3:A,R}".parse().unwrap()),
+            ("xyz".to_string(), "xyz{s>=8:A,R}".parse().unwrap()),
+        ]
+        .into();
+
+        let combinations =
+            get_combinations("abc", &workflows, &Ranges::cube(Interval::new(1, 11)), 0).unwrap();
+
+        // a <= 5 and s >= 8, or a > 5 and m == 3
+        assert_eq!(combinations, 10 * 10 * 5 * 3 + 10 * 10 * 5);
+    }
+
+    #[test]
+    fn test_get_combinations_with_one_split_more() {
+        let workflows: HashMap<String, Workflow> = [
+            ("abc".to_string(), "abc{a>5:xyz,R}".parse().unwrap()),
+            ("xyz".to_string(), "xyz{A}".parse().unwrap()),
+        ]
+        .into();
+
+        let combinations = get_combinations(
+            "abc",
+            &workflows,
+            &Ranges::new([
+                Interval::new(1, 11),
+                Interval::new(1, 11),
+                Interval::new(1, 11),
+                Interval::new(1, 11),
+            ]),
+            0,
+        )
+        .unwrap();
+
+        assert_eq!(combinations, 10 * 10 * 10 * 5);
+    }
+
+    #[test]
+    fn test_largest_rating_threshold() {
+        let input = "in{x>4294967295:A,m<=4294967295:px,R}\npx{a==4294967295:R,A}\n\n\
+                     {x=1,m=1,a=1,s=1}";
+        let (workflows, parts) = parse_input(input).unwrap();
+
+        // No rating is above the largest one, and every rating is at most the largest one
+        assert_eq!(part2(&workflows).unwrap(), 4000u128.pow(4));
+        assert_eq!(part1(&workflows, &parts).unwrap(), 4);
+        assert!(Day::explain(&(workflows, parts)).unwrap().is_some());
+    }
+
+    #[test]
+    fn test_accepted_ranges() {
+        let input = "in{x<10:A,m>5:abc,R}\nabc{a==3:A,R}\n\n{x=1,m=1,a=1,s=1}";
+        let (workflows, _) = parse_input(input).unwrap();
+
+        let accepted = accepted(&workflows).unwrap();
+        let described: Vec<String> = accepted.iter().map(describe).collect();
+        assert_eq!(
+            described,
+            vec![
+                "x [1, 10), m [1, 4001), a [1, 4001), s [1, 4001)",
+                "x [10, 4001), m [6, 4001), a [3, 4), s [1, 4001)",
+            ]
+        );
+        assert_eq!(
+            accepted.iter().map(Ranges::volume).sum::<u128>(),
+            part2(&workflows).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_workflow_loops_are_errors() {
+        let input = "in{x>10:qqz,R}\nqqz{in}\n\n{x=787,m=2655,a=1222,s=2876}";
+        let (workflows, parts) = parse_input(input).unwrap();
+
+        assert!(part1(&workflows, &parts)
+            .unwrap_err()
+            .to_string()
+            .contains("The workflows loop"));
+        assert!(part2(&workflows)
+            .unwrap_err()
+            .to_string()
+            .contains("The workflows loop"));
+    }
+
+    #[test]
+    fn test_unknown_workflow_is_an_error() {
+        let input = "in{x>10:qqz,R}\n\n{x=787,m=2655,a=1222,s=2876}";
+        let (workflows, parts) = parse_input(input).unwrap();
+
+        assert_eq!(
+            part1(&workflows, &parts).unwrap_err().to_string(),
+            "Error: Unknown workflow: qqz"
+        );
+    }
+}
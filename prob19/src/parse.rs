@@ -1,15 +1,15 @@
 use crate::Category;
+use advent::hyperrect::Comparison;
 use advent::prelude::*;
 use nom::{
     branch::alt,
     bytes::complete::tag,
     character::complete::{alpha1, char, digit1, one_of},
-    combinator::{map, map_res, opt},
+    combinator::{map_opt, map_res, opt},
     multi::separated_list1,
     sequence::{delimited, terminated, tuple},
     IResult,
 };
-use std::cmp::Ordering;
 
 /// XMAS Part
 ///
@@ -39,28 +39,31 @@ fn category_from_char(c: char) -> Result<Category> {
     }
 }
 
-type ConditionTuple = (Category, Ordering, u32);
+type ConditionTuple = (Category, Comparison, u32);
 type ConditionsVec<'a> = Vec<(Option<ConditionTuple>, &'a str)>;
 
 /// Parse condition
 ///
 /// A condition is a letter, followed by a comparison operator, followed by a number, like this:
 ///
-///     * a>1716
-///     * b<519
-fn parse_condition_part(input: &str) -> IResult<&str, (Category, Ordering, u32)> {
-    let parse_ordering = alt((
-        map(tag(">"), |_| Ordering::Greater),
-        map(tag("<"), |_| Ordering::Less),
-    ));
-
-    let (input, (cond_char, ordering, number)) = tuple((
+/// * a>1716
+/// * b<519
+/// * m>=20
+/// * s==1000
+fn parse_condition_part(input: &str) -> IResult<&str, ConditionTuple> {
+    // The two character operators have to be tried before their one character prefixes
+    let parse_comparison = map_opt(
+        alt((tag("<="), tag(">="), tag("=="), tag("<"), tag(">"))),
+        Comparison::from_symbol,
+    );
+
+    let (input, (cond_char, comparison, number)) = tuple((
         map_res(one_of("xmas"), category_from_char),
-        parse_ordering,
+        parse_comparison,
         map_res(digit1, str::parse),
     ))(input)?;
 
-    Ok((input, (cond_char, ordering, number)))
+    Ok((input, (cond_char, comparison, number)))
 }
 
 pub fn condition(input: &str) -> IResult<&str, (Option<ConditionTuple>, &str)> {
@@ -70,14 +73,33 @@ pub fn condition(input: &str) -> IResult<&str, (Option<ConditionTuple>, &str)> {
     Ok((input, (condition, final_string)))
 }
 
-pub fn conditions(input: &str) -> IResult<&str, ConditionsVec> {
+pub fn conditions(input: &str) -> IResult<&str, ConditionsVec<'_>> {
     separated_list1(char(','), condition)(input)
 }
 
-pub fn workflow(input: &str) -> IResult<&str, (&str, ConditionsVec)> {
+pub fn workflow(input: &str) -> IResult<&str, (&str, ConditionsVec<'_>)> {
     tuple((alpha1, delimited(char('{'), conditions, char('}'))))(input)
 }
 
+/// Run a parser over the whole input
+///
+/// If the parser fails or leaves any input behind, the error points at where it stopped
+pub fn parse_all<'a, O>(
+    input: &'a str,
+    mut parser: impl FnMut(&'a str) -> IResult<&'a str, O>,
+) -> Result<O> {
+    match parser(input) {
+        Ok(("", output)) => Ok(output),
+        Ok((rest, _)) => Err(parse_error!("Unexpected trailing input", input, rest)),
+        Err(nom::Err::Error(e) | nom::Err::Failure(e)) => Err(parse_error!(
+            format!("Unexpected input ({})", e.code.description()),
+            input,
+            e.input
+        )),
+        Err(nom::Err::Incomplete(_)) => Err(parse_error!("Incomplete input")),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -94,15 +116,32 @@ mod tests {
     fn test_parse_condition_part() {
         assert_eq!(
             parse_condition_part("x>1716:A"),
-            Ok((":A", (Category::X, Ordering::Greater, 1716)))
+            Ok((":A", (Category::X, Comparison::Gt, 1716)))
+        );
+    }
+
+    #[test]
+    fn test_parse_condition_part_comparisons() {
+        assert_eq!(
+            parse_condition_part("a<=5"),
+            Ok(("", (Category::A, Comparison::Le, 5)))
+        );
+        assert_eq!(
+            parse_condition_part("s>=5"),
+            Ok(("", (Category::S, Comparison::Ge, 5)))
+        );
+        assert_eq!(
+            parse_condition_part("m==5"),
+            Ok(("", (Category::M, Comparison::Eq, 5)))
         );
+        assert!(parse_condition_part("x=5").is_err());
     }
 
     #[test]
     fn test_condition() {
         assert_eq!(
             condition("m>1716:A"),
-            Ok(("", (Some((Category::M, Ordering::Greater, 1716)), "A")))
+            Ok(("", (Some((Category::M, Comparison::Gt, 1716)), "A")))
         )
     }
 
@@ -113,8 +152,8 @@ mod tests {
             Ok((
                 "",
                 vec![
-                    (Some((Category::M, Ordering::Greater, 1716)), "A"),
-                    (Some((Category::X, Ordering::Less, 1716)), "B"),
+                    (Some((Category::M, Comparison::Gt, 1716)), "A"),
+                    (Some((Category::X, Comparison::Lt, 1716)), "B"),
                     (None, "R")
                 ]
             ))
@@ -130,8 +169,8 @@ mod tests {
                 (
                     "A",
                     vec![
-                        (Some((Category::M, Ordering::Greater, 1716)), "A"),
-                        (Some((Category::X, Ordering::Less, 1716)), "B"),
+                        (Some((Category::M, Comparison::Gt, 1716)), "A"),
+                        (Some((Category::X, Comparison::Lt, 1716)), "B"),
                         (None, "R")
                     ]
                 )
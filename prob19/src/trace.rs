@@ -0,0 +1,205 @@
+use crate::{Condition, Part, Workflow};
+use advent::prelude::*;
+use std::fmt;
+
+/// A rule a part was tested against, and whether it matched
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tested {
+    /// Where the rule is in the workflow, counting from 1
+    pub position: usize,
+    pub rule: Condition,
+    /// The rating the rule compared, or None if the rule matches every part
+    pub rating: Option<u32>,
+    pub matched: bool,
+}
+
+/// A workflow a part went through, with the rules it was tested against until one sent it on
+#[derive(Debug, Clone, PartialEq)]
+pub struct Step {
+    pub workflow: String,
+    pub tested: Vec<Tested>,
+}
+
+impl Step {
+    /// The rule that sent the part on, which is the last one tested
+    pub fn fired(&self) -> Option<&Tested> {
+        self.tested.last().filter(|tested| tested.matched)
+    }
+}
+
+/// Where a part went through the workflows, from `in` until it's accepted or rejected
+#[derive(Debug, Clone, PartialEq)]
+pub struct Trace {
+    pub part: Part,
+    pub steps: Vec<Step>,
+    pub accepted: bool,
+}
+
+impl Part {
+    /// Run the part through the workflows, recording every rule it's tested against
+    pub fn trace(&self, workflows: &HashMap<String, Workflow>) -> Result<Trace> {
+        let mut steps: Vec<Step> = Vec::new();
+        let mut name = "in";
+        while name != "A" && name != "R" {
+            let workflow = workflows
+                .get(name)
+                .ok_or(error!("Unknown workflow: {}", name))?;
+            // A part that goes through more workflows than there are is stuck in a loop
+            if steps.len() >= workflows.len() {
+                let path: Vec<&str> = steps.iter().map(|step| step.workflow.as_str()).collect();
+                return Err(error!(
+                    "The workflows loop: {} -> {}",
+                    path.join(" -> "),
+                    name
+                ));
+            }
+
+            let mut tested = Vec::new();
+            for (idx, rule) in workflow.conditions.iter().enumerate() {
+                let (rating, matched) = match rule.test(&workflow.name)? {
+                    Some((category, comparison, value)) => {
+                        let rating = self.value(&category);
+                        (Some(rating), comparison.holds(rating, value))
+                    }
+                    None => (None, true),
+                };
+                tested.push(Tested {
+                    position: idx + 1,
+                    rule: rule.clone(),
+                    rating,
+                    matched,
+                });
+                if matched {
+                    break;
+                }
+            }
+
+            let step = Step {
+                workflow: workflow.name.clone(),
+                tested,
+            };
+            name = match step.fired() {
+                Some(fired) => &workflow.conditions[fired.position - 1].workflow_name,
+                None => return Err(error!("No condition in workflow {} matched", workflow.name)),
+            };
+            steps.push(step);
+        }
+
+        Ok(Trace {
+            part: self.clone(),
+            steps,
+            accepted: name == "A",
+        })
+    }
+}
+
+impl fmt::Display for Trace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let outcome = match self.accepted {
+            true => "accepted",
+            false => "rejected",
+        };
+        writeln!(f, "{} is {}", self.part, outcome)?;
+        for step in &self.steps {
+            writeln!(f, "  {}", step.workflow)?;
+            for tested in &step.tested {
+                let matched = match tested.matched {
+                    true => "matches",
+                    false => "doesn't match",
+                };
+                write!(f, "    {}. {} {}", tested.position, tested.rule, matched)?;
+                match (tested.rule.category, tested.rating) {
+                    (Some(category), Some(rating)) => writeln!(f, ", {} is {}", category, rating)?,
+                    _ => writeln!(f)?,
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trace_example() {
+        let input = include_str!("../test.txt");
+        let (workflows, parts) = crate::parse_input(input).unwrap();
+
+        let trace = parts[0].trace(&workflows).unwrap();
+        assert!(trace.accepted);
+        let visited: Vec<&str> = trace
+            .steps
+            .iter()
+            .map(|step| step.workflow.as_str())
+            .collect();
+        assert_eq!(visited, vec!["in", "qqz", "qs", "lnx"]);
+        assert_eq!(
+            trace.steps[0].tested,
+            vec![
+                Tested {
+                    position: 1,
+                    rule: "s<1351:px".parse().unwrap(),
+                    rating: Some(2876),
+                    matched: false,
+                },
+                Tested {
+                    position: 2,
+                    rule: "qqz".parse().unwrap(),
+                    rating: None,
+                    matched: true,
+                },
+            ]
+        );
+        assert_eq!(trace.steps[0].fired().map(|fired| fired.position), Some(2));
+
+        let trace = parts[1].trace(&workflows).unwrap();
+        assert!(!trace.accepted);
+        assert_eq!(
+            trace.to_string(),
+            "{x=1679,m=44,a=2067,s=496} is rejected\n\
+             \x20 in\n\
+             \x20   1. s<1351:px matches, s is 496\n\
+             \x20 px\n\
+             \x20   1. a<2006:qkq doesn't match, a is 2067\n\
+             \x20   2. m>2090:A doesn't match, m is 44\n\
+             \x20   3. rfg matches\n\
+             \x20 rfg\n\
+             \x20   1. s<537:gd matches, s is 496\n\
+             \x20 gd\n\
+             \x20   1. a>3333:R doesn't match, a is 2067\n\
+             \x20   2. R matches\n"
+        );
+
+        let accepted: Vec<bool> = parts
+            .iter()
+            .map(|part| part.trace(&workflows).unwrap().accepted)
+            .collect();
+        assert_eq!(accepted, vec![true, false, true, false, true]);
+    }
+
+    #[test]
+    fn test_trace_errors() {
+        let part: Part = "{x=787,m=2655,a=1222,s=2876}".parse().unwrap();
+
+        let (workflows, _) =
+            crate::parse_input("in{x>10:qqz,R}\nqqz{in}\n\n{x=1,m=1,a=1,s=1}").unwrap();
+        assert_eq!(
+            part.trace(&workflows).unwrap_err().to_string(),
+            "Error: The workflows loop: in -> qqz -> in"
+        );
+
+        let (workflows, _) = crate::parse_input("in{x>10:qqz,R}\n\n{x=1,m=1,a=1,s=1}").unwrap();
+        assert_eq!(
+            part.trace(&workflows).unwrap_err().to_string(),
+            "Error: Unknown workflow: qqz"
+        );
+
+        let (workflows, _) = crate::parse_input("in{x<10:A}\n\n{x=1,m=1,a=1,s=1}").unwrap();
+        assert_eq!(
+            part.trace(&workflows).unwrap_err().to_string(),
+            "Error: No condition in workflow in matched"
+        );
+    }
+}
@@ -0,0 +1,213 @@
+use advent::prelude::*;
+
+#[derive(Debug, PartialEq)]
+pub struct Game {
+    num: u32,
+    rounds: Vec<Round>,
+}
+
+#[derive(Debug, PartialEq)]
+struct Round {
+    red: Option<u32>,
+    green: Option<u32>,
+    blue: Option<u32>,
+}
+
+impl FromStr for Game {
+    type Err = AdventError;
+
+    fn from_str(line: &str) -> std::result::Result<Self, Self::Err> {
+        let (game, rounds_str) = line.split_once(": ").ok_or(parse_error!(
+            "Unable to parse rounds from line",
+            line,
+            line
+        ))?;
+        let num = parse_at(line, game.trim_start_matches("Game "))?;
+
+        let mut rounds = Vec::new();
+        for round_str in rounds_str.split("; ") {
+            let mut round = Round {
+                red: None,
+                green: None,
+                blue: None,
+            };
+            for colour in round_str.split(", ") {
+                let (amount, colour) = colour.split_once(' ').ok_or(parse_error!(
+                    "Unable to parse amount and colour",
+                    line,
+                    colour
+                ))?;
+                let amount = parse_at(line, amount)?;
+                match colour {
+                    "red" => round.red = Some(amount),
+                    "green" => round.green = Some(amount),
+                    "blue" => round.blue = Some(amount),
+                    _ => {
+                        return Err(parse_error!(
+                            format!("Unknown colour: {}", colour),
+                            line,
+                            colour
+                        ))
+                    }
+                }
+            }
+            rounds.push(round);
+        }
+
+        Ok(Game { num, rounds })
+    }
+}
+
+impl Game {
+    fn above_max(&self, red: u32, green: u32, blue: u32) -> bool {
+        for round in &self.rounds {
+            if let Some(amount) = round.red {
+                if amount > red {
+                    return true;
+                }
+            }
+            if let Some(amount) = round.green {
+                if amount > green {
+                    return true;
+                }
+            }
+            if let Some(amount) = round.blue {
+                if amount > blue {
+                    return true;
+                }
+            }
+        }
+        false
+    }
+
+    fn min_power(&self) -> Result<u32> {
+        let mut min_red = 0;
+        let mut min_green = 0;
+        let mut min_blue = 0;
+
+        for round in &self.rounds {
+            if let Some(amount) = round.red {
+                min_red = std::cmp::max(min_red, amount);
+            }
+            if let Some(amount) = round.green {
+                min_green = std::cmp::max(min_green, amount);
+            }
+            if let Some(amount) = round.blue {
+                min_blue = std::cmp::max(min_blue, amount);
+            }
+        }
+        min_red
+            .checked_mul(min_green)
+            .and_then(|power| power.checked_mul(min_blue))
+            .ok_or(error!("Power of game {} overflows", self.num))
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Vec<Game>;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        parse_lines(input, str::parse).context("parsing games")
+    }
+
+    fn part1(games: &Self::Parsed) -> Result<Answer> {
+        Ok(part1(games)?.into())
+    }
+
+    fn part2(games: &Self::Parsed) -> Result<Answer> {
+        Ok(part2(games)?.into())
+    }
+}
+
+fn part1(games: &[Game]) -> Result<u32> {
+    let red = 12;
+    let green = 13;
+    let blue = 14;
+
+    games
+        .iter()
+        .filter(|game| !game.above_max(red, green, blue))
+        .try_fold(0u32, |sum, game| {
+            sum.checked_add(game.num)
+                .ok_or(error!("Sum of game numbers overflows"))
+        })
+}
+
+fn part2(games: &[Game]) -> Result<u32> {
+    games.iter().try_fold(0u32, |sum, game| {
+        sum.checked_add(game.min_power()?)
+            .ok_or(error!("Sum of powers overflows"))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_game_from_line() {
+        let line = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green";
+
+        let game: Game = line.parse().unwrap();
+
+        assert_eq!(
+            game,
+            Game {
+                num: 1,
+                rounds: vec![
+                    Round {
+                        red: Some(4),
+                        green: None,
+                        blue: Some(3),
+                    },
+                    Round {
+                        red: Some(1),
+                        green: Some(2),
+                        blue: Some(6),
+                    },
+                    Round {
+                        red: None,
+                        green: Some(2),
+                        blue: None,
+                    },
+                ],
+            }
+        );
+    }
+
+    #[test]
+    fn test_game_parse_error_span() {
+        let input = "Game 1: 3 blue, 4 red\nGame 2: 1 red; 2 purple";
+
+        let err = Day::parse(input).unwrap_err();
+
+        assert_eq!(
+            err.span(),
+            Some(&Span::new(2, 17..23, "Game 2: 1 red; 2 purple"))
+        );
+        assert!(err.render().contains("Unknown colour: purple"));
+    }
+
+    #[test]
+    fn test_overflow_is_an_error() {
+        let games: Vec<Game> = ["Game 4294967295: 1 red", "Game 1: 1 red"]
+            .iter()
+            .map(|line| line.parse().unwrap())
+            .collect();
+        assert_eq!(
+            part1(&games).unwrap_err().to_string(),
+            "Error: Sum of game numbers overflows"
+        );
+
+        let games: Vec<Game> = ["Game 1: 65536 red, 65536 green, 1 blue"]
+            .iter()
+            .map(|line| line.parse().unwrap())
+            .collect();
+        assert_eq!(
+            part2(&games).unwrap_err().to_string(),
+            "Error: Power of game 1 overflows"
+        );
+    }
+}
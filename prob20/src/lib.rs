@@ -0,0 +1,1266 @@
+use advent::math::lcm_all;
+use advent::prelude::*;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::{self, Write};
+
+/// A pulse from one module to another, as its source, whether it's high and its destination
+pub type Signal = (String, bool, String);
+type Modules = HashMap<String, Box<dyn IO>>;
+
+/// The types of module, which decide what a module does with a pulse
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    FlipFlop,
+    Conjunction,
+    Broadcaster,
+}
+
+impl fmt::Display for Kind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Kind::FlipFlop => write!(f, "flip-flop"),
+            Kind::Conjunction => write!(f, "conjunction"),
+            Kind::Broadcaster => write!(f, "broadcaster"),
+        }
+    }
+}
+
+impl Kind {
+    /// The shape of the module in a graph
+    fn shape(self) -> &'static str {
+        match self {
+            Kind::FlipFlop => "box",
+            // Wide at the top, where the inputs come in
+            Kind::Conjunction => "invtrapezium",
+            Kind::Broadcaster => "diamond",
+        }
+    }
+}
+
+trait IO: std::fmt::Debug {
+    fn input(&mut self, high_pulse: bool, source: Option<&str>) -> Option<Vec<Signal>>;
+    fn add_input(&mut self, input: &str);
+    fn add_connection(&mut self, connection: &str);
+    fn get_name(&self) -> &str;
+    fn kind(&self) -> Kind;
+    fn state(&self) -> State;
+    fn set_state(&mut self, state: &State) -> Result<()>;
+    fn clone_box(&self) -> Box<dyn IO>;
+}
+
+/// What a module remembers between pulses
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum State {
+    /// Whether a flip-flop is on
+    FlipFlop(bool),
+    /// The most recent pulse from each input of a conjunction, where true is high
+    Conjunction(BTreeMap<String, bool>),
+    /// The broadcaster doesn't remember anything
+    Stateless,
+}
+
+fn mismatched_state(module: &dyn IO, state: &State) -> AdventError {
+    error!(
+        "Unable to restore the {} {} to {:?}",
+        module.kind(),
+        module.get_name(),
+        state
+    )
+}
+
+impl Clone for Box<dyn IO> {
+    fn clone(&self) -> Self {
+        self.clone_box()
+    }
+}
+
+#[derive(Debug, Clone)]
+struct FlipFlop {
+    name: String,
+    state: bool,
+    connections: Vec<String>,
+}
+
+impl FlipFlop {
+    fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            state: false,
+            connections: vec![],
+        }
+    }
+}
+
+impl IO for FlipFlop {
+    /// A FlipFlop will flip it's state on low pulse
+    fn input(&mut self, high_pulse: bool, _source: Option<&str>) -> Option<Vec<Signal>> {
+        if !high_pulse {
+            self.state = !self.state;
+            Some(
+                self.connections
+                    .iter()
+                    .map(|connection| (self.name.clone(), self.state, connection.to_string()))
+                    .collect(),
+            )
+        } else {
+            None
+        }
+    }
+
+    fn add_connection(&mut self, connection: &str) {
+        self.connections.push(connection.to_string());
+    }
+
+    fn add_input(&mut self, _input: &str) {}
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    fn kind(&self) -> Kind {
+        Kind::FlipFlop
+    }
+
+    fn state(&self) -> State {
+        State::FlipFlop(self.state)
+    }
+
+    fn set_state(&mut self, state: &State) -> Result<()> {
+        match state {
+            State::FlipFlop(on) => {
+                self.state = *on;
+                Ok(())
+            }
+            _ => Err(mismatched_state(self, state)),
+        }
+    }
+
+    fn clone_box(&self) -> Box<dyn IO> {
+        Box::new(self.clone())
+    }
+}
+
+#[derive(Default, Debug, Clone)]
+struct Conjunction {
+    name: String,
+    states: HashMap<String, bool>,
+    connections: Vec<String>,
+}
+
+impl Conjunction {
+    fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            states: HashMap::new(),
+            connections: vec![],
+        }
+    }
+}
+
+impl IO for Conjunction {
+    /// A Conjunction will remember the most recent pulse for each connection. When a pulse is
+    /// received, the conjunction module first updates its memory for that input. Then, if it
+    /// remembers high pulse for all inputs, it sends a low pulse; otherwise, it sends a high pulse
+    fn input(&mut self, high_pulse: bool, source: Option<&str>) -> Option<Vec<Signal>> {
+        // Every input is known after parsing, but the button isn't, so it's remembered like the rest
+        if let Some(source) = source {
+            self.states.insert(source.to_string(), high_pulse);
+        }
+
+        let output = !self.states.values().all(|&state| state);
+        Some(
+            self.connections
+                .iter()
+                .map(|connection| (self.name.clone(), output, connection.to_string()))
+                .collect(),
+        )
+    }
+
+    fn add_input(&mut self, input: &str) {
+        self.states.insert(input.to_string(), false);
+    }
+
+    fn add_connection(&mut self, connection: &str) {
+        self.connections.push(connection.to_string());
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    fn kind(&self) -> Kind {
+        Kind::Conjunction
+    }
+
+    fn state(&self) -> State {
+        State::Conjunction(
+            self.states
+                .iter()
+                .map(|(input, &high_pulse)| (input.clone(), high_pulse))
+                .collect(),
+        )
+    }
+
+    fn set_state(&mut self, state: &State) -> Result<()> {
+        match state {
+            State::Conjunction(states) => {
+                self.states = states
+                    .iter()
+                    .map(|(input, &high_pulse)| (input.clone(), high_pulse))
+                    .collect();
+                Ok(())
+            }
+            _ => Err(mismatched_state(self, state)),
+        }
+    }
+
+    fn clone_box(&self) -> Box<dyn IO> {
+        Box::new(self.clone())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Broadcaster {
+    name: String,
+    connections: Vec<String>,
+}
+
+impl Broadcaster {
+    fn new() -> Self {
+        Self {
+            name: "broadcaster".to_string(),
+            connections: vec![],
+        }
+    }
+}
+
+impl IO for Broadcaster {
+    fn input(&mut self, high_pulse: bool, _source: Option<&str>) -> Option<Vec<Signal>> {
+        Some(
+            self.connections
+                .iter()
+                .map(|connection| (self.name.clone(), high_pulse, connection.to_string()))
+                .collect(),
+        )
+    }
+
+    fn add_connection(&mut self, connection: &str) {
+        self.connections.push(connection.to_string());
+    }
+
+    fn add_input(&mut self, _input: &str) {}
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    fn kind(&self) -> Kind {
+        Kind::Broadcaster
+    }
+
+    fn state(&self) -> State {
+        State::Stateless
+    }
+
+    fn set_state(&mut self, state: &State) -> Result<()> {
+        match state {
+            State::Stateless => Ok(()),
+            _ => Err(mismatched_state(self, state)),
+        }
+    }
+
+    fn clone_box(&self) -> Box<dyn IO> {
+        Box::new(self.clone())
+    }
+}
+
+/// How many pulses a single press of the button can send before the network is considered stuck,
+/// since conjunctions wired into a loop can keep pulsing forever
+const MAX_PULSES_PER_PRESS: usize = 100_000;
+
+/// Watches the pulses of a network as its button is pressed
+pub trait Observer {
+    /// See a pulse, sent on the given press, before it's handled
+    fn pulse(&mut self, press: u64, signal: &Signal);
+
+    /// Called once every pulse of a press has been handled
+    fn pressed(&mut self, _press: u64) {}
+
+    /// Whether there's no need to press the button any more, see [`Network::press_until`]
+    fn done(&self) -> bool {
+        false
+    }
+}
+
+impl<F: FnMut(u64, &Signal)> Observer for F {
+    fn pulse(&mut self, press: u64, signal: &Signal) {
+        self(press, signal)
+    }
+}
+
+/// A pair of observers is done once either of them is, like a stop condition and a recorder
+impl<A: Observer, B: Observer> Observer for (A, B) {
+    fn pulse(&mut self, press: u64, signal: &Signal) {
+        self.0.pulse(press, signal);
+        self.1.pulse(press, signal);
+    }
+
+    fn pressed(&mut self, press: u64) {
+        self.0.pressed(press);
+        self.1.pressed(press);
+    }
+
+    fn done(&self) -> bool {
+        self.0.done() || self.1.done()
+    }
+}
+
+/// A list of observers is done once all of them are
+impl<T: Observer> Observer for Vec<T> {
+    fn pulse(&mut self, press: u64, signal: &Signal) {
+        self.iter_mut()
+            .for_each(|observer| observer.pulse(press, signal));
+    }
+
+    fn pressed(&mut self, press: u64) {
+        self.iter_mut().for_each(|observer| observer.pressed(press));
+    }
+
+    fn done(&self) -> bool {
+        self.iter().all(|observer| observer.done())
+    }
+}
+
+/// Remembers the presses on which a module sent a high pulse, or a low one
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Watch {
+    module: String,
+    high_pulse: bool,
+    /// Only pulses to this module count, if set
+    destination: Option<String>,
+    /// The presses the module sent such a pulse on, in order and once even if it sent several
+    pub presses: Vec<u64>,
+}
+
+impl Watch {
+    pub fn high(module: &str) -> Self {
+        Self {
+            module: module.to_string(),
+            high_pulse: true,
+            destination: None,
+            presses: vec![],
+        }
+    }
+
+    pub fn low(module: &str) -> Self {
+        Self {
+            high_pulse: false,
+            ..Self::high(module)
+        }
+    }
+
+    /// Only watch the pulses the module sends to `destination`
+    pub fn to(self, destination: &str) -> Self {
+        Self {
+            destination: Some(destination.to_string()),
+            ..self
+        }
+    }
+}
+
+impl Observer for Watch {
+    fn pulse(&mut self, press: u64, (source, high_pulse, destination): &Signal) {
+        if *source == self.module
+            && *high_pulse == self.high_pulse
+            && self
+                .destination
+                .as_ref()
+                .is_none_or(|module| module == destination)
+            && self.presses.last() != Some(&press)
+        {
+            self.presses.push(press);
+        }
+    }
+}
+
+/// Done once a pulse matches the predicate, remembering the first one that did
+#[derive(Debug, Clone)]
+pub struct StopWhen<P> {
+    predicate: P,
+    /// The first matching pulse and the press it was sent on
+    pub matched: Option<(u64, Signal)>,
+}
+
+impl<P: Fn(&Signal) -> bool> StopWhen<P> {
+    pub fn new(predicate: P) -> Self {
+        Self {
+            predicate,
+            matched: None,
+        }
+    }
+}
+
+impl<P: Fn(&Signal) -> bool> Observer for StopWhen<P> {
+    fn pulse(&mut self, press: u64, signal: &Signal) {
+        if self.matched.is_none() && (self.predicate)(signal) {
+            self.matched = Some((press, signal.clone()));
+        }
+    }
+
+    fn done(&self) -> bool {
+        self.matched.is_some()
+    }
+}
+
+/// Counts the low and high pulses sent on each press
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PulseCounts {
+    /// The number of low and high pulses of each press, in order
+    pub presses: Vec<(u64, u64)>,
+    current: (u64, u64),
+}
+
+impl PulseCounts {
+    /// The number of low and high pulses over every press
+    pub fn total(&self) -> (u64, u64) {
+        self.presses
+            .iter()
+            .fold((0, 0), |(low, high), (l, h)| (low + l, high + h))
+    }
+}
+
+impl Observer for PulseCounts {
+    fn pulse(&mut self, _press: u64, (_, high_pulse, _): &Signal) {
+        match high_pulse {
+            true => self.current.1 += 1,
+            false => self.current.0 += 1,
+        }
+    }
+
+    fn pressed(&mut self, _press: u64) {
+        self.presses.push(std::mem::take(&mut self.current));
+    }
+}
+
+/// The state of every module at some point, to go back to with [`Network::restore`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Snapshot {
+    presses: u64,
+    states: BTreeMap<String, State>,
+}
+
+/// The modules wired up, along with how many times the button has been pressed
+///
+/// The parsed network hasn't been pressed yet, so each part presses a clone of it.
+#[derive(Debug, Clone)]
+pub struct Network {
+    modules: Modules,
+    presses: u64,
+}
+
+impl Network {
+    /// Wire up the modules of a spec, before the button has been pressed
+    pub fn new(spec: &NetworkSpec) -> Self {
+        let mut modules: Modules = HashMap::new();
+        for module in &spec.modules {
+            let mut io: Box<dyn IO> = match module.kind {
+                Kind::FlipFlop => Box::new(FlipFlop::new(&module.name)),
+                Kind::Conjunction => Box::new(Conjunction::new(&module.name)),
+                Kind::Broadcaster => Box::new(Broadcaster::new()),
+            };
+            for destination in &module.destinations {
+                io.add_connection(destination);
+            }
+            // Conjunctions need to know about all their inputs before any pulse is sent
+            for input in spec.inputs(&module.name) {
+                io.add_input(input);
+            }
+            modules.insert(module.name.clone(), io);
+        }
+
+        Self {
+            modules,
+            presses: 0,
+        }
+    }
+
+    /// How many times the button has been pressed
+    pub fn presses(&self) -> u64 {
+        self.presses
+    }
+
+    /// Press the button once, returning every pulse in the order they were sent
+    pub fn press(&mut self) -> Result<Vec<Signal>> {
+        let mut signals = Vec::new();
+        self.press_with(&mut |_, signal: &Signal| signals.push(signal.clone()))?;
+        Ok(signals)
+    }
+
+    /// Press the button once and send pulses until they have all been handled, showing each one
+    /// to the observer before it's handled
+    pub fn press_with(&mut self, observer: &mut impl Observer) -> Result<()> {
+        self.presses += 1;
+        let mut signals: VecDeque<Signal> =
+            vec![("button".to_string(), false, "broadcaster".to_string())].into();
+        let mut pulses = 0;
+        while let Some(signal) = signals.pop_front() {
+            pulses += 1;
+            if pulses > MAX_PULSES_PER_PRESS {
+                return Err(error!(
+                    "The network sent more than {} pulses in a single press",
+                    MAX_PULSES_PER_PRESS
+                ));
+            }
+            observer.pulse(self.presses, &signal);
+
+            let (source, high_pulse, destination) = signal;
+            // Modules that aren't defined, like rx, only receive pulses
+            if let Some(module) = self.modules.get_mut(&destination) {
+                if let Some(new_signals) = module.input(high_pulse, Some(&source)) {
+                    signals.extend(new_signals);
+                }
+            }
+        }
+        observer.pressed(self.presses);
+        Ok(())
+    }
+
+    /// Press the button until the observer is done, returning the press it was done on, or None
+    /// if it still isn't after `max_presses` more
+    pub fn press_until(
+        &mut self,
+        max_presses: u64,
+        observer: &mut impl Observer,
+    ) -> Result<Option<u64>> {
+        for _ in 0..max_presses {
+            self.press_with(observer)?;
+            if observer.done() {
+                return Ok(Some(self.presses));
+            }
+        }
+        Ok(None)
+    }
+
+    /// The state of every module, along with the number of presses so far
+    pub fn snapshot(&self) -> Snapshot {
+        Snapshot {
+            presses: self.presses,
+            states: self
+                .modules
+                .iter()
+                .map(|(name, module)| (name.clone(), module.state()))
+                .collect(),
+        }
+    }
+
+    /// Go back to a snapshot of this network, leaving it as it was if the snapshot doesn't fit
+    pub fn restore(&mut self, snapshot: &Snapshot) -> Result<()> {
+        if snapshot.states.len() != self.modules.len() {
+            return Err(error!(
+                "The snapshot has {} modules, but the network has {}",
+                snapshot.states.len(),
+                self.modules.len()
+            ));
+        }
+        let mut modules = self.modules.clone();
+        for (name, module) in modules.iter_mut() {
+            let state = snapshot
+                .states
+                .get(name)
+                .ok_or(error!("The snapshot has no state for {}", name))?;
+            module.set_state(state)?;
+        }
+        self.modules = modules;
+        self.presses = snapshot.presses;
+        Ok(())
+    }
+}
+
+/// A module as it's written in the input, before it's wired up
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModuleSpec {
+    pub name: String,
+    pub kind: Kind,
+    pub destinations: Vec<String>,
+}
+
+/// The modules as they're written in the input, checked that they make up a network
+///
+/// Destinations that aren't defined as modules are sinks, like rx, which only receive pulses.
+/// Neither sinks nor modules that no pulse reaches are errors, but they're kept for callers to
+/// check, since either can be a typo in the input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NetworkSpec {
+    /// The modules in the order of the input
+    modules: Vec<ModuleSpec>,
+    /// The destinations that aren't defined as modules, like rx, in order of their names
+    pub sinks: Vec<String>,
+    /// The modules that no pulse from the broadcaster can reach, in the order of the input
+    pub unreachable: Vec<String>,
+}
+
+impl NetworkSpec {
+    pub fn get(&self, name: &str) -> Option<&ModuleSpec> {
+        self.modules.iter().find(|module| module.name == name)
+    }
+
+    /// The modules that send pulses to `name`, in the order of the input
+    pub fn inputs(&self, name: &str) -> Vec<&str> {
+        self.modules
+            .iter()
+            .filter(|module| module.destinations.iter().any(|d| d == name))
+            .map(|module| module.name.as_str())
+            .collect()
+    }
+
+    fn find_sinks(&self) -> Vec<String> {
+        let sinks: BTreeSet<&String> = self
+            .modules
+            .iter()
+            .flat_map(|module| module.destinations.iter())
+            .filter(|destination| self.get(destination).is_none())
+            .collect();
+        sinks.into_iter().cloned().collect()
+    }
+
+    fn find_unreachable(&self) -> Vec<String> {
+        let mut reached: HashSet<&str> = HashSet::from(["broadcaster"]);
+        let mut queue: VecDeque<&str> = VecDeque::from(["broadcaster"]);
+        while let Some(name) = queue.pop_front() {
+            let destinations = self.get(name).map_or(&[][..], |m| &m.destinations[..]);
+            for destination in destinations {
+                if reached.insert(destination) {
+                    queue.push_back(destination);
+                }
+            }
+        }
+
+        self.modules
+            .iter()
+            .map(|module| &module.name)
+            .filter(|name| !reached.contains(name.as_str()))
+            .cloned()
+            .collect()
+    }
+
+    /// The network as a Graphviz DOT graph, with the type of each module as its shape
+    ///
+    /// Conjunctions are labelled with how many inputs they have, unreachable modules are dashed
+    /// and sinks are double circles.
+    pub fn to_dot(&self) -> String {
+        let (sinks, unreachable) = (&self.sinks, &self.unreachable);
+        let escape = |name: &str| name.replace('\\', "\\\\").replace('"', "\\\"");
+        let quote = |name: &str| format!("\"{}\"", escape(name));
+
+        // Writing to a string can't fail
+        let mut dot = String::from("digraph network {\n");
+        if !sinks.is_empty() {
+            let _ = writeln!(
+                dot,
+                "    // Sinks, which only receive pulses: {}",
+                sinks.join(", ")
+            );
+        }
+        if !unreachable.is_empty() {
+            let _ = writeln!(
+                dot,
+                "    // Unreachable from the broadcaster: {}",
+                unreachable.join(", ")
+            );
+        }
+
+        let _ = writeln!(dot, "    {} [shape=point];", quote("button"));
+        for module in &self.modules {
+            let mut attributes = vec![format!("shape={}", module.kind.shape())];
+            match module.kind {
+                Kind::FlipFlop => {
+                    attributes.push(format!("label={}", quote(&format!("%{}", module.name))))
+                }
+                Kind::Conjunction => {
+                    let inputs = self.inputs(&module.name).len();
+                    let plural = if inputs == 1 { "" } else { "s" };
+                    // Graphviz reads \n as a line break, so it goes in unescaped
+                    attributes.push(format!(
+                        "label=\"&{}\\n{} input{}\"",
+                        escape(&module.name),
+                        inputs,
+                        plural
+                    ));
+                }
+                Kind::Broadcaster => {}
+            }
+            if unreachable.contains(&module.name) {
+                attributes.push("style=dashed".to_string());
+            }
+            let _ = writeln!(
+                dot,
+                "    {} [{}];",
+                quote(&module.name),
+                attributes.join(", ")
+            );
+        }
+        for sink in sinks {
+            let _ = writeln!(dot, "    {} [shape=doublecircle];", quote(sink));
+        }
+
+        let _ = writeln!(dot, "    {} -> {};", quote("button"), quote("broadcaster"));
+        for module in &self.modules {
+            for destination in &module.destinations {
+                let _ = writeln!(
+                    dot,
+                    "    {} -> {};",
+                    quote(&module.name),
+                    quote(destination)
+                );
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+impl FromStr for NetworkSpec {
+    type Err = AdventError;
+
+    fn from_str(input: &str) -> std::result::Result<Self, Self::Err> {
+        // Lines will start with:
+        //  * 'broadcaster': A single line that is the main broadcaster
+        //  * %: A FlipFlop
+        //  * &: A Conjunction
+        //
+        // Lines will look like this:
+        //  broadcaster -> a, b, c
+        //  %a -> b
+        //  &inv -> a
+        let mut modules: Vec<ModuleSpec> = Vec::new();
+        // Where each module is defined, to point at when it turns out to be wrong
+        let mut sources: Vec<&str> = Vec::new();
+
+        for line in input.lines() {
+            let (source, destinations) = line.split_once(" -> ").ok_or(parse_error!(
+                "Expected a module and its destinations",
+                input,
+                line
+            ))?;
+
+            let (kind, name) = match (source.strip_prefix('%'), source.strip_prefix('&')) {
+                _ if source == "broadcaster" => (Kind::Broadcaster, source),
+                // The button always pushes to the broadcaster, so no other module can be called that
+                (Some(name), _) if name != "broadcaster" => (Kind::FlipFlop, name),
+                (_, Some(name)) if name != "broadcaster" => (Kind::Conjunction, name),
+                _ => {
+                    return Err(parse_error!(
+                        format!("Unknown module: {}", source),
+                        input,
+                        source
+                    ))
+                }
+            };
+            if name.is_empty() {
+                return Err(parse_error!(
+                    "Expected a name for the module",
+                    input,
+                    source
+                ));
+            }
+            if modules.iter().any(|module| module.name == name) {
+                return Err(parse_error!(
+                    format!("Module {} is defined more than once", name),
+                    input,
+                    source
+                ));
+            }
+            let destinations: Vec<String> = destinations.split(", ").map(str::to_string).collect();
+            if destinations
+                .iter()
+                .any(|destination| destination.is_empty())
+            {
+                return Err(parse_error!(
+                    "Expected a name for each destination",
+                    input,
+                    line
+                ));
+            }
+
+            modules.push(ModuleSpec {
+                name: name.to_string(),
+                kind,
+                destinations,
+            });
+            sources.push(source);
+        }
+
+        let mut spec = Self {
+            modules,
+            sinks: vec![],
+            unreachable: vec![],
+        };
+        if spec.get("broadcaster").is_none() {
+            return Err(parse_error!(
+                "There is no broadcaster for the button to send pulses to"
+            ));
+        }
+        // A conjunction without inputs would never get a pulse to send one of its own
+        for (module, source) in spec.modules.iter().zip(sources) {
+            if module.kind == Kind::Conjunction && spec.inputs(&module.name).is_empty() {
+                return Err(parse_error!(
+                    format!("Conjunction {} has no inputs", module.name),
+                    input,
+                    source
+                ));
+            }
+        }
+        spec.sinks = spec.find_sinks();
+        spec.unreachable = spec.find_unreachable();
+
+        Ok(spec)
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = NetworkSpec;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        input.parse().context("parsing modules")
+    }
+
+    fn part1(spec: &Self::Parsed) -> Result<Answer> {
+        Ok(part1(spec)?.into())
+    }
+
+    fn part2(spec: &Self::Parsed) -> Result<Answer> {
+        Ok(part2(spec)?.into())
+    }
+
+    fn explain(spec: &Self::Parsed) -> Result<Option<String>> {
+        Ok(Some(spec.to_dot()))
+    }
+}
+
+/// How many times part 2 presses the button while looking for the cycles, before giving up
+const MAX_PRESSES: u64 = 100_000;
+
+fn part1(spec: &NetworkSpec) -> Result<u64> {
+    let mut network = Network::new(spec);
+    let mut counts = PulseCounts::default();
+    for _ in 1..=1000 {
+        network.press_with(&mut counts)?;
+    }
+
+    let (low_pulse_count, high_pulse_count) = counts.total();
+    low_pulse_count
+        .checked_mul(high_pulse_count)
+        .ok_or(error!("Number of pulses overflows"))
+}
+
+/// Find the fewest presses of the button before rx receives a low pulse
+///
+/// Simulating until then would take far too long, but rx is fed by a single conjunction, which
+/// sends the low pulse once it remembers a high pulse from every one of its inputs. Each input
+/// sends a high pulse every so many presses, starting from the first, so the answer is the least
+/// common multiple of those cycle lengths. The shape of the network and the cycles are checked,
+/// with an error when they don't hold rather than an answer that would be wrong.
+fn part2(spec: &NetworkSpec) -> Result<u64> {
+    let feeder = match spec.inputs("rx")[..] {
+        [feeder] => feeder,
+        [] => return Err(error!("No module sends pulses to rx")),
+        ref sources => {
+            return Err(error!(
+                "Expected a single module to send pulses to rx, but {} do: {}",
+                sources.len(),
+                sources.join(", ")
+            ))
+        }
+    };
+    let kind = spec.get(feeder).map(|module| module.kind);
+    if kind != Some(Kind::Conjunction) {
+        return Err(error!(
+            "Expected rx to be fed by a conjunction, but {} is a {}",
+            feeder,
+            kind.map_or("sink".to_string(), |kind| kind.to_string())
+        ));
+    }
+    // Every conjunction has inputs, which parsing makes sure of
+    let inputs = spec.inputs(feeder);
+
+    let mut observers = (
+        // A network small enough to get there by simulating needs no cycles
+        StopWhen::new(|(_, high_pulse, destination): &Signal| destination == "rx" && !high_pulse),
+        inputs
+            .iter()
+            .map(|input| Watch::high(input).to(feeder))
+            .collect(),
+    );
+    let mut network = Network::new(spec);
+    // The states after each press, until the network is back in one it has been in before
+    let mut seen = HashMap::from([(network.snapshot().states, 0)]);
+    let mut repeats = None;
+    let mut max_presses = MAX_PRESSES;
+    while network.presses() < max_presses {
+        network.press_with(&mut observers)?;
+        let (rx_low, watches): &(_, Vec<Watch>) = &observers;
+        if let Some((press, _)) = rx_low.matched {
+            return Ok(press);
+        }
+        if watches.iter().all(|watch| watch.presses.len() >= 2) {
+            return combine_cycles(feeder, watches);
+        }
+
+        if repeats.is_none() {
+            if let Some(first) = seen.insert(network.snapshot().states, network.presses()) {
+                // Every press from here on is one the network has made before, so going round
+                // once more is enough for every input that ever sends a high pulse to send two
+                let period = network.presses() - first;
+                repeats = Some(period);
+                max_presses = max_presses.min(network.presses() + period);
+            }
+        }
+    }
+
+    let missing: Vec<&str> = observers
+        .1
+        .iter()
+        .filter(|watch| watch.presses.len() < 2)
+        .map(|watch| watch.module.as_str())
+        .collect();
+    match repeats {
+        Some(period) => Err(error!(
+            "The network repeats itself every {} presses without a cycle for the inputs of {}: {}",
+            period,
+            feeder,
+            missing.join(", ")
+        )),
+        None => Err(error!(
+            "No cycle found within {} presses for the inputs of {}: {}",
+            MAX_PRESSES,
+            feeder,
+            missing.join(", ")
+        )),
+    }
+}
+
+/// The first press on which every input of the feeder sends a high pulse, given the presses on
+/// which each of them did
+fn combine_cycles(feeder: &str, watches: &[Watch]) -> Result<u64> {
+    let mut lengths = Vec::new();
+    for watch in watches {
+        let (first, second) = (watch.presses[0], watch.presses[1]);
+        // The cycles only line up at their least common multiple when each starts from nothing
+        if second != 2 * first {
+            return Err(error!(
+                "{} sent {} a high pulse on presses {} and {}, which isn't a cycle from the start",
+                watch.module, feeder, first, second
+            ));
+        }
+        lengths.push(first);
+    }
+    lcm_all(lengths).ok_or(error!("The least common multiple of the cycles overflows"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = include_str!("../test.txt");
+    const TEST_INPUT_2: &str = include_str!("../test2.txt");
+    const TEST_INPUT_3: &str = include_str!("../test3.txt");
+
+    fn new_network(input: &str) -> Network {
+        Network::new(&input.parse().unwrap())
+    }
+
+    fn signal(source: &str, high_pulse: bool, destination: &str) -> Signal {
+        (source.to_string(), high_pulse, destination.to_string())
+    }
+
+    #[test]
+    fn test_network_press() {
+        let mut network = new_network(TEST_INPUT);
+
+        let signals = network.press().unwrap();
+
+        assert_eq!(
+            signals,
+            vec![
+                signal("button", false, "broadcaster"),
+                signal("broadcaster", false, "a"),
+                signal("broadcaster", false, "b"),
+                signal("broadcaster", false, "c"),
+                signal("a", true, "b"),
+                signal("b", true, "c"),
+                signal("c", true, "inv"),
+                signal("inv", false, "a"),
+                signal("a", false, "b"),
+                signal("b", false, "c"),
+                signal("c", false, "inv"),
+                signal("inv", true, "a"),
+            ]
+        );
+        assert_eq!(network.presses(), 1);
+    }
+
+    #[test]
+    fn test_pulse_counts() {
+        let mut network = new_network(TEST_INPUT_2);
+        let mut counts = PulseCounts::default();
+
+        for _ in 0..4 {
+            network.press_with(&mut counts).unwrap();
+        }
+
+        assert_eq!(counts.presses, vec![(4, 4), (4, 2), (5, 3), (4, 2)]);
+        assert_eq!(counts.total(), (17, 11));
+    }
+
+    #[test]
+    fn test_watch_and_stop() {
+        let mut network = new_network(TEST_INPUT_3);
+        let mut observers = (
+            StopWhen::new(|(source, high_pulse, _): &Signal| source == "ia" && *high_pulse),
+            vec![Watch::high("ib"), Watch::low("ca")],
+        );
+
+        assert_eq!(network.press_until(100, &mut observers).unwrap(), Some(11));
+        let (stop, watches) = observers;
+        assert_eq!(stop.matched, Some((11, signal("ia", true, "out"))));
+        assert_eq!(watches[0].presses, vec![]);
+        assert_eq!(watches[1].presses, vec![11]);
+
+        // A list of watches is never done
+        let mut watches = vec![Watch::high("ib")];
+        assert_eq!(network.press_until(20, &mut watches).unwrap(), None);
+        assert_eq!(watches[0].presses, vec![13, 26]);
+
+        // Only the pulses to the destination count
+        let mut network = new_network("broadcaster -> a, b\n%a -> c, d\n&c -> rx\n&d -> rx");
+        let mut watches = vec![Watch::high("a").to("c"), Watch::high("a").to("b")];
+        network.press_until(2, &mut watches).unwrap();
+        assert_eq!(watches[0].presses, vec![1]);
+        assert_eq!(watches[1].presses, vec![]);
+    }
+
+    #[test]
+    fn test_snapshot_restore() {
+        let mut network = new_network(TEST_INPUT_2);
+        network.press().unwrap();
+        let snapshot = network.snapshot();
+        let second = network.press().unwrap();
+        network.press().unwrap();
+
+        network.restore(&snapshot).unwrap();
+
+        assert_eq!(network.presses(), 1);
+        assert_eq!(network.snapshot(), snapshot);
+        assert_eq!(network.press().unwrap(), second);
+    }
+
+    #[test]
+    fn test_restore_other_network() {
+        let mut network = new_network(TEST_INPUT);
+        let before = network.snapshot();
+
+        let other = new_network(TEST_INPUT_2).snapshot();
+        assert!(network.restore(&other).is_err());
+
+        // Same names, but a flip-flop where there was a conjunction
+        let other = new_network("broadcaster -> a, b, c\n%a -> b\n%b -> c\n%c -> inv\n%inv -> a")
+            .snapshot();
+        assert!(network
+            .restore(&other)
+            .unwrap_err()
+            .to_string()
+            .starts_with("Error: Unable to restore the conjunction inv to FlipFlop(false)"));
+        assert_eq!(network.snapshot(), before);
+    }
+
+    #[test]
+    fn test_parse_network_errors() {
+        let err = Day::parse("broadcaster -> a\n%a -> b\n$b -> a").unwrap_err();
+        assert_eq!(err.span(), Some(&Span::new(3, 0..2, "$b -> a")));
+        assert!(err.to_string().contains("Unknown module: $b"));
+
+        let err = Day::parse("broadcaster -> a\n%a -> b\n&a -> b").unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Module a is defined more than once"));
+
+        assert!(Day::parse("broadcaster -> a\n\n%a -> b").is_err());
+        assert!(Day::parse("&broadcaster -> a").is_err());
+        assert!(Day::parse("broadcast -> a").is_err());
+        assert!(Day::parse("broadcaster -> a\n% -> b").is_err());
+        assert!(Day::parse("broadcaster -> a, \n%a -> b").is_err());
+
+        let err = Day::parse("%a -> b").unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("There is no broadcaster for the button to send pulses to"));
+
+        let err = Day::parse("broadcaster -> a\n%a -> b\n&c -> a").unwrap_err();
+        assert_eq!(err.span(), Some(&Span::new(3, 0..2, "&c -> a")));
+        assert!(err.to_string().contains("Conjunction c has no inputs"));
+    }
+
+    #[test]
+    fn test_spec_sinks_and_unreachable() {
+        let spec: NetworkSpec = "broadcaster -> a\n%a -> output, rx\n%b -> a, c\n%c -> b"
+            .parse()
+            .unwrap();
+
+        assert_eq!(spec.sinks, vec!["output", "rx"]);
+        assert_eq!(spec.unreachable, vec!["b", "c"]);
+        assert_eq!(spec.inputs("a"), vec!["broadcaster", "b"]);
+    }
+
+    #[test]
+    fn test_spec_to_dot() {
+        let spec: NetworkSpec = TEST_INPUT_2.parse().unwrap();
+
+        assert_eq!(
+            spec.to_dot(),
+            [
+                "digraph network {",
+                "    // Sinks, which only receive pulses: output",
+                "    \"button\" [shape=point];",
+                "    \"broadcaster\" [shape=diamond];",
+                "    \"a\" [shape=box, label=\"%a\"];",
+                "    \"inv\" [shape=invtrapezium, label=\"&inv\\n1 input\"];",
+                "    \"b\" [shape=box, label=\"%b\"];",
+                "    \"con\" [shape=invtrapezium, label=\"&con\\n2 inputs\"];",
+                "    \"output\" [shape=doublecircle];",
+                "    \"button\" -> \"broadcaster\";",
+                "    \"broadcaster\" -> \"a\";",
+                "    \"a\" -> \"inv\";",
+                "    \"a\" -> \"con\";",
+                "    \"inv\" -> \"b\";",
+                "    \"b\" -> \"con\";",
+                "    \"con\" -> \"output\";",
+                "}",
+                "",
+            ]
+            .join("\n")
+        );
+
+        let spec: NetworkSpec = "broadcaster -> a\n%a -> a\n&b -> b".parse().unwrap();
+        let dot = spec.to_dot();
+        assert!(dot.contains("    // Unreachable from the broadcaster: b\n"));
+        assert!(
+            dot.contains("    \"b\" [shape=invtrapezium, label=\"&b\\n1 input\", style=dashed];\n")
+        );
+    }
+
+    #[test]
+    fn test_part1_stuck_network() {
+        // The conjunction keeps sending itself high pulses, since it never sees only high ones
+        let spec = Day::parse("broadcaster -> a\n&a -> a").unwrap();
+
+        assert!(Day::part1(&spec).is_err());
+    }
+
+    #[test]
+    fn test_part2_network_shape() {
+        let err = |input: &str| part2(&Day::parse(input).unwrap()).unwrap_err().to_string();
+
+        assert_eq!(
+            err("broadcaster -> a\n%a -> b"),
+            "Error: No module sends pulses to rx"
+        );
+        assert_eq!(
+            err("broadcaster -> a, b\n%a -> rx\n%b -> rx"),
+            "Error: Expected a single module to send pulses to rx, but 2 do: a, b"
+        );
+        assert_eq!(
+            err("broadcaster -> a\n%a -> rx"),
+            "Error: Expected rx to be fed by a conjunction, but a is a flip-flop"
+        );
+    }
+
+    #[test]
+    fn test_part2_without_cycles() {
+        // The only input of b can't be reached, so b never sends a high pulse to the feeder, which
+        // shows once the network is back to how it started
+        let spec =
+            Day::parse("broadcaster -> a\n%a -> out\n%c -> b\n&b -> out\n&out -> rx").unwrap();
+
+        assert_eq!(
+            part2(&spec).unwrap_err().to_string(),
+            "Error: The network repeats itself every 2 presses without a cycle for the inputs of \
+             out: b"
+        );
+    }
+
+    #[test]
+    fn test_combine_cycles() {
+        let watch = |module, presses| Watch {
+            presses,
+            ..Watch::high(module)
+        };
+
+        let watches = [watch("a", vec![4, 8]), watch("b", vec![6, 12])];
+        assert_eq!(combine_cycles("out", &watches).unwrap(), 12);
+
+        let watches = [watch("a", vec![4, 8]), watch("b", vec![1, 3])];
+        assert_eq!(
+            combine_cycles("out", &watches).unwrap_err().to_string(),
+            "Error: b sent out a high pulse on presses 1 and 3, which isn't a cycle from the start"
+        );
+    }
+
+    #[test]
+    fn test_part2_simulated() {
+        // The inverter sends rx a low pulse on the very first press
+        let spec = Day::parse("broadcaster -> a\n%a -> inv\n&inv -> rx").unwrap();
+
+        assert_eq!(part2(&spec).unwrap(), 1);
+    }
+
+    #[test]
+    fn test_modules() {
+        let mut modules: Modules = HashMap::new();
+
+        let mut broadcaster = Broadcaster::new();
+
+        let mut flipflop_a = FlipFlop::new("a");
+        let mut flipflop_b = FlipFlop::new("b");
+        let mut flipflop_c = FlipFlop::new("c");
+
+        broadcaster.add_connection(&flipflop_a.name);
+        broadcaster.add_connection(&flipflop_b.name);
+        broadcaster.add_connection(&flipflop_c.name);
+
+        flipflop_a.add_connection(&flipflop_b.name);
+        flipflop_b.add_connection(&flipflop_c.name);
+
+        let mut inv = Conjunction::new("inv");
+
+        flipflop_c.add_connection(&inv.name);
+
+        inv.add_input(&flipflop_c.name);
+        inv.add_connection(&flipflop_a.name);
+
+        modules.insert(broadcaster.name.clone(), Box::new(broadcaster));
+        modules.insert(flipflop_a.name.clone(), Box::new(flipflop_a));
+        modules.insert(flipflop_b.name.clone(), Box::new(flipflop_b));
+        modules.insert(flipflop_c.name.clone(), Box::new(flipflop_c));
+        modules.insert(inv.name.clone(), Box::new(inv));
+
+        let mut signals: VecDeque<Signal> =
+            vec![("button".to_string(), false, "broadcaster".to_string())].into();
+        let mut signal_count = 0;
+        while let Some((source, high_pulse, destination)) = signals.pop_front() {
+            signal_count += 1;
+            if let Some(new_signals) = modules
+                .get_mut(&destination)
+                .unwrap()
+                .input(high_pulse, Some(&source))
+            {
+                for signal in new_signals {
+                    signals.push_back(signal);
+                }
+            }
+        }
+
+        assert_eq!(signal_count, 12);
+    }
+}
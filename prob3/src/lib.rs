@@ -0,0 +1,245 @@
+use advent::prelude::*;
+
+pub struct Schematic {
+    grid: Grid<char>,
+}
+
+type GearMap = HashMap<Point<usize>, Vec<u32>>;
+
+enum SymbolType {
+    Gear,
+    Other, // We don't really care what the other symbols are
+}
+
+trait Symbol {
+    fn get_symbol_type(&self) -> Option<SymbolType>;
+    fn is_symbol(&self) -> bool {
+        self.get_symbol_type().is_some()
+    }
+}
+
+impl Symbol for char {
+    fn get_symbol_type(&self) -> Option<SymbolType> {
+        match self {
+            '*' => Some(SymbolType::Gear),
+            '.' | '0'..='9' => None,
+            _ => Some(SymbolType::Other),
+        }
+    }
+}
+
+impl FromStr for Schematic {
+    type Err = AdventError;
+
+    fn from_str(input: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(Self {
+            grid: Grid::parse_with(input, Ok)?,
+        })
+    }
+}
+
+/// Add a digit to the end of a number
+fn push_digit(number: u32, digit: char) -> Result<u32> {
+    let digit = digit.to_digit(10).ok_or(AdventError::InvalidDigit(digit))?;
+    number
+        .checked_mul(10)
+        .and_then(|number| number.checked_add(digit))
+        .ok_or(error!("Number {}{} overflows", number, digit))
+}
+
+impl Schematic {
+    /// Returns every number in the schematic, along with the points of its digits
+    fn numbers(&self) -> Result<Vec<(u32, Vec<Point<usize>>)>> {
+        let mut numbers = Vec::new();
+
+        for (row_index, row) in self.grid.rows().enumerate() {
+            let mut number = None;
+            let mut digits = Vec::new();
+
+            for (col_index, &col) in row.iter().enumerate() {
+                if col.is_ascii_digit() {
+                    number = Some(push_digit(number.unwrap_or(0), col)?);
+                    digits.push(Point::new(row_index, col_index));
+                } else if let Some(number) = number.take() {
+                    numbers.push((number, std::mem::take(&mut digits)));
+                }
+            }
+            // A number can run up to the end of the row
+            if let Some(number) = number {
+                numbers.push((number, digits));
+            }
+        }
+
+        Ok(numbers)
+    }
+
+    /// Returns a list of all the part numbers in the schematic.
+    ///
+    /// A number in the schematic is considered a part number only if it is adjacent to any symbol
+    fn get_part_numbers(&self) -> Result<Vec<u32>> {
+        Ok(self
+            .numbers()?
+            .into_iter()
+            .filter(|(_, digits)| {
+                digits
+                    .iter()
+                    .any(|&digit| self.is_adjacent_to_symbol(digit))
+            })
+            .map(|(number, _)| number)
+            .collect())
+    }
+
+    /// Build gear map
+    ///
+    /// By going through all the numbers in the schematic, we can associate them to any adjacent
+    /// gear by adding the number to the gear map.
+    fn build_gear_map(&self) -> Result<GearMap> {
+        let mut gear_map = GearMap::new();
+        for (number, digits) in self.numbers()? {
+            let adjacent_gears: HashSet<Point<usize>> = digits
+                .iter()
+                .flat_map(|&digit| self.grid.neighbours8(digit))
+                .filter(|&point| {
+                    matches!(self.grid[point].get_symbol_type(), Some(SymbolType::Gear))
+                })
+                .collect();
+            for gear in adjacent_gears {
+                gear_map.entry(gear).or_default().push(number);
+            }
+        }
+
+        Ok(gear_map)
+    }
+
+    /// Return if there is a symbol at adjacent cells
+    fn is_adjacent_to_symbol(&self, point: Point<usize>) -> bool {
+        self.grid
+            .neighbours8(point)
+            .any(|neighbour| self.grid[neighbour].is_symbol())
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Schematic;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        input.parse()
+    }
+
+    fn part1(schematic: &Self::Parsed) -> Result<Answer> {
+        Ok(part1(schematic)?.into())
+    }
+
+    fn part2(schematic: &Self::Parsed) -> Result<Answer> {
+        Ok(part2(schematic)?.into())
+    }
+}
+
+fn part1(schematic: &Schematic) -> Result<u32> {
+    schematic
+        .get_part_numbers()?
+        .iter()
+        .try_fold(0u32, |sum, &number| {
+            sum.checked_add(number)
+                .ok_or(error!("Sum of part numbers overflows"))
+        })
+}
+
+fn part2(schematic: &Schematic) -> Result<u32> {
+    let gear_map = schematic.build_gear_map()?;
+
+    // Find all gears that have two numbers adjacent
+    let mut ratios_sum: u32 = 0;
+
+    for (_, numbers) in gear_map.iter() {
+        if numbers.len() == 2 {
+            ratios_sum = numbers[0]
+                .checked_mul(numbers[1])
+                .and_then(|ratio| ratios_sum.checked_add(ratio))
+                .ok_or(error!("Sum of gear ratios overflows"))?;
+        }
+    }
+
+    Ok(ratios_sum)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_schematic_from_str() {
+        let schematic: Schematic = ".#.\n123\n$*#".parse().unwrap();
+
+        assert_eq!(schematic.grid.height(), 3);
+        assert_eq!(schematic.grid.row(1), Some(&['1', '2', '3'][..]));
+        assert_eq!(schematic.grid.get((2, 1)), Some(&'*'));
+
+        let err = ".#.\n12\n$*#".parse::<Schematic>().err().unwrap();
+        assert_eq!(err.span().map(|span| span.line), Some(2));
+    }
+
+    #[test]
+    fn test_numbers() {
+        let schematic: Schematic = "12.\n..3\n45.".parse().unwrap();
+
+        assert_eq!(
+            schematic.numbers().unwrap(),
+            vec![
+                (12, vec![Point::new(0, 0), Point::new(0, 1)]),
+                (3, vec![Point::new(1, 2)]),
+                (45, vec![Point::new(2, 0), Point::new(2, 1)]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_is_symbol() {
+        assert!('#'.is_symbol());
+        assert!('*'.is_symbol());
+        assert!('$'.is_symbol());
+        assert!(!'.'.is_symbol());
+        assert!(!'7'.is_symbol());
+    }
+
+    #[test]
+    fn test_is_adjacent_to_symbol() {
+        let schematic: Schematic = "...\n123\n..#".parse().unwrap();
+
+        assert!(!schematic.is_adjacent_to_symbol(Point::new(1, 0)));
+        assert!(schematic.is_adjacent_to_symbol(Point::new(1, 1)));
+        assert!(schematic.is_adjacent_to_symbol(Point::new(1, 2)));
+    }
+
+    #[test]
+    fn test_get_part_numbers() {
+        let schematic: Schematic = ".#.\n123\n..#\n...\n456".parse().unwrap();
+        assert_eq!(schematic.get_part_numbers().unwrap(), vec![123]);
+    }
+
+    #[test]
+    fn test_build_gear_map() {
+        let schematic: Schematic = ".*.\n123\n..#\n...\n456".parse().unwrap();
+
+        let gear_map = schematic.build_gear_map().unwrap();
+
+        assert_eq!(gear_map.get(&Point::new(0, 1)).unwrap(), &vec![123]);
+    }
+
+    #[test]
+    fn test_overflow_is_an_error() {
+        let schematic: Schematic = "4294967296#".parse().unwrap();
+        assert_eq!(
+            part1(&schematic).unwrap_err().to_string(),
+            "Error: Number 4294967296 overflows"
+        );
+
+        let schematic: Schematic = "100000*100000".parse().unwrap();
+        assert_eq!(
+            part2(&schematic).unwrap_err().to_string(),
+            "Error: Sum of gear ratios overflows"
+        );
+    }
+}
@@ -1,8 +1,6 @@
 use advent::prelude::*;
 
-const INPUT: &str = include_str!("../input.txt");
-
-struct Card {
+pub struct Card {
     winning_numbers: HashSet<u32>,
     playing_numbers: HashSet<u32>,
 }
@@ -18,26 +16,28 @@ impl FromStr for Card {
     /// where the first 5 numbers left of the | are the winning numbers and on the right are the
     /// playing numbers
     fn from_str(input: &str) -> std::result::Result<Self, Self::Err> {
-        let input = input.split_whitespace().collect::<Vec<_>>().join(" ");
         // Get everything after the first :
-        let input = input.split(": ").collect::<Vec<_>>()[1];
-        let mut numbers = input.split(" | ");
-        let winning_numbers = numbers
-            .next()
-            .ok_or(AdventError::InvalidInput)?
-            .split_whitespace()
-            .map(|n| n.parse().unwrap())
-            .collect();
-        let playing_numbers = numbers
-            .next()
-            .ok_or(AdventError::InvalidInput)?
-            .split_whitespace()
-            .map(|n| n.parse().unwrap())
-            .collect();
+        let (_, numbers) = input.split_once(':').ok_or(parse_error!(
+            "Unable to find card numbers",
+            input,
+            input
+        ))?;
+        let (winning, playing) = numbers.split_once('|').ok_or(parse_error!(
+            "Unable to find the | separator",
+            input,
+            numbers
+        ))?;
+
+        let parse_numbers = |numbers: &str| {
+            numbers
+                .split_whitespace()
+                .map(|n| parse_at(input, n))
+                .collect::<Result<HashSet<u32>>>()
+        };
 
         Ok(Self {
-            winning_numbers,
-            playing_numbers,
+            winning_numbers: parse_numbers(winning)?,
+            playing_numbers: parse_numbers(playing)?,
         })
     }
 }
@@ -65,28 +65,32 @@ impl Card {
     }
 }
 
-fn main() -> Result<()> {
-    println!("## Part 1");
-    println!(" > {}", part1(INPUT)?);
+pub struct Day;
 
-    println!("## Part 2");
-    println!(" > {}", part2(INPUT)?);
+impl Solution for Day {
+    type Parsed = Vec<Card>;
 
-    Ok(())
-}
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        parse_lines(input, str::parse).context("parsing cards")
+    }
 
-fn part1(input: &str) -> Result<u32> {
-    let cards: Vec<Card> = input.lines().map(str::parse).collect::<Result<Vec<_>>>()?;
-    let score = cards.iter().map(Card::get_score).sum::<u32>();
-    Ok(score)
+    fn part1(cards: &Self::Parsed) -> Result<Answer> {
+        Ok(part1(cards).into())
+    }
+
+    fn part2(cards: &Self::Parsed) -> Result<Answer> {
+        Ok(part2(cards).into())
+    }
 }
 
-fn part2(input: &str) -> Result<u32> {
-    let mut cards: Vec<Card> = input.lines().map(str::parse).collect::<Result<Vec<_>>>()?;
+fn part1(cards: &[Card]) -> u32 {
+    cards.iter().map(Card::get_score).sum::<u32>()
+}
 
+fn part2(cards: &[Card]) -> u32 {
     let mut counts = vec![1; cards.len()];
 
-    for (idx, card) in cards.iter_mut().enumerate() {
+    for (idx, card) in cards.iter().enumerate() {
         let count = counts[idx];
         let matches = card.get_match_count();
         if matches == 0 {
@@ -98,26 +102,13 @@ fn part2(input: &str) -> Result<u32> {
         }
     }
 
-    Ok(counts.iter().sum())
+    counts.iter().sum()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const PART_1_TEST_INPUT: &str = include_str!("../part_1_test.txt");
-    const PART_2_TEST_INPUT: &str = include_str!("../part_2_test.txt");
-
-    #[test]
-    fn test_part1() {
-        assert_eq!(part1(PART_1_TEST_INPUT).unwrap(), 13);
-    }
-
-    #[test]
-    fn test_part2() {
-        assert_eq!(part2(PART_2_TEST_INPUT).unwrap(), 30);
-    }
-
     #[test]
     fn test_card_from_str() {
         let input = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53";
@@ -134,4 +125,16 @@ mod tests {
                 .collect::<HashSet<_>>()
         );
     }
+
+    #[test]
+    fn test_card_parse_error_span() {
+        let input = "Card 1: 41 48 | 83 86\nCard 2: 13 x2 | 61 30";
+
+        let err = Day::parse(input).err().unwrap();
+
+        assert_eq!(
+            err.span(),
+            Some(&Span::new(2, 11..13, "Card 2: 13 x2 | 61 30"))
+        );
+    }
 }
@@ -0,0 +1,345 @@
+use advent::interval::{Interval, IntervalSet, RangeMap};
+use advent::prelude::*;
+
+#[derive(Debug, PartialEq, Eq)]
+struct Mapping {
+    source_start: u64,
+    destination_start: u64,
+    range_length: u64,
+}
+
+impl Mapping {
+    /// Where the source and destination ranges end, which has to fit in a u64
+    fn ends(&self) -> Result<(u64, u64)> {
+        let end = |start: u64| {
+            start.checked_add(self.range_length).ok_or(error!(
+                "Mapping of {} numbers from {} overflows",
+                self.range_length, start
+            ))
+        };
+        Ok((end(self.source_start)?, end(self.destination_start)?))
+    }
+
+    /// Convert a number, or None if the number isn't in the source range
+    fn convert_number(&self, number: u64) -> Result<Option<u64>> {
+        let (source_end, _) = self.ends()?;
+        Ok((self.source_start..source_end)
+            .contains(&number)
+            .then(|| self.destination_start + (number - self.source_start)))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Map {
+    from: String,
+    to: String,
+    mappings: Vec<Mapping>,
+}
+
+impl Map {
+    fn convert_number(&self, number: u64) -> Result<u64> {
+        for mapping in &self.mappings {
+            if let Some(mapped_number) = mapping.convert_number(number)? {
+                return Ok(mapped_number);
+            }
+        }
+        Ok(number)
+    }
+
+    /// Build a range map covering all the mappings of the map
+    fn range_map(&self) -> Result<RangeMap<u64>> {
+        let mut range_map = RangeMap::new();
+        for mapping in &self.mappings {
+            mapping.ends()?;
+            range_map.insert(
+                Interval::from_length(mapping.source_start, mapping.range_length),
+                mapping.destination_start,
+            );
+        }
+        Ok(range_map)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Almanac {
+    seeds: Vec<u64>,
+    seed_ranges: Vec<(u64, u64)>,
+    maps: Vec<Map>,
+}
+
+impl FromStr for Almanac {
+    type Err = AdventError;
+    /// Parse input into an Almanac
+    ///
+    /// The input will start with a line listing the seeds, as such:
+    ///
+    /// ```text
+    /// seeds: 1 12 41 678
+    /// ```
+    ///
+    /// followed by a list of maps, each separated by an empty new line:
+    ///
+    /// ```text
+    /// x-to-y map:
+    /// 10 20 4
+    /// 31 6 21
+    /// ```
+    ///
+    /// each map can have any number of mappings listed under the title
+    fn from_str(input: &str) -> std::result::Result<Self, Self::Err> {
+        let mut lines = input.lines();
+
+        // Get the seeds first
+        let seeds_line = lines.next().ok_or(parse_error!("No seeds found"))?;
+        let seeds = seeds_line
+            .strip_prefix("seeds: ")
+            .ok_or(parse_error!("Expected a list of seeds", input, seeds_line))?
+            .split_whitespace()
+            .map(|s| parse_at(input, s))
+            .collect::<Result<Vec<u64>>>()
+            .context("parsing seeds")?;
+        lines.next(); // Skip the empty line
+
+        let mut maps = vec![];
+
+        while let Some(line) = lines.next() {
+            // Get the map name
+            let (map_from, map_to) = line
+                .strip_suffix(" map:")
+                .and_then(|name| name.split_once("-to-"))
+                .ok_or(parse_error!("Expected a map title", input, line))?;
+
+            // Get the mappings
+            let mut mappings = vec![];
+            for line in lines.by_ref() {
+                if line.is_empty() {
+                    // We've reached the end of the map
+                    break;
+                }
+                let numbers = line
+                    .split_whitespace()
+                    .map(|n| parse_at(input, n))
+                    .collect::<Result<Vec<u64>>>()?;
+                let &[destination_start, source_start, range_length] = numbers.as_slice() else {
+                    return Err(parse_error!("Expected three numbers", input, line));
+                };
+                mappings.push(Mapping {
+                    source_start,
+                    destination_start,
+                    range_length,
+                });
+            }
+
+            maps.push(Map {
+                from: map_from.to_string(),
+                to: map_to.to_string(),
+                mappings,
+            });
+        }
+
+        // Calculate the seed ranges
+        let mut seed_ranges = vec![];
+
+        let seed_iter = seeds.iter();
+        for (range_start, range_len) in seed_iter.clone().zip(seed_iter.skip(1)).step_by(2) {
+            seed_ranges.push((*range_start, *range_len));
+        }
+
+        Ok(Self {
+            seeds,
+            seed_ranges,
+            maps,
+        })
+    }
+}
+impl Almanac {
+    /// Convert a number through all the mappings of the almanac
+    fn convert_number(&self, number: u64) -> Result<u64> {
+        self.maps
+            .iter()
+            .try_fold(number, |number, map| map.convert_number(number))
+    }
+
+    /// Convert a whole set of numbers through all the mappings of the almanac
+    ///
+    /// Each map only has to split the intervals of the set where its mappings start and end, so
+    /// this is proportional to the number of ranges rather than the number of values
+    fn convert_set(&self, set: IntervalSet<u64>) -> Result<IntervalSet<u64>> {
+        self.maps
+            .iter()
+            .try_fold(set, |set, map| Ok(map.range_map()?.map_set(&set)))
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Almanac;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        input.parse()
+    }
+
+    fn part1(almanac: &Self::Parsed) -> Result<Answer> {
+        Ok(part1(almanac)?.into())
+    }
+
+    fn part2(almanac: &Self::Parsed) -> Result<Answer> {
+        Ok(part2(almanac)?.into())
+    }
+}
+
+fn part1(almanac: &Almanac) -> Result<u64> {
+    let mut lowest_number = u64::MAX;
+
+    for seed in &almanac.seeds {
+        let converted_seed = almanac.convert_number(*seed)?;
+        if converted_seed < lowest_number {
+            lowest_number = converted_seed;
+        }
+    }
+
+    Ok(lowest_number)
+}
+
+fn part2(almanac: &Almanac) -> Result<u64> {
+    let seeds = almanac
+        .seed_ranges
+        .iter()
+        .map(|&(seed_start, range_length)| {
+            let seed_end = seed_start.checked_add(range_length).ok_or(error!(
+                "Range of {} seeds from {} overflows",
+                range_length, seed_start
+            ))?;
+            Ok(Interval::new(seed_start, seed_end))
+        })
+        .collect::<Result<_>>()?;
+
+    almanac
+        .convert_set(seeds)?
+        .min()
+        .ok_or(error!("No seeds to convert"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = include_str!("../test.txt");
+
+    #[test]
+    fn test_alamanac_from_str() {
+        let input = "seeds: 1 12 41 678\n\nfoo-to-bar map:\n10 20 4\n31 6 21\n\nbar-to-baz map:\n1 2 3\n4 5 6";
+
+        let almanac: Almanac = input.parse().unwrap();
+
+        assert_eq!(almanac.seeds, vec![1, 12, 41, 678]);
+        assert_eq!(almanac.maps.len(), 2);
+
+        let map1 = &almanac.maps[0];
+        assert_eq!(map1.from, "foo");
+        assert_eq!(map1.to, "bar");
+
+        assert_eq!(map1.mappings.len(), 2);
+        assert_eq!(
+            map1.mappings[0],
+            Mapping {
+                source_start: 20,
+                destination_start: 10,
+                range_length: 4
+            }
+        );
+        assert_eq!(
+            map1.mappings[1],
+            Mapping {
+                source_start: 6,
+                destination_start: 31,
+                range_length: 21
+            }
+        );
+
+        let map2 = &almanac.maps[1];
+        assert_eq!(map2.from, "bar");
+        assert_eq!(map2.to, "baz");
+        assert_eq!(map2.mappings.len(), 2);
+        assert_eq!(
+            map2.mappings[0],
+            Mapping {
+                source_start: 2,
+                destination_start: 1,
+                range_length: 3
+            }
+        );
+        assert_eq!(
+            map2.mappings[1],
+            Mapping {
+                source_start: 5,
+                destination_start: 4,
+                range_length: 6
+            }
+        );
+    }
+
+    #[test]
+    fn test_mapping_convert_number() {
+        let mapping = Mapping {
+            source_start: 10,
+            destination_start: 20,
+            range_length: 4,
+        };
+
+        assert_eq!(mapping.convert_number(9).unwrap(), None);
+        assert_eq!(mapping.convert_number(10).unwrap(), Some(20));
+        assert_eq!(mapping.convert_number(11).unwrap(), Some(21));
+        assert_eq!(mapping.convert_number(12).unwrap(), Some(22));
+        assert_eq!(mapping.convert_number(13).unwrap(), Some(23));
+        assert_eq!(mapping.convert_number(14).unwrap(), None);
+    }
+
+    #[test]
+    fn test_convert_number() {
+        let almanac: Almanac = TEST_INPUT.parse().unwrap();
+
+        assert_eq!(almanac.convert_number(79).unwrap(), 82);
+    }
+
+    #[test]
+    fn test_convert_set() {
+        let almanac: Almanac = TEST_INPUT.parse().unwrap();
+
+        // Converting a set should give the same result as converting every number in it
+        let converted = almanac
+            .convert_set(Interval::from_length(79, 14).into())
+            .unwrap();
+        for seed in 79..93 {
+            assert!(converted.contains(almanac.convert_number(seed).unwrap()));
+        }
+        assert_eq!(converted.len(), 14);
+    }
+
+    #[test]
+    fn test_almanac_parse_error_span() {
+        let input = "seeds: 1 2\n\nfoo-to-bar map:\n10 20 4\n31 6\n";
+
+        let err = input.parse::<Almanac>().unwrap_err();
+
+        assert_eq!(err.span(), Some(&Span::new(5, 0..4, "31 6")));
+    }
+
+    #[test]
+    fn test_overflow_is_an_error() {
+        let almanac: Almanac =
+            "seeds: 5 18446744073709551615\n\na-to-b map:\n1 18446744073709551610 10"
+                .parse()
+                .unwrap();
+
+        assert_eq!(
+            part1(&almanac).unwrap_err().to_string(),
+            "Error: Mapping of 10 numbers from 18446744073709551610 overflows"
+        );
+        assert_eq!(
+            part2(&almanac).unwrap_err().to_string(),
+            "Error: Range of 18446744073709551615 seeds from 5 overflows"
+        );
+    }
+}
@@ -0,0 +1,107 @@
+use advent::prelude::*;
+
+fn parse_input(input: &str) -> Result<Vec<(i64, i64)>> {
+    let mut lines = input.lines();
+
+    let mut parse_line = |prefix: &str| {
+        let line = lines.next().ok_or(parse_error!(format!(
+            "Expected a line starting with {}",
+            prefix
+        )))?;
+        line.strip_prefix(prefix)
+            .ok_or(parse_error!(
+                format!("Expected the line to start with {}", prefix),
+                input,
+                line
+            ))?
+            .split_whitespace()
+            .map(|t| parse_at(input, t))
+            .collect::<Result<Vec<i64>>>()
+    };
+
+    let times = parse_line("Time:").context("parsing times")?;
+    let distances = parse_line("Distance:").context("parsing distances")?;
+
+    // Return the zipped pairs
+    Ok(times.into_iter().zip(distances).collect())
+}
+
+/// Solve a hyperbola of the form:
+/// x * (y - x) = d
+///
+/// Returns the two solutions for x, or an error if y is too big to square
+fn solve(y: i64, d: i64) -> Result<(f64, f64)> {
+    let square = y
+        .checked_mul(y)
+        .ok_or(error!("Race time {} is too long to square", y))?;
+    let discriminant = square as f64 - 4.0 * (d as f64 + 0.01);
+    let x1 = ((y as f64) + discriminant.sqrt()) / 2.0;
+    let x2 = ((y as f64) - discriminant.sqrt()) / 2.0;
+
+    Ok((x1, x2))
+}
+
+/// The number of ways to beat the distance in a race of the given time
+fn ways_to_win(time: i64, distance: i64) -> Result<i64> {
+    let (x1, x2) = match solve(time, distance)? {
+        (x1, x2) if x1 > x2 => (x1, x2),
+        (x1, x2) => (x2, x1),
+    };
+
+    (x1.ceil() as i64)
+        .checked_sub(x2.ceil() as i64)
+        .ok_or(error!("Ways to win the race of {} ms overflows", time))
+}
+
+/// Join the digits of numbers together, since the spaces between them shouldn't have been there
+fn join_digits(numbers: impl Iterator<Item = i64>) -> Result<i64> {
+    Ok(numbers.map(|n| n.to_string()).collect::<String>().parse()?)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Vec<(i64, i64)>;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        parse_input(input)
+    }
+
+    fn part1(races: &Self::Parsed) -> Result<Answer> {
+        let product = races.iter().try_fold(1i64, |product, &(time, distance)| {
+            product
+                .checked_mul(ways_to_win(time, distance)?)
+                .ok_or(error!("Product of ways to win overflows"))
+        })?;
+        Ok(product.into())
+    }
+
+    fn part2(races: &Self::Parsed) -> Result<Answer> {
+        let time = join_digits(races.iter().map(|&(time, _)| time))?;
+        let distance = join_digits(races.iter().map(|&(_, distance)| distance))?;
+
+        Ok(ways_to_win(time, distance)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = include_str!("../test.txt");
+
+    #[test]
+    fn test_parse_input() {
+        let input = parse_input(TEST_INPUT).unwrap();
+        assert_eq!(input, vec![(7, 9), (15, 40), (30, 200)]);
+    }
+
+    #[test]
+    fn test_overflow_is_an_error() {
+        assert_eq!(ways_to_win(30, 200).unwrap(), 9);
+        assert_eq!(
+            ways_to_win(i64::MAX, 9).unwrap_err().to_string(),
+            "Error: Race time 9223372036854775807 is too long to square"
+        );
+    }
+}
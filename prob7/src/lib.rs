@@ -0,0 +1,326 @@
+use advent::prelude::*;
+use std::fmt;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum HandType {
+    FiveOfAKind,
+    FourOfAKind,
+    FullHouse,
+    ThreeOfAKind,
+    TwoPair,
+    OnePair,
+    HighCard,
+}
+
+impl PartialOrd for HandType {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for HandType {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        let self_value = match self {
+            HandType::FiveOfAKind => 8,
+            HandType::FourOfAKind => 7,
+            HandType::FullHouse => 6,
+            HandType::ThreeOfAKind => 5,
+            HandType::TwoPair => 4,
+            HandType::OnePair => 3,
+            HandType::HighCard => 2,
+        };
+        let other_value = match other {
+            HandType::FiveOfAKind => 8,
+            HandType::FourOfAKind => 7,
+            HandType::FullHouse => 6,
+            HandType::ThreeOfAKind => 5,
+            HandType::TwoPair => 4,
+            HandType::OnePair => 3,
+            HandType::HighCard => 2,
+        };
+        self_value.cmp(&other_value)
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct Card(u8);
+
+impl Card {
+    fn new(card: char) -> Result<Self> {
+        match card {
+            '*' => Ok(Self(1)), // A part 2 joker
+            'T' => Ok(Self(10)),
+            'J' => Ok(Self(11)),
+            'Q' => Ok(Self(12)),
+            'K' => Ok(Self(13)),
+            'A' => Ok(Self(14)),
+            '2'..='9' => card
+                .to_digit(10)
+                .map(|value| Self(value as u8))
+                .ok_or(AdventError::InvalidDigit(card)),
+            _ => Err(parse_error!(format!("Invalid card: {}", card))),
+        }
+    }
+}
+
+impl fmt::Display for Card {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            14 => write!(f, "A"),
+            10 => write!(f, "T"),
+            11 => write!(f, "J"),
+            12 => write!(f, "Q"),
+            13 => write!(f, "K"),
+            _ => write!(f, "{}", self.0),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hand([Card; 5]);
+
+impl FromStr for Hand {
+    type Err = AdventError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let cards = s.chars().map(Card::new).collect::<Result<Vec<_>>>()?;
+        let cards = cards
+            .try_into()
+            .map_err(|_| parse_error!(format!("Expected a hand of five cards, got {}", s)))?;
+        Ok(Self(cards))
+    }
+}
+
+impl Hand {
+    /// The same hand with the jacks played as jokers, like in part 2
+    fn with_jokers(&self) -> Self {
+        Self(
+            self.0
+                .map(|card| if card == Card(11) { Card(1) } else { card }),
+        )
+    }
+
+    fn r#type(&self) -> HandType {
+        let mut counts: HashMap<u8, u8> = self.0.iter().fold(HashMap::new(), |mut map, card| {
+            *map.entry(card.0).or_insert(0) += 1;
+            map
+        });
+
+        let jokers = counts.remove(&1).unwrap_or(0);
+        let sorts = counts.values().len();
+
+        if jokers == 5 || sorts == 1 {
+            return HandType::FiveOfAKind;
+        }
+        if sorts == 2 {
+            if counts.values().any(|&v| v == (4 - jokers)) {
+                return HandType::FourOfAKind;
+            }
+            if counts.values().any(|&v| v == (3 - jokers)) {
+                // If we have 3 of a sort, then it doesn't matter if we have a joker or not, it's a
+                // full house
+                return HandType::FullHouse;
+            }
+        }
+        if sorts == 3 {
+            if counts.values().any(|&v| v == (3 - jokers)) {
+                return HandType::ThreeOfAKind;
+            }
+            if counts.values().any(|&v| v == (2 - jokers)) {
+                return HandType::TwoPair;
+            }
+        }
+        if sorts == 4 {
+            return HandType::OnePair;
+        }
+
+        HandType::HighCard
+    }
+}
+
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Hand {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        if self.r#type() != other.r#type() {
+            self.r#type().cmp(&other.r#type())
+        } else {
+            self.0.cmp(&other.0)
+        }
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Vec<(Hand, u32)>;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        // Each line is of the form: <cards> <score>, such as:
+        //
+        // 32T3K 123
+        //
+        // meaning the hand 32T3K with the score 123
+        //
+        // We'll parse the hands into a vector of (Hand, u32) tuples
+        parse_lines(input, |line| {
+            let (hand, score) = line.split_once(' ').ok_or(parse_error!(
+                "Expected a hand and a score",
+                line,
+                line
+            ))?;
+            Ok::<_, AdventError>((parse_at(line, hand)?, parse_at(line, score)?))
+        })
+        .context("parsing hands")
+    }
+
+    fn part1(hands: &Self::Parsed) -> Result<Answer> {
+        Ok(total_winnings(hands.to_vec())?.into())
+    }
+
+    fn part2(hands: &Self::Parsed) -> Result<Answer> {
+        let hands = hands
+            .iter()
+            .map(|(hand, score)| (hand.with_jokers(), *score))
+            .collect();
+
+        Ok(total_winnings(hands)?.into())
+    }
+}
+
+fn total_winnings(mut hands: Vec<(Hand, u32)>) -> Result<u32> {
+    // First we sort it by the hand
+    hands.sort();
+
+    // And finally we can sum up the scores multiplied by the rank (idx 0 is rank 1)
+    hands
+        .iter()
+        .enumerate()
+        .try_fold(0u32, |total, (idx, (_, score))| {
+            u32::try_from(idx + 1)
+                .ok()
+                .and_then(|rank| rank.checked_mul(*score))
+                .and_then(|winnings| total.checked_add(winnings))
+                .ok_or(error!("Total winnings overflow"))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hand(cards: &str) -> Hand {
+        cards.parse().unwrap()
+    }
+
+    #[test]
+    fn test_card_new() {
+        assert_eq!(Card::new('A').unwrap(), Card(14));
+        assert_eq!(Card::new('T').unwrap(), Card(10));
+        assert_eq!(Card::new('3').unwrap(), Card(3));
+        assert!(Card::new('1').is_err());
+    }
+
+    #[test]
+    fn test_hand_from_str() {
+        let hand = hand("27TKQ");
+        assert_eq!(hand.0[0], Card(2));
+        assert_eq!(hand.0[1], Card(7));
+        assert_eq!(hand.0[2], Card(10));
+        assert_eq!(hand.0[3], Card(13));
+        assert_eq!(hand.0[4], Card(12));
+    }
+
+    #[test]
+    fn test_hands_parse_error() {
+        assert!("27TK".parse::<Hand>().is_err());
+        assert!("27TKQQ".parse::<Hand>().is_err());
+
+        let err = Day::parse("32T3K 765\nT5XJ5 684").unwrap_err();
+        assert_eq!(err.span(), Some(&Span::new(2, 0..5, "T5XJ5 684")));
+        assert!(err.to_string().contains("Invalid card: X"));
+        assert!(Day::parse("32T3K").is_err());
+        assert!(Day::parse("32T3K -1").is_err());
+    }
+
+    #[test]
+    fn test_hand_type() {
+        assert_eq!(hand("27TKQ").r#type(), HandType::HighCard);
+        assert_eq!(hand("27T2Q").r#type(), HandType::OnePair);
+        assert_eq!(hand("27T22").r#type(), HandType::ThreeOfAKind);
+        assert_eq!(hand("77T22").r#type(), HandType::TwoPair);
+        assert_eq!(hand("77722").r#type(), HandType::FullHouse);
+        assert_eq!(hand("77772").r#type(), HandType::FourOfAKind);
+        assert_eq!(hand("77777").r#type(), HandType::FiveOfAKind);
+    }
+
+    #[test]
+    fn test_hand_ord() {
+        assert!(hand("45678") > hand("23456"));
+        assert!(hand("45678") < hand("22456"));
+        assert!(hand("32T3K") < hand("T55J5"));
+        assert!(hand("33332") > hand("2AAAA"));
+
+        let mut hands = vec![
+            hand("32T3K"),
+            hand("T55J5"),
+            hand("KK677"),
+            hand("KTJJT"),
+            hand("QQQJA"),
+        ];
+        hands.sort();
+        assert_eq!(
+            hands,
+            vec![
+                hand("32T3K"),
+                hand("KTJJT"),
+                hand("KK677"),
+                hand("T55J5"),
+                hand("QQQJA"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_hand_type_with_joker() {
+        // Five of a kind
+        assert_eq!(hand("7777*").r#type(), HandType::FiveOfAKind);
+        assert_eq!(hand("7*77*").r#type(), HandType::FiveOfAKind);
+        assert_eq!(hand("7**7*").r#type(), HandType::FiveOfAKind);
+        assert_eq!(hand("*7***").r#type(), HandType::FiveOfAKind);
+        assert_eq!(hand("*****").r#type(), HandType::FiveOfAKind);
+
+        // Four of a kind
+        assert_eq!(hand("3777*").r#type(), HandType::FourOfAKind);
+        assert_eq!(hand("377**").r#type(), HandType::FourOfAKind);
+        assert_eq!(hand("37***").r#type(), HandType::FourOfAKind);
+        assert_eq!(hand("3****").r#type(), HandType::FiveOfAKind); // Better than 4 of a kind
+
+        // Full house
+        assert_eq!(hand("3377*").r#type(), HandType::FullHouse);
+        assert_eq!(hand("337**").r#type(), HandType::FourOfAKind); // Better than full house
+
+        // Three of a kind
+        assert_eq!(hand("3327*").r#type(), HandType::ThreeOfAKind);
+
+        // Two pair
+        // Is there any way to have a joker that turns into two pairs? With something like
+        // 2234* you would make the joker be a 2 for a three of a kind rather than two pair
+
+        // One pair
+        assert_eq!(hand("2345*").r#type(), HandType::OnePair);
+    }
+
+    #[test]
+    fn test_overflow_is_an_error() {
+        let hands = vec![(hand("23456"), 1), (hand("AAAAA"), 4294967295)];
+        assert_eq!(
+            total_winnings(hands).unwrap_err().to_string(),
+            "Error: Total winnings overflow"
+        );
+    }
+}
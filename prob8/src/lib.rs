@@ -0,0 +1,357 @@
+use advent::cycle::{find_cycle_by, Cycle};
+use advent::math::crt;
+use advent::prelude::*;
+
+#[derive(Debug, PartialEq, Eq, Hash)]
+struct Node(String);
+
+impl Node {
+    fn is_start(&self) -> bool {
+        self.0.ends_with('A')
+    }
+
+    fn is_end(&self) -> bool {
+        self.0.ends_with('Z')
+    }
+}
+
+enum Direction {
+    R,
+    L,
+}
+
+pub struct Map {
+    nodes: HashMap<Node, (Node, Node)>,
+    directions: Vec<Direction>,
+}
+
+impl FromStr for Map {
+    type Err = AdventError;
+
+    fn from_str(input: &str) -> std::result::Result<Self, Self::Err> {
+        let mut lines = input.lines();
+        // First line is the directions, which is a list of R and L (no spacing)
+        let directions_line = lines.next().ok_or(parse_error!("No directions found"))?;
+        let directions = directions_line
+            .char_indices()
+            .map(|(idx, c)| match c {
+                'R' => Ok(Direction::R),
+                'L' => Ok(Direction::L),
+                _ => Err(parse_error!(
+                    format!("Unknown direction: {}", c),
+                    input,
+                    &directions_line[idx..idx + c.len_utf8()]
+                )),
+            })
+            .collect::<Result<Vec<_>>>()?;
+        if directions.is_empty() {
+            return Err(parse_error!("No directions found"));
+        }
+
+        // Followed by an empty line
+        lines.next();
+
+        // And then all following lines show a mapping in the form of
+        //
+        //   AAA = (BBB, CCC)
+        //
+        // which means that AAA goes left to BBB or right to CCC, we'll just represent this as a
+        // hashmap to the tuple
+        let nodes = lines
+            .map(|line| {
+                let (node, mapping) = line.split_once(" = ").ok_or(parse_error!(
+                    "No mapping found for node",
+                    input,
+                    line
+                ))?;
+                let (left, right) = mapping
+                    .strip_prefix('(')
+                    .and_then(|mapping| mapping.strip_suffix(')'))
+                    .and_then(|mapping| mapping.split_once(", "))
+                    .ok_or(parse_error!(
+                        "Mapping should be in the form of (left, right)",
+                        input,
+                        mapping
+                    ))?;
+                Ok((
+                    Node(node.to_string()),
+                    (Node(left.to_string()), Node(right.to_string())),
+                ))
+            })
+            .collect::<Result<HashMap<Node, (Node, Node)>>>()
+            .context("parsing nodes")?;
+
+        Ok(Self { nodes, directions })
+    }
+}
+
+impl Map {
+    /// Follow the directions from a node until the walk starts repeating itself
+    ///
+    /// The walk repeats once we are back on the same node at the same point in the directions.
+    /// For every step we only keep track of whether it landed on an end node.
+    fn find_end_cycle(&self, start: &Node) -> Result<Cycle<bool>> {
+        find_cycle_by(
+            (start, 0),
+            |&(node, steps_idx)| {
+                let (left, right) = self
+                    .nodes
+                    .get(node)
+                    .ok_or(error!("No node found for {}", node.0))?;
+                let next_node = match self.directions[steps_idx] {
+                    Direction::L => left,
+                    Direction::R => right,
+                };
+                Ok((next_node, (steps_idx + 1) % self.directions.len()))
+            },
+            |&state| state,
+            |(node, _)| node.is_end(),
+        )
+    }
+}
+
+/// Find the first step at which every cycle is on an end node, or None if there is no such step
+///
+/// Before a cycle starts repeating, an end node is only hit once, so we check the steps up to the
+/// longest lead-in one by one. After that, each end node in a cycle is hit at a fixed offset
+/// every cycle length, which [`combine_ends`] finds a step for.
+fn first_common_end(cycles: &[Cycle<bool>]) -> Result<Option<u64>> {
+    let Some(lead_in) = cycles.iter().map(|cycle| cycle.start).max() else {
+        return Ok(None);
+    };
+    let lead_in = lead_in.max(1);
+
+    if let Some(step) = (1..lead_in).find(|&step| cycles.iter().all(|cycle| *cycle.at(step))) {
+        return Ok(Some(step as u64));
+    }
+
+    // The (offset, cycle length) congruences of the end nodes in each cycle
+    let ends: Vec<Vec<(i128, i128)>> = cycles
+        .iter()
+        .map(|cycle| {
+            cycle.history()[cycle.start..]
+                .iter()
+                .enumerate()
+                .filter(|(_, is_end)| **is_end)
+                .map(|(offset, _)| ((cycle.start + offset) as i128, cycle.length as i128))
+                .collect()
+        })
+        .collect();
+
+    combine_ends(&ends, lead_in as i128)
+}
+
+/// Find the first step from `lead_in` on that satisfies one of the congruences of every cycle
+///
+/// We combine the cycles one at a time, solving each step that works for the cycles so far
+/// against every end node of the next one, so the steps that work for all of them never grow past
+/// the ones the combined cycle length can hold. A step too large to count is an error, rather than
+/// a step that doesn't exist.
+fn combine_ends(ends: &[Vec<(i128, i128)>], lead_in: i128) -> Result<Option<u64>> {
+    let overflow = || error!("The first step every ghost is on an end node is too large to count");
+
+    let mut candidates: Vec<(i128, i128)> = vec![(0, 1)];
+    for cycle_ends in ends {
+        let mut combined = Vec::new();
+        for &candidate in &candidates {
+            for &end in cycle_ends {
+                if let Some(congruence) = crt(&[candidate, end]).map_err(|_| overflow())? {
+                    combined.push(congruence);
+                }
+            }
+        }
+        combined.sort_unstable();
+        combined.dedup();
+        candidates = combined;
+    }
+
+    let mut first = None;
+    for (remainder, modulus) in candidates {
+        // The solution has to be past the lead-in of every cycle
+        let step = if remainder >= lead_in {
+            remainder
+        } else {
+            let cycles = (lead_in - remainder + modulus - 1) / modulus;
+            cycles
+                .checked_mul(modulus)
+                .and_then(|skipped| skipped.checked_add(remainder))
+                .ok_or_else(overflow)?
+        };
+        let step = u64::try_from(step).map_err(|_| overflow())?;
+        first = Some(first.map_or(step, |first: u64| first.min(step)));
+    }
+
+    Ok(first)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Map;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        input.parse()
+    }
+
+    fn part1(map: &Self::Parsed) -> Result<Answer> {
+        Ok(part1(map)?.into())
+    }
+
+    fn part2(map: &Self::Parsed) -> Result<Answer> {
+        Ok(part2(map)?.into())
+    }
+}
+
+fn part1(map: &Map) -> Result<usize> {
+    // We'll follow the directions until we reach the end, which is ZZZ. Once every node has been
+    // visited at every point in the directions, the walk is going round in circles without it
+    let max_steps = map.nodes.len() * map.directions.len();
+    let mut current_node = Node("AAA".to_string());
+    let mut steps_taken = 0;
+    loop {
+        if steps_taken >= max_steps {
+            return Err(error!("ZZZ can't be reached from AAA"));
+        }
+        let (left, right) = map
+            .nodes
+            .get(&current_node)
+            .ok_or(error!("No node found for {}", current_node.0))?;
+        let steps_idx = steps_taken % map.directions.len();
+        match map.directions[steps_idx] {
+            Direction::L => current_node = Node(left.0.clone()),
+            Direction::R => current_node = Node(right.0.clone()),
+        };
+        steps_taken += 1;
+        if current_node == Node("ZZZ".to_string()) {
+            break;
+        }
+    }
+
+    Ok(steps_taken)
+}
+
+fn part2(map: &Map) -> Result<u64> {
+    // Get all start nodes
+    let start_nodes = map
+        .nodes
+        .iter()
+        .filter(|(node, _)| node.is_start())
+        .map(|(node, _)| node)
+        .collect::<Vec<&Node>>();
+
+    // Find the cycle each ghost ends up walking in, which might include several end nodes and
+    // might not start until after a few steps
+    let cycles = start_nodes
+        .into_iter()
+        .map(|node| map.find_end_cycle(node))
+        .collect::<Result<Vec<_>>>()?;
+
+    first_common_end(&cycles)?.ok_or(error!("Ghosts never reach end nodes at the same time"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PART_1_TEST_INPUT: &str = include_str!("../part_1_test.txt");
+
+    #[test]
+    fn test_map_from_str() {
+        let map = Map::from_str(PART_1_TEST_INPUT).unwrap();
+        assert_eq!(map.directions.len(), 3);
+        assert_eq!(map.nodes.len(), 3);
+    }
+
+    #[test]
+    fn test_map_parse_error_span() {
+        let err = Map::from_str("LXR\n\nAAA = (BBB, CCC)").err().unwrap();
+        assert_eq!(err.span(), Some(&Span::new(1, 1..2, "LXR")));
+
+        let err = Map::from_str("LR\n\nAAA = (BBB, CCC)\nBBB = BBB, CCC")
+            .err()
+            .unwrap();
+        assert_eq!(err.span(), Some(&Span::new(4, 6..14, "BBB = BBB, CCC")));
+        assert!(err.to_string().starts_with("parsing nodes: "));
+    }
+
+    #[test]
+    fn test_map_without_directions() {
+        let err = Map::from_str("\n\nAAA = (BBB, CCC)").err().unwrap();
+        assert_eq!(err.to_string(), "Unable to parse: No directions found");
+    }
+
+    #[test]
+    fn test_part1_unreachable_end() {
+        let map =
+            Map::from_str("LR\n\nAAA = (BBB, BBB)\nBBB = (AAA, AAA)\nZZZ = (ZZZ, ZZZ)").unwrap();
+        assert_eq!(
+            part1(&map).unwrap_err().to_string(),
+            "Error: ZZZ can't be reached from AAA"
+        );
+    }
+
+    #[test]
+    fn test_part2_many_end_nodes() {
+        // Eight ghosts that are on an end node at every step of their cycles, which would be 8^8
+        // combinations of end nodes to try all at once
+        let mut input = String::from("L\n");
+        for ghost in 1..=8 {
+            input += &format!("\n{}A = ({}0Z, {}0Z)", ghost, ghost, ghost);
+            for end in 0..8 {
+                let next = format!("{}{}Z", ghost, (end + 1) % 8);
+                input += &format!("\n{}{}Z = ({}, {})", ghost, end, next, next);
+            }
+        }
+        let map = Map::from_str(&input).unwrap();
+
+        assert_eq!(part2(&map).unwrap(), 1);
+    }
+
+    #[test]
+    fn test_combine_ends() {
+        // Two ends every 4 steps and one every 6, which meet after 2 steps every 12
+        let ends = vec![vec![(1, 4), (2, 4)], vec![(2, 6)]];
+        assert_eq!(combine_ends(&ends, 1).unwrap(), Some(2));
+        // Past a longer lead-in, the next time they meet
+        assert_eq!(combine_ends(&ends, 5).unwrap(), Some(14));
+        // The ends every 4 steps are never odd steps apart from the one every 6
+        let ends = vec![vec![(1, 4)], vec![(2, 6)]];
+        assert_eq!(combine_ends(&ends, 1).unwrap(), None);
+    }
+
+    #[test]
+    fn test_combine_ends_overflow() {
+        // The cycle lengths are primes, so the first common end is past u64::MAX
+        let ends = vec![
+            vec![(1, 4_294_967_291)],
+            vec![(2, 4_294_967_279)],
+            vec![(3, 4_294_967_231)],
+        ];
+        assert_eq!(
+            combine_ends(&ends, 1).unwrap_err().to_string(),
+            "Error: The first step every ghost is on an end node is too large to count"
+        );
+
+        // And these overflow while combining them
+        let ends = vec![
+            vec![(1, (1 << 62) - 57)],
+            vec![(2, (1 << 62) - 87)],
+            vec![(3, (1 << 62) - 117)],
+        ];
+        assert!(combine_ends(&ends, 1).is_err());
+    }
+
+    #[test]
+    fn test_node_is_start() {
+        assert!(Node("AAA".to_string()).is_start());
+        assert!(Node("CBA".to_string()).is_start());
+        assert!(!Node("ABC".to_string()).is_start());
+    }
+
+    #[test]
+    fn test_node_is_end() {
+        assert!(Node("ZZZ".to_string()).is_end());
+        assert!(Node("XYZ".to_string()).is_end());
+        assert!(!Node("ABC".to_string()).is_end());
+    }
+}
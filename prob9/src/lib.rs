@@ -1,8 +1,6 @@
 use advent::prelude::*;
 
-const INPUT: &str = include_str!("../input.txt");
-
-struct Sequence {
+pub struct Sequence {
     stack: Vec<Vec<i64>>,
 }
 
@@ -12,8 +10,8 @@ impl FromStr for Sequence {
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
         let numbers = s
             .split_whitespace()
-            .map(|s| s.parse::<i64>())
-            .collect::<std::result::Result<Vec<_>, _>>()?;
+            .map(|n| parse_at(s, n))
+            .collect::<Result<Vec<i64>>>()?;
 
         let mut stack = vec![numbers];
 
@@ -31,8 +29,11 @@ impl FromStr for Sequence {
             let next_layer = current_layer
                 .iter()
                 .zip(current_layer.iter().skip(1))
-                .map(|(&a, &b)| b - a)
-                .collect::<Vec<_>>();
+                .map(|(&a, &b)| {
+                    b.checked_sub(a)
+                        .ok_or(error!("Difference between {} and {} overflows", b, a))
+                })
+                .collect::<Result<Vec<_>>>()?;
 
             stack.push(next_layer);
         }
@@ -59,7 +60,11 @@ impl Sequence {
     /// top layer, so the next value is 18
     fn next_value(&self) -> Result<i64> {
         // Add the last value of all the layers
-        Ok(self.stack.iter().map(|layer| layer.last().unwrap()).sum())
+        self.stack.iter().try_fold(0i64, |sum, layer| {
+            let last_val = layer.last().ok_or(error!("Unable to get last value"))?;
+            sum.checked_add(*last_val)
+                .ok_or(error!("Next value overflows"))
+        })
     }
 
     /// Find the previous value in the sequence
@@ -72,67 +77,51 @@ impl Sequence {
         let mut last = 0;
         for layer in self.stack.iter().rev() {
             let first_val = layer.first().ok_or(error!("Unable to get first value"))?;
-            last = first_val - last;
+            last = first_val
+                .checked_sub(last)
+                .ok_or(error!("Previous value overflows"))?;
         }
 
         Ok(last)
     }
 }
 
-fn main() -> Result<()> {
-    println!("## Part 1");
-    println!(" > {}", part1(INPUT)?);
+pub struct Day;
+
+impl Solution for Day {
+    type Parsed = Vec<Sequence>;
 
-    println!("## Part 2");
-    println!(" > {}", part2(INPUT)?);
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        parse_lines(input, str::parse).context("parsing sequences")
+    }
 
-    Ok(())
+    fn part1(sequences: &Self::Parsed) -> Result<Answer> {
+        Ok(part1(sequences)?.into())
+    }
+
+    fn part2(sequences: &Self::Parsed) -> Result<Answer> {
+        Ok(part2(sequences)?.into())
+    }
 }
 
-fn part1(input: &str) -> Result<i64> {
-    let sequences: Vec<Sequence> = input
-        .lines()
-        .map(|line| line.parse())
-        .collect::<Result<Vec<_>>>()?;
-    let sum_of_next_values = sequences
-        .iter()
-        .map(|sequence| sequence.next_value())
-        .collect::<Result<Vec<_>>>()?
-        .iter()
-        .sum::<i64>();
-    Ok(sum_of_next_values)
+fn part1(sequences: &[Sequence]) -> Result<i64> {
+    sequences.iter().try_fold(0i64, |sum, sequence| {
+        sum.checked_add(sequence.next_value()?)
+            .ok_or(error!("Sum of next values overflows"))
+    })
 }
 
-fn part2(input: &str) -> Result<i64> {
-    let sequences: Vec<Sequence> = input
-        .lines()
-        .map(|line| line.parse())
-        .collect::<Result<Vec<_>>>()?;
-    let sum_of_previous_values = sequences
-        .iter()
-        .map(|sequence| sequence.previous_value())
-        .collect::<Result<Vec<_>>>()?
-        .iter()
-        .sum::<i64>();
-    Ok(sum_of_previous_values)
+fn part2(sequences: &[Sequence]) -> Result<i64> {
+    sequences.iter().try_fold(0i64, |sum, sequence| {
+        sum.checked_add(sequence.previous_value()?)
+            .ok_or(error!("Sum of previous values overflows"))
+    })
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const TEST_INPUT: &str = include_str!("../test.txt");
-
-    #[test]
-    fn test_part1() {
-        assert_eq!(part1(TEST_INPUT).unwrap(), 114);
-    }
-
-    #[test]
-    fn test_part2() {
-        assert_eq!(part2(TEST_INPUT).unwrap(), 2);
-    }
-
     #[test]
     fn test_sequence_from_str() {
         let sequence = "1 2 3 4 5".parse::<Sequence>().unwrap();
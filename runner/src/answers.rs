@@ -0,0 +1,201 @@
+use advent::prelude::*;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// The default answers file, `answers.toml` in the root of the workspace
+pub fn default_path() -> PathBuf {
+    crate::scaffold::workspace_root().join("answers.toml")
+}
+
+/// The accepted answers, keyed by day and part
+///
+/// They are stored as TOML, with a table for each day:
+///
+/// ```toml
+/// [17]
+/// part1 = "1138"
+/// part2 = "1312"
+/// ```
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    answers: BTreeMap<(u32, u32), String>,
+}
+
+impl Answers {
+    /// Load the answers from a file, which doesn't have to exist yet
+    pub fn load(path: &Path) -> Result<Self> {
+        match std::fs::read_to_string(path) {
+            Ok(contents) => contents
+                .parse()
+                .with_context(|| format!("reading answers from {}", path.display())),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        Ok(std::fs::write(path, self.to_string())?)
+    }
+
+    pub fn get(&self, day: u32, part: u32) -> Option<&str> {
+        self.answers.get(&(day, part)).map(String::as_str)
+    }
+
+    pub fn record(&mut self, day: u32, part: u32, answer: impl Into<String>) {
+        self.answers.insert((day, part), answer.into());
+    }
+
+    /// Compare an answer with the recorded one
+    pub fn check(&self, day: u32, part: u32, answer: &str) -> Status {
+        match self.get(day, part) {
+            Some(expected) if expected == answer => Status::Match,
+            Some(expected) => Status::Mismatch {
+                expected: expected.to_string(),
+            },
+            None => Status::Missing,
+        }
+    }
+}
+
+impl FromStr for Answers {
+    type Err = AdventError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let table: toml::Table = s.parse().map_err(|e: toml::de::Error| {
+            let error = parse_error!(e.message());
+            match e.span() {
+                Some(span) => error.locate(s, &s[span]),
+                None => error,
+            }
+        })?;
+
+        let mut answers = Self::default();
+        for (day, parts) in &table {
+            let number = day
+                .parse()
+                .map_err(|_| parse_error!(format!("Expected a day number, got {}", day)))?;
+            let parts = parts.as_table().ok_or(parse_error!(format!(
+                "Expected a table of parts for {}",
+                day
+            )))?;
+
+            for (part, answer) in parts {
+                let part_number = match part.as_str() {
+                    "part1" => 1,
+                    "part2" => 2,
+                    _ => return Err(parse_error!(format!("Unknown part {} for {}", part, day))),
+                };
+                let answer = answer.as_str().ok_or(parse_error!(format!(
+                    "Expected the answer for {}.{} to be a string",
+                    day, part
+                )))?;
+                answers.record(number, part_number, answer);
+            }
+        }
+
+        Ok(answers)
+    }
+}
+
+/// Write the answers out as TOML, in order of day and part
+impl fmt::Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut current_day = None;
+        for (&(day, part), answer) in &self.answers {
+            if current_day != Some(day) {
+                if current_day.is_some() {
+                    writeln!(f)?;
+                }
+                writeln!(f, "[{}]", day)?;
+                current_day = Some(day);
+            }
+            writeln!(f, "part{} = {}", part, toml::Value::from(answer.as_str()))?;
+        }
+        Ok(())
+    }
+}
+
+/// How an answer compares with the recorded one
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Match,
+    Mismatch {
+        expected: String,
+    },
+    /// There is no recorded answer to compare with
+    Missing,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Match => write!(f, "match"),
+            Status::Mismatch { .. } => write!(f, "mismatch"),
+            Status::Missing => write!(f, "missing"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ANSWERS: &str = "[2]\npart1 = \"8\"\n\n[10]\npart1 = \"70\"\npart2 = \"hello\"\n";
+
+    #[test]
+    fn test_answers_round_trip() {
+        let answers: Answers = ANSWERS.parse().unwrap();
+
+        assert_eq!(answers.get(2, 1), Some("8"));
+        assert_eq!(answers.get(2, 2), None);
+        assert_eq!(answers.get(10, 2), Some("hello"));
+        assert_eq!(answers.to_string(), ANSWERS);
+    }
+
+    #[test]
+    fn test_answers_record() {
+        let mut answers = Answers::default();
+        answers.record(10, 2, "1");
+        answers.record(2, 1, "3");
+        answers.record(10, 2, "2");
+
+        assert_eq!(
+            answers.to_string(),
+            "[2]\npart1 = \"3\"\n\n[10]\npart2 = \"2\"\n"
+        );
+    }
+
+    #[test]
+    fn test_answers_check() {
+        let answers: Answers = ANSWERS.parse().unwrap();
+
+        assert_eq!(answers.check(2, 1, "8"), Status::Match);
+        assert_eq!(
+            answers.check(2, 1, "9"),
+            Status::Mismatch {
+                expected: "8".to_string()
+            }
+        );
+        assert_eq!(answers.check(3, 1, "8"), Status::Missing);
+    }
+
+    #[test]
+    fn test_answers_parse_error() {
+        assert!("[2]\npart3 = \"1\"".parse::<Answers>().is_err());
+        assert!("[two]\npart1 = \"1\"".parse::<Answers>().is_err());
+        assert!("[2]\npart1 = 1".parse::<Answers>().is_err());
+
+        let err = "[2]\npart1 = \"1\npart2 = \"2\""
+            .parse::<Answers>()
+            .unwrap_err();
+        assert_eq!(err.span().map(|span| span.line), Some(2));
+    }
+
+    #[test]
+    fn test_answers_load_missing_file() {
+        let answers = Answers::load(Path::new("/does/not/exist/answers.toml")).unwrap();
+
+        assert_eq!(answers, Answers::default());
+    }
+}
@@ -0,0 +1,413 @@
+use advent::prelude::*;
+use runner::days::Day;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+/// The step of a day that is timed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Phase {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl Phase {
+    pub fn from_part(part: u32) -> Option<Self> {
+        match part {
+            1 => Some(Phase::Part1),
+            2 => Some(Phase::Part2),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Phase::Parse => write!(f, "parse"),
+            Phase::Part1 => write!(f, "part1"),
+            Phase::Part2 => write!(f, "part2"),
+        }
+    }
+}
+
+/// Statistics over repeated runs of the same thing
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Stats {
+    #[serde(rename = "median_ns", with = "nanos")]
+    pub median: Duration,
+    #[serde(rename = "min_ns", with = "nanos")]
+    pub min: Duration,
+    pub runs: usize,
+}
+
+impl Stats {
+    /// The statistics of a set of samples, or None if there are none
+    pub fn from_samples(mut samples: Vec<Duration>) -> Option<Self> {
+        samples.sort();
+        let runs = samples.len();
+        let middle = runs / 2;
+        let median = match runs {
+            0 => return None,
+            _ if runs.is_multiple_of(2) => (samples[middle - 1] + samples[middle]) / 2,
+            _ => samples[middle],
+        };
+
+        Some(Self {
+            median,
+            min: samples[0],
+            runs,
+        })
+    }
+}
+
+/// Time `f` over a number of runs, stopping at the first error
+pub fn time<T>(runs: usize, mut f: impl FnMut() -> Result<T>) -> Result<Stats> {
+    let mut samples = Vec::with_capacity(runs);
+    for _ in 0..runs {
+        let start = Instant::now();
+        std::hint::black_box(f()?);
+        samples.push(start.elapsed());
+    }
+    Stats::from_samples(samples).ok_or(error!("Nothing was timed, the number of runs is 0"))
+}
+
+/// The timing of a phase of a day
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Measurement {
+    pub day: u32,
+    pub phase: Phase,
+    #[serde(flatten)]
+    pub stats: Stats,
+}
+
+/// Time parsing the input of a day, and then the implemented parts on the parsed input
+pub fn bench_day(day: &Day, parts: &[u32], input: &str, runs: usize) -> Result<Vec<Measurement>> {
+    let context = || format!("day {} input", day.number);
+    let stats = time(runs, || (day.parse)(input)).with_context(context)?;
+    let mut measurements = vec![Measurement {
+        day: day.number,
+        phase: Phase::Parse,
+        stats,
+    }];
+
+    let parsed = (day.parse)(input).with_context(context)?;
+    for &part in parts {
+        let (Some(solve), Some(phase)) = (day.part(part), Phase::from_part(part)) else {
+            continue;
+        };
+        let stats = time(runs, || solve(parsed.as_ref(), &Params::default()))
+            .with_context(|| format!("day {} part {}", day.number, part))?;
+        measurements.push(Measurement {
+            day: day.number,
+            phase,
+            stats,
+        });
+    }
+    Ok(measurements)
+}
+
+/// How a timing compares with the baseline
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Change {
+    /// The median changed by this percentage, within the threshold
+    Within(f64),
+    /// The median got slower by this percentage, more than the threshold
+    Regression(f64),
+    /// There is nothing in the baseline to compare with
+    New,
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Change::Within(percent) => write!(f, "{:+.1}%", percent),
+            Change::Regression(percent) => write!(f, "{:+.1}% regression", percent),
+            Change::New => write!(f, "new"),
+        }
+    }
+}
+
+/// The timings of a benchmark run, which can be saved and used as a baseline for later runs
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Report {
+    pub measurements: Vec<Measurement>,
+}
+
+impl Report {
+    pub fn load(path: &Path) -> Result<Self> {
+        std::fs::read_to_string(path)?
+            .parse()
+            .with_context(|| format!("reading baseline from {}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        Ok(std::fs::write(path, self.to_json() + "\n")?)
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("A report is always valid JSON")
+    }
+
+    fn find(&self, day: u32, phase: Phase) -> Option<&Measurement> {
+        self.measurements
+            .iter()
+            .find(|m| m.day == day && m.phase == phase)
+    }
+
+    /// Compare a timing with the same one in the baseline
+    ///
+    /// The threshold is the percentage the median can get slower by before it's a regression
+    pub fn compare(&self, measurement: &Measurement, threshold: f64) -> Change {
+        let Some(baseline) = self.find(measurement.day, measurement.phase) else {
+            return Change::New;
+        };
+        let before = baseline.stats.median.as_secs_f64();
+        if before == 0.0 {
+            return Change::New;
+        }
+
+        let percent = (measurement.stats.median.as_secs_f64() / before - 1.0) * 100.0;
+        if percent > threshold {
+            Change::Regression(percent)
+        } else {
+            Change::Within(percent)
+        }
+    }
+
+    /// The timings that regressed compared with the baseline
+    pub fn regressions<'a>(&'a self, baseline: &Report, threshold: f64) -> Vec<&'a Measurement> {
+        self.measurements
+            .iter()
+            .filter(|m| matches!(baseline.compare(m, threshold), Change::Regression(_)))
+            .collect()
+    }
+
+    /// A table of the timings, compared with the baseline if there is one
+    pub fn table(&self, baseline: Option<&Report>, threshold: f64) -> String {
+        let mut output = String::from("Day  Phase  Median      Min         Runs");
+        if baseline.is_some() {
+            output.push_str("  Baseline");
+        }
+        output.push('\n');
+
+        for measurement in &self.measurements {
+            let change = baseline
+                .map(|baseline| baseline.compare(measurement, threshold).to_string())
+                .unwrap_or_default();
+            let row = format!(
+                "{:>3}  {:<5}  {:<10}  {:<10}  {:>4}  {}",
+                measurement.day,
+                measurement.phase,
+                format!("{:.2?}", measurement.stats.median),
+                format!("{:.2?}", measurement.stats.min),
+                measurement.stats.runs,
+                change
+            );
+            output.push_str(row.trim_end());
+            output.push('\n');
+        }
+        output
+    }
+}
+
+impl FromStr for Report {
+    type Err = AdventError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        serde_json::from_str(s).map_err(|e| {
+            let error = parse_error!(e);
+            match s.lines().nth(e.line().saturating_sub(1)) {
+                Some(line) if e.line() > 0 => {
+                    let column = e.column().saturating_sub(1);
+                    error.with_span(Span::new(e.line(), column..column + 1, line))
+                }
+                _ => error,
+            }
+        })
+    }
+}
+
+/// Durations as a whole number of nanoseconds, which is easier to read and compare than seconds
+mod nanos {
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::time::Duration;
+
+    pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(duration.as_nanos().try_into().unwrap_or(u64::MAX))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        u64::deserialize(deserializer).map(Duration::from_nanos)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn measurement(day: u32, phase: Phase, median_ms: u64) -> Measurement {
+        Measurement {
+            day,
+            phase,
+            stats: Stats {
+                median: Duration::from_millis(median_ms),
+                min: Duration::from_millis(median_ms / 2),
+                runs: 5,
+            },
+        }
+    }
+
+    #[test]
+    fn test_stats_from_samples() {
+        let ms = |samples: &[u64]| {
+            samples
+                .iter()
+                .map(|&ms| Duration::from_millis(ms))
+                .collect()
+        };
+
+        assert_eq!(
+            Stats::from_samples(ms(&[5, 1, 3])),
+            Some(Stats {
+                median: Duration::from_millis(3),
+                min: Duration::from_millis(1),
+                runs: 3,
+            })
+        );
+        assert_eq!(
+            Stats::from_samples(ms(&[4, 1, 8, 2])).map(|stats| stats.median),
+            Some(Duration::from_millis(3))
+        );
+        assert_eq!(Stats::from_samples(Vec::new()), None);
+    }
+
+    #[test]
+    fn test_time() {
+        let mut calls = 0;
+        let stats = time(3, || {
+            calls += 1;
+            Ok(calls)
+        })
+        .unwrap();
+
+        assert_eq!(stats.runs, 3);
+        assert_eq!(calls, 3);
+        assert!(stats.min <= stats.median);
+
+        assert!(time(3, || -> Result<()> { Err(error!("Oops")) }).is_err());
+        assert!(time(0, || Ok(())).is_err());
+    }
+
+    #[test]
+    fn test_bench_day() {
+        let registered = runner::days::find(20).unwrap();
+        // Part 2 is taken out, as if it wasn't implemented, so it isn't timed
+        let day = Day {
+            parts: [registered.parts[0], None],
+            ..registered
+        };
+
+        let measurements = bench_day(&day, &[1, 2], "broadcaster -> a\n%a -> b", 2).unwrap();
+
+        let phases: Vec<_> = measurements.iter().map(|m| m.phase).collect();
+        assert_eq!(phases, vec![Phase::Parse, Phase::Part1]);
+        assert!(measurements
+            .iter()
+            .all(|m| m.day == 20 && m.stats.runs == 2));
+
+        let failing = Day {
+            number: 3,
+            parse: |_| Ok(Box::new(())),
+            parts: [Some(|_, _| Err(error!("Oops"))), None],
+            explain: |_| Ok(None),
+        };
+        let err = bench_day(&failing, &[1, 2], "", 2).unwrap_err();
+        assert_eq!(err.to_string(), "day 3 part 1: Error: Oops");
+
+        let unparsable = Day {
+            parse: |_| Err(parse_error!("Oops")),
+            ..failing
+        };
+        let err = bench_day(&unparsable, &[1, 2], "", 2).unwrap_err();
+        assert_eq!(err.to_string(), "day 3 input: Unable to parse: Oops");
+    }
+
+    #[test]
+    fn test_compare() {
+        let baseline = Report {
+            measurements: vec![measurement(5, Phase::Part1, 100)],
+        };
+
+        assert_eq!(
+            baseline.compare(&measurement(5, Phase::Part1, 125), 30.0),
+            Change::Within(25.0)
+        );
+        assert_eq!(
+            baseline.compare(&measurement(5, Phase::Part1, 200), 10.0),
+            Change::Regression(100.0)
+        );
+        assert_eq!(
+            baseline.compare(&measurement(5, Phase::Part1, 50), 10.0),
+            Change::Within(-50.0)
+        );
+        assert_eq!(
+            baseline.compare(&measurement(5, Phase::Part2, 100), 10.0),
+            Change::New
+        );
+    }
+
+    #[test]
+    fn test_table() {
+        let report = Report {
+            measurements: vec![
+                measurement(14, Phase::Part1, 2),
+                measurement(14, Phase::Part2, 300),
+            ],
+        };
+        let baseline = Report {
+            measurements: vec![measurement(14, Phase::Part2, 200)],
+        };
+
+        assert_eq!(
+            report.table(None, 10.0),
+            [
+                "Day  Phase  Median      Min         Runs",
+                " 14  part1  2.00ms      1.00ms         5",
+                " 14  part2  300.00ms    150.00ms       5",
+                "",
+            ]
+            .join("\n")
+        );
+        assert_eq!(
+            report.table(Some(&baseline), 10.0),
+            [
+                "Day  Phase  Median      Min         Runs  Baseline",
+                " 14  part1  2.00ms      1.00ms         5  new",
+                " 14  part2  300.00ms    150.00ms       5  +50.0% regression",
+                "",
+            ]
+            .join("\n")
+        );
+        assert_eq!(report.regressions(&baseline, 10.0).len(), 1);
+        assert!(report.regressions(&baseline, 60.0).is_empty());
+    }
+
+    #[test]
+    fn test_report_round_trip() {
+        let report = Report {
+            measurements: vec![measurement(5, Phase::Part2, 1)],
+        };
+
+        let json = report.to_json();
+        assert!(json.contains("\"phase\": \"part2\""));
+        assert!(json.contains("\"median_ns\": 1000000"));
+        assert_eq!(json.parse::<Report>().unwrap(), report);
+
+        let err = "{\n  \"measurements\": [}".parse::<Report>().unwrap_err();
+        assert_eq!(err.span().map(|span| span.line), Some(2));
+    }
+}
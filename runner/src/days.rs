@@ -0,0 +1,179 @@
+use advent::prelude::*;
+use std::any::Any;
+
+/// The parsed input of a day, which only that day's parts know the type of
+pub type Parsed = Box<dyn Any>;
+
+/// Parse the input of a day
+pub type Parse = fn(&str) -> Result<Parsed>;
+
+/// A part of a puzzle, taking the parsed input and any parameters and returning the answer
+pub type Part = fn(&dyn Any, &Params) -> Result<Answer>;
+
+/// Describe the parsed input of a day, if the day has a way to
+pub type Explain = fn(&dyn Any) -> Result<Option<String>>;
+
+/// A registered day, with the parts that have been implemented
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub number: u32,
+    pub parse: Parse,
+    pub parts: [Option<Part>; 2],
+    pub explain: Explain,
+}
+
+impl Day {
+    /// Get a part by its number, 1 or 2, if it has been implemented
+    pub fn part(&self, part: u32) -> Option<Part> {
+        match part {
+            1 | 2 => self.parts[part as usize - 1],
+            _ => None,
+        }
+    }
+}
+
+fn parse<S: Solution>(input: &str) -> Result<Parsed>
+where
+    S::Parsed: 'static,
+{
+    Ok(Box::new(S::parse(input)?))
+}
+
+fn parsed<S: Solution>(parsed: &dyn Any) -> &S::Parsed
+where
+    S::Parsed: 'static,
+{
+    // The registry always pairs the parse and parts of the same day
+    parsed
+        .downcast_ref()
+        .expect("Parsed input should be passed to the day that parsed it")
+}
+
+fn part1<S: Solution>(input: &dyn Any, params: &Params) -> Result<Answer>
+where
+    S::Parsed: 'static,
+{
+    S::part1_with(parsed::<S>(input), params)
+}
+
+fn part2<S: Solution>(input: &dyn Any, params: &Params) -> Result<Answer>
+where
+    S::Parsed: 'static,
+{
+    S::part2_with(parsed::<S>(input), params)
+}
+
+fn explain<S: Solution>(input: &dyn Any) -> Result<Option<String>>
+where
+    S::Parsed: 'static,
+{
+    S::explain(parsed::<S>(input))
+}
+
+/// Register a day, where each part is implemented unless listed as `unimplemented`
+macro_rules! day {
+    ($number:literal, $day:ident) => {
+        Day {
+            number: $number,
+            parse: parse::<$day::Day>,
+            parts: [Some(part1::<$day::Day>), Some(part2::<$day::Day>)],
+            explain: explain::<$day::Day>,
+        }
+    };
+    ($number:literal, $day:ident, unimplemented: part2) => {
+        Day {
+            number: $number,
+            parse: parse::<$day::Day>,
+            parts: [Some(part1::<$day::Day>), None],
+            explain: explain::<$day::Day>,
+        }
+    };
+    ($number:literal, $day:ident, unimplemented: part1, part2) => {
+        Day {
+            number: $number,
+            parse: parse::<$day::Day>,
+            parts: [None, None],
+            explain: explain::<$day::Day>,
+        }
+    };
+}
+
+/// All the registered days, in order
+pub fn days() -> Vec<Day> {
+    vec![
+        day!(1, prob1),
+        day!(2, prob2),
+        day!(3, prob3),
+        day!(4, prob4),
+        day!(5, prob5),
+        day!(6, prob6),
+        day!(7, prob7),
+        day!(8, prob8),
+        day!(9, prob9),
+        day!(10, prob10),
+        day!(11, prob11),
+        day!(12, prob12),
+        day!(13, prob13),
+        day!(14, prob14),
+        day!(15, prob15),
+        day!(16, prob16),
+        day!(17, prob17),
+        day!(18, prob18),
+        day!(19, prob19),
+        day!(20, prob20),
+    ]
+}
+
+/// Find a registered day by its number
+pub fn find(number: u32) -> Option<Day> {
+    days().into_iter().find(|day| day.number == number)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_days_are_in_order() {
+        let numbers: Vec<_> = days().iter().map(|day| day.number).collect();
+
+        assert_eq!(numbers, (1..=20).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_find() {
+        let day = find(17).unwrap();
+
+        assert_eq!(day.number, 17);
+        assert!(day.part(1).is_some());
+        assert!(day.part(2).is_some());
+        assert!(day.part(3).is_none());
+        assert!(find(26).is_none());
+    }
+
+    #[test]
+    fn test_parse_and_solve() {
+        let day = find(1).unwrap();
+
+        let parsed = (day.parse)("1abc2\npqr3stu8vwx").unwrap();
+
+        let solve = day.part(1).unwrap();
+        assert_eq!(solve(parsed.as_ref(), &Params::default()).unwrap(), 50);
+        assert!(solve(parsed.as_ref(), &[("scale", 2)].into_iter().collect()).is_err());
+        // Day 1 has nothing more to say about its input
+        assert!((day.explain)(parsed.as_ref()).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_unimplemented_part() {
+        let day = day!(20, prob20, unimplemented: part2);
+
+        assert!(day.part(1).is_some());
+        assert!(day.part(2).is_none());
+
+        let day = day!(20, prob20, unimplemented: part1, part2);
+
+        assert!(day.part(1).is_none());
+        assert!(day.part(2).is_none());
+    }
+}
@@ -0,0 +1,292 @@
+use crate::days::Day;
+use advent::prelude::*;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// The file in each day's directory that lists its examples
+pub const MANIFEST: &str = "examples.toml";
+
+/// The examples of a day, each with the part it's for and the expected answer
+///
+/// They are stored as TOML in the day's directory, as a list of examples:
+///
+/// ```toml
+/// [[example]]
+/// input = "test.txt"
+/// part = 2
+/// answer = 8410
+/// params = { expansion = 100 }
+///
+/// [[example]]
+/// input = "test.txt"
+/// part = 2
+/// answer = { error = "Unknown parameter" }
+/// params = { scale = 100 }
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Examples {
+    #[serde(default, rename = "example")]
+    pub examples: Vec<Example>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Example {
+    /// What to call the example, which defaults to the part
+    pub name: Option<String>,
+    /// A file with the input, relative to the day's directory
+    pub input: Option<PathBuf>,
+    /// The input itself, for examples too short to be worth a file
+    pub text: Option<String>,
+    pub part: u32,
+    pub answer: Expected,
+    /// Parameters for the part, for examples that are a smaller version of the puzzle
+    #[serde(default)]
+    pub params: BTreeMap<String, i64>,
+}
+
+/// The expected answer to an example
+///
+/// Text is compared with how the answer is printed, which also covers numbers too big for TOML.
+/// An example can also expect the part to fail, with an error that contains the given text.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(untagged)]
+pub enum Expected {
+    Number(i64),
+    Text(String),
+    Error { error: String },
+}
+
+impl fmt::Display for Expected {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Expected::Number(n) => write!(f, "{}", n),
+            Expected::Text(text) => write!(f, "{}", text),
+            Expected::Error { error } => write!(f, "an error containing \"{}\"", error),
+        }
+    }
+}
+
+impl Expected {
+    fn matches(&self, answer: &Answer) -> bool {
+        match self {
+            Expected::Number(n) => answer == n,
+            Expected::Text(text) => answer.to_string() == *text,
+            Expected::Error { .. } => false,
+        }
+    }
+}
+
+impl Examples {
+    /// Load the examples of the day in `dir`, which doesn't need to have any
+    pub fn load(dir: &Path) -> Result<Self> {
+        let path = dir.join(MANIFEST);
+        match fs::read_to_string(&path) {
+            Ok(contents) => contents
+                .parse()
+                .with_context(|| format!("reading examples from {}", path.display())),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// The examples with their names, where repeated names are numbered from the second one on
+    pub fn named(self) -> Vec<(String, Example)> {
+        let mut seen = HashMap::new();
+        self.examples
+            .into_iter()
+            .map(|example| {
+                let name = example
+                    .name
+                    .clone()
+                    .unwrap_or_else(|| format!("part{}", example.part));
+                let count = seen.entry(name.clone()).or_insert(0);
+                *count += 1;
+                match *count {
+                    1 => (name, example),
+                    n => (format!("{}_{}", name, n), example),
+                }
+            })
+            .collect()
+    }
+}
+
+impl FromStr for Examples {
+    type Err = AdventError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let examples: Self = toml::from_str(s).map_err(|e: toml::de::Error| {
+            let error = parse_error!(e.message());
+            match e.span() {
+                Some(span) => error.locate(s, &s[span]),
+                None => error,
+            }
+        })?;
+
+        for (idx, example) in examples.examples.iter().enumerate() {
+            if example.input.is_some() == example.text.is_some() {
+                return Err(parse_error!(format!(
+                    "Example {} needs either an input file or text, but not both",
+                    idx + 1
+                )));
+            }
+            if !(1..=2).contains(&example.part) {
+                return Err(parse_error!(format!(
+                    "Example {} is for part {}, which should be 1 or 2",
+                    idx + 1,
+                    example.part
+                )));
+            }
+        }
+        Ok(examples)
+    }
+}
+
+impl Example {
+    /// The input of the example, where an input file is relative to the day's directory
+    pub fn read_input(&self, dir: &Path) -> Result<String> {
+        match (&self.input, &self.text) {
+            (Some(path), _) => {
+                let path = dir.join(path);
+                fs::read_to_string(&path).with_context(|| format!("reading {}", path.display()))
+            }
+            (None, text) => Ok(text.clone().unwrap_or_default()),
+        }
+    }
+
+    /// Run the example on a day, whose directory any input file is relative to
+    pub fn check(&self, day: &Day, dir: &Path) -> Result<()> {
+        let input = self.read_input(dir)?;
+        let solve = day
+            .part(self.part)
+            .ok_or(error!("Part {} isn't implemented", self.part))?;
+
+        let parsed = (day.parse)(&input)?;
+        let params: Params = self.params.clone().into_iter().collect();
+        let answer = match (solve(parsed.as_ref(), &params), &self.answer) {
+            (Err(err), Expected::Error { error }) if err.to_string().contains(error) => {
+                return Ok(())
+            }
+            (Err(err), Expected::Error { .. }) => err.to_string(),
+            (Err(err), _) => return Err(err),
+            (Ok(answer), _) if self.answer.matches(&answer) => return Ok(()),
+            (Ok(answer), _) => answer.to_string(),
+        };
+
+        Err(error!("Expected {}, got {}", self.answer, answer))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLES: &str = "[[example]]\ninput = \"part_1_test.txt\"\npart = 1\nanswer = 142\n\n\
+                            [[example]]\ntext = \"1abc2\"\npart = 1\nanswer = \"12\"\n\n\
+                            [[example]]\nname = \"bigger\"\ntext = \"1abc2\\nab9\"\npart = 2\n\
+                            answer = 111\nparams = { scale = 10 }\n";
+
+    #[test]
+    fn test_examples_from_str() {
+        let examples: Examples = EXAMPLES.parse().unwrap();
+
+        let named: Vec<_> = examples.named();
+        assert_eq!(
+            named
+                .iter()
+                .map(|(name, _)| name.as_str())
+                .collect::<Vec<_>>(),
+            vec!["part1", "part1_2", "bigger"]
+        );
+        assert_eq!(
+            named[0].1,
+            Example {
+                name: None,
+                input: Some(PathBuf::from("part_1_test.txt")),
+                text: None,
+                part: 1,
+                answer: Expected::Number(142),
+                params: BTreeMap::new(),
+            }
+        );
+        assert_eq!(named[1].1.answer, Expected::Text("12".to_string()));
+        assert_eq!(named[2].1.params.get("scale"), Some(&10));
+    }
+
+    #[test]
+    fn test_examples_from_str_invalid() {
+        let err = "[[example]]\npart = 1\nanswer = 1\n"
+            .parse::<Examples>()
+            .unwrap_err();
+        assert!(err.to_string().contains("Example 1 needs either"));
+
+        let err = "[[example]]\ntext = \"\"\npart = 3\nanswer = 1\n"
+            .parse::<Examples>()
+            .unwrap_err();
+        assert!(err.to_string().contains("Example 1 is for part 3"));
+
+        let err = "[[example]]\ntext = \"\"\npart = 1\nanwser = 1\n"
+            .parse::<Examples>()
+            .unwrap_err();
+        assert_eq!(err.span().map(|span| span.line), Some(4));
+    }
+
+    #[test]
+    fn test_example_check() {
+        let day = crate::days::find(1).unwrap();
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../prob1");
+        let examples: Examples = EXAMPLES.parse().unwrap();
+
+        assert!(examples.examples[0].check(&day, &dir).is_ok());
+        assert!(examples.examples[1].check(&day, &dir).is_ok());
+        // Day 1 doesn't take any parameters
+        assert_eq!(
+            examples.examples[2]
+                .check(&day, &dir)
+                .unwrap_err()
+                .to_string(),
+            "Error: Unknown parameter: scale"
+        );
+
+        let wrong = Example {
+            answer: Expected::Number(13),
+            ..examples.examples[1].clone()
+        };
+        assert_eq!(
+            wrong.check(&day, &dir).unwrap_err().to_string(),
+            "Error: Expected 13, got 12"
+        );
+
+        let failing: Example = "[[example]]\ntext = \"1abc2\"\npart = 1\n\
+                                answer = { error = \"Unknown parameter: scale\" }\n\
+                                params = { scale = 10 }\n"
+            .parse::<Examples>()
+            .unwrap()
+            .examples
+            .remove(0);
+        assert!(failing.check(&day, &dir).is_ok());
+
+        let wrong = Example {
+            answer: Expected::Error {
+                error: "Oops".to_string(),
+            },
+            ..failing.clone()
+        };
+        assert_eq!(
+            wrong.check(&day, &dir).unwrap_err().to_string(),
+            "Error: Expected an error containing \"Oops\", got Error: Unknown parameter: scale"
+        );
+        let wrong = Example {
+            params: BTreeMap::new(),
+            ..wrong
+        };
+        assert_eq!(
+            wrong.check(&day, &dir).unwrap_err().to_string(),
+            "Error: Expected an error containing \"Oops\", got 12"
+        );
+    }
+}
@@ -0,0 +1,454 @@
+use advent::prelude::*;
+use serde::Deserialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// The year the puzzles are from
+const YEAR: u32 = 2023;
+/// Sent with every request, so the site knows where they come from
+const USER_AGENT: &str = "github.com/ikornaselur/advent-of-code-2023 input fetcher";
+
+/// The default config file, `advent.toml` in the root of the workspace
+pub fn default_config_path() -> PathBuf {
+    crate::scaffold::workspace_root().join("advent.toml")
+}
+
+/// Where the time of the last request is kept, so the delay holds across runs
+pub fn default_stamp_path() -> PathBuf {
+    crate::scaffold::workspace_root().join(".last_fetch")
+}
+
+/// How to fetch inputs, read from a TOML file:
+///
+/// ```toml
+/// session = "53616c7465645f5f..."
+/// # Optional, these are the defaults
+/// endpoint = "https://adventofcode.com"
+/// delay = 5
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    /// The value of the `session` cookie from a logged in browser
+    pub session: String,
+    /// The site to fetch from, which tests point at a local server
+    #[serde(default = "default_endpoint")]
+    pub endpoint: String,
+    /// The least number of seconds between two requests
+    #[serde(default = "default_delay")]
+    pub delay: u64,
+}
+
+fn default_endpoint() -> String {
+    "https://adventofcode.com".to_string()
+}
+
+fn default_delay() -> u64 {
+    5
+}
+
+impl Config {
+    pub fn load(path: &Path) -> Result<Self> {
+        let contents = fs::read_to_string(path).map_err(|e| match e.kind() {
+            std::io::ErrorKind::NotFound => error!(
+                "No config at {}, it needs at least the session token to fetch inputs",
+                path.display()
+            ),
+            _ => e.into(),
+        })?;
+        contents
+            .parse()
+            .with_context(|| format!("reading the config from {}", path.display()))
+    }
+
+    /// The address of the input for a day
+    fn input_url(&self, day: u32) -> String {
+        format!(
+            "{}/{}/day/{}/input",
+            self.endpoint.trim_end_matches('/'),
+            YEAR,
+            day
+        )
+    }
+}
+
+impl FromStr for Config {
+    type Err = AdventError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        toml::from_str(s).map_err(|e: toml::de::Error| {
+            let error = parse_error!(e.message());
+            match e.span() {
+                Some(span) => error.locate(s, &s[span]),
+                None => error,
+            }
+        })
+    }
+}
+
+/// A response to a request, whatever its status
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+/// Something that can send requests to the site, so the backend can be swapped out
+pub trait Http {
+    /// Send a GET request with the session cookie
+    ///
+    /// Only failing to get a response at all is an error, any status is returned as a response
+    fn get(&self, url: &str, session: &str) -> Result<Response>;
+}
+
+/// Sends requests with `ureq`
+pub struct Ureq {
+    agent: ureq::Agent,
+}
+
+impl Ureq {
+    pub fn new() -> Self {
+        let agent = ureq::AgentBuilder::new()
+            .user_agent(USER_AGENT)
+            .timeout(Duration::from_secs(30))
+            .build();
+        Self { agent }
+    }
+}
+
+impl Http for Ureq {
+    fn get(&self, url: &str, session: &str) -> Result<Response> {
+        let response = match self
+            .agent
+            .get(url)
+            .set("Cookie", &format!("session={}", session))
+            .call()
+        {
+            Ok(response) => response,
+            Err(ureq::Error::Status(_, response)) => response,
+            Err(ureq::Error::Transport(e)) => {
+                return Err(error!("Request to {} failed: {}", url, e))
+            }
+        };
+        Ok(Response {
+            status: response.status(),
+            body: response.into_string()?,
+        })
+    }
+}
+
+/// Keeps requests at least a delay apart
+///
+/// The time of the last request is written to a file, so the delay also holds between runs
+pub struct RateLimiter {
+    delay: Duration,
+    stamp: PathBuf,
+}
+
+impl RateLimiter {
+    pub fn new(delay: Duration, stamp: impl Into<PathBuf>) -> Self {
+        Self {
+            delay,
+            stamp: stamp.into(),
+        }
+    }
+
+    /// Wait until the delay since the last request has passed, then note a new request
+    pub fn acquire(&self) -> Result<()> {
+        if let Some(last) = self.last_request() {
+            // A last request in the future means the clock moved, so wait the whole delay
+            let elapsed = SystemTime::now()
+                .duration_since(last)
+                .unwrap_or(Duration::ZERO);
+            if let Some(remaining) = self.delay.checked_sub(elapsed) {
+                std::thread::sleep(remaining);
+            }
+        }
+
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or(Duration::ZERO);
+        fs::write(&self.stamp, now.as_nanos().to_string())?;
+        Ok(())
+    }
+
+    /// When the last request was sent, or None if there hasn't been one
+    fn last_request(&self) -> Option<SystemTime> {
+        let nanos = fs::read_to_string(&self.stamp).ok()?.trim().parse().ok()?;
+        UNIX_EPOCH.checked_add(Duration::from_nanos(nanos))
+    }
+}
+
+/// What fetching an input did
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fetched {
+    /// The input was already there, so nothing was sent
+    Cached,
+    Downloaded,
+}
+
+/// Fetches inputs, never asking for one that is already saved
+pub struct Fetcher<H> {
+    config: Config,
+    http: H,
+    limiter: RateLimiter,
+}
+
+impl<H: Http> Fetcher<H> {
+    /// A fetcher keeping the delay from the config, with the time of the last request in `stamp`
+    pub fn new(config: Config, http: H, stamp: impl Into<PathBuf>) -> Self {
+        let limiter = RateLimiter::new(Duration::from_secs(config.delay), stamp);
+        Self {
+            config,
+            http,
+            limiter,
+        }
+    }
+
+    /// Fetch the input for a day into `path`, unless it's already there
+    pub fn fetch(&self, day: u32, path: &Path) -> Result<Fetched> {
+        if path.exists() {
+            return Ok(Fetched::Cached);
+        }
+
+        let url = self.config.input_url(day);
+        self.limiter.acquire()?;
+        let response = self.http.get(&url, &self.config.session)?;
+
+        match response.status {
+            401 | 403 => return Err(AdventError::SessionExpired),
+            // The site asks to log in when the session is missing or no longer valid, either
+            // with a bad request or with the page that asks for it
+            400 | 200 if response.body.contains("log in") => {
+                return Err(AdventError::SessionExpired)
+            }
+            200..=299 => {}
+            404 => return Err(AdventError::Unreleased { day }),
+            status => {
+                return Err(error!(
+                    "Unexpected status {} from {}: {}",
+                    status,
+                    url,
+                    response.body.lines().next().unwrap_or("").trim()
+                ))
+            }
+        }
+
+        write_atomically(path, &response.body)?;
+        Ok(Fetched::Downloaded)
+    }
+}
+
+/// Write a file so it's either all there or not there at all
+///
+/// The contents go to a temporary file next to it first, which is then renamed over the path, so
+/// an interrupted download never leaves part of an input behind to be read as cached.
+fn write_atomically(path: &Path, contents: &str) -> Result<()> {
+    let dir = match path.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir,
+        _ => Path::new("."),
+    };
+    fs::create_dir_all(dir)?;
+
+    let name = path
+        .file_name()
+        .ok_or(error!("{} is not a file", path.display()))?;
+    let temp = dir.join(format!(
+        ".{}.{}.part",
+        name.to_string_lossy(),
+        std::process::id()
+    ));
+    let written = fs::write(&temp, contents).and_then(|_| fs::rename(&temp, path));
+    if written.is_err() {
+        let _ = fs::remove_file(&temp);
+    }
+    Ok(written?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+    use std::thread::JoinHandle;
+    use std::time::Instant;
+
+    /// Serve one canned response per request on a local port, returning the endpoint and the
+    /// requests that were received once the responses run out
+    fn stub_server(responses: Vec<(u16, &'static str)>) -> (String, JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let endpoint = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = std::thread::spawn(move || {
+            let mut requests = Vec::new();
+            for (status, body) in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut request = Vec::new();
+                let mut buffer = [0; 1024];
+                while !request.ends_with(b"\r\n\r\n") {
+                    let read = stream.read(&mut buffer).unwrap();
+                    if read == 0 {
+                        break;
+                    }
+                    request.extend_from_slice(&buffer[..read]);
+                }
+                requests.push(String::from_utf8_lossy(&request).into_owned());
+
+                write!(
+                    stream,
+                    "HTTP/1.1 {} Stub\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                )
+                .unwrap();
+            }
+            requests
+        });
+
+        (endpoint, handle)
+    }
+
+    fn config(endpoint: &str) -> Config {
+        Config {
+            session: "abc123".to_string(),
+            endpoint: endpoint.to_string(),
+            delay: 0,
+        }
+    }
+
+    #[test]
+    fn test_fetch_caches_the_input() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("inputs").join("17.txt");
+        let (endpoint, server) = stub_server(vec![(200, "2413432311323\n")]);
+        let fetcher = Fetcher::new(config(&endpoint), Ureq::new(), dir.path().join("stamp"));
+
+        assert_eq!(fetcher.fetch(17, &path).unwrap(), Fetched::Downloaded);
+        assert_eq!(fetcher.fetch(17, &path).unwrap(), Fetched::Cached);
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "2413432311323\n");
+        let requests = server.join().unwrap();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].starts_with("GET /2023/day/17/input HTTP/1.1\r\n"));
+        assert!(requests[0].contains("session=abc123"));
+    }
+
+    #[test]
+    fn test_fetch_errors() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("1.txt");
+        let (endpoint, server) = stub_server(vec![
+            (
+                400,
+                "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n",
+            ),
+            (
+                200,
+                "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n",
+            ),
+            (500, "Internal Server Error: please log in again\n"),
+            (
+                404,
+                "Please don't repeatedly request this endpoint before it unlocks!\n",
+            ),
+            (503, "Service Unavailable\nTry again later\n"),
+        ]);
+        let fetcher = Fetcher::new(config(&endpoint), Ureq::new(), dir.path().join("stamp"));
+
+        assert!(matches!(
+            fetcher.fetch(1, &path),
+            Err(AdventError::SessionExpired)
+        ));
+        assert!(matches!(
+            fetcher.fetch(1, &path),
+            Err(AdventError::SessionExpired)
+        ));
+        // Only a bad request or the page itself asking to log in means the session is bad
+        assert_eq!(
+            fetcher.fetch(1, &path).unwrap_err().to_string(),
+            format!(
+                "Error: Unexpected status 500 from {}/2023/day/1/input: Internal Server Error: \
+                 please log in again",
+                endpoint
+            )
+        );
+        assert!(matches!(
+            fetcher.fetch(25, &path),
+            Err(AdventError::Unreleased { day: 25 })
+        ));
+        assert_eq!(
+            fetcher.fetch(1, &path).unwrap_err().to_string(),
+            format!(
+                "Error: Unexpected status 503 from {}/2023/day/1/input: Service Unavailable",
+                endpoint
+            )
+        );
+
+        assert!(!path.exists());
+        assert_eq!(server.join().unwrap().len(), 5);
+    }
+
+    #[test]
+    fn test_write_atomically() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("inputs").join("5.txt");
+
+        write_atomically(&path, "seeds: 79 14 55 13\n").unwrap();
+        write_atomically(&path, "seeds: 79 14\n").unwrap();
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "seeds: 79 14\n");
+        // Nothing but the input is left next to it
+        let entries: Vec<_> = fs::read_dir(path.parent().unwrap())
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name())
+            .collect();
+        assert_eq!(entries, vec!["5.txt"]);
+
+        // A failed rename, here over a directory, doesn't leave the temporary file behind
+        assert!(write_atomically(path.parent().unwrap(), "").is_err());
+        let entries: Vec<_> = fs::read_dir(dir.path())
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name())
+            .collect();
+        assert_eq!(entries, vec!["inputs"]);
+    }
+
+    #[test]
+    fn test_rate_limiter() {
+        let dir = tempfile::tempdir().unwrap();
+        let stamp = dir.path().join("stamp");
+        let delay = Duration::from_millis(200);
+
+        let start = Instant::now();
+        RateLimiter::new(delay, &stamp).acquire().unwrap();
+        assert!(start.elapsed() < delay);
+
+        // A new limiter picks up the last request from the stamp, like a second run would
+        RateLimiter::new(delay, &stamp).acquire().unwrap();
+        assert!(start.elapsed() >= delay);
+    }
+
+    #[test]
+    fn test_config() {
+        let config: Config = "session = \"abc123\"\n".parse().unwrap();
+        assert_eq!(
+            config,
+            Config {
+                session: "abc123".to_string(),
+                endpoint: "https://adventofcode.com".to_string(),
+                delay: 5,
+            }
+        );
+        assert_eq!(
+            config.input_url(3),
+            "https://adventofcode.com/2023/day/3/input"
+        );
+
+        let err = "endpoint = \"http://localhost\"\n"
+            .parse::<Config>()
+            .unwrap_err();
+        assert!(err.to_string().contains("missing field `session`"));
+    }
+}
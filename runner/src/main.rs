@@ -0,0 +1,675 @@
+use advent::input::{self, InputSource};
+use advent::parallel;
+use advent::prelude::*;
+use answers::{Answers, Status};
+use bench::Report;
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use fetch::{Config, Fetched, Fetcher};
+use runner::days::{self, Day};
+use std::any::Any;
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+mod answers;
+mod bench;
+mod fetch;
+mod scaffold;
+
+/// Run the Advent of Code 2023 solutions
+#[derive(Parser, Debug)]
+#[command(name = "advent")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// The file of accepted answers, defaults to answers.toml in the workspace
+    #[arg(long, global = true, value_name = "PATH")]
+    answers: Option<PathBuf>,
+}
+
+#[derive(Subcommand, Debug, PartialEq)]
+enum Command {
+    /// Run a single day, or all of them
+    Run(RunArgs),
+    /// Run every day and compare the answers with the recorded ones
+    Verify,
+    /// Time the parts of a day, or all of them, over repeated runs
+    Bench(BenchArgs),
+    /// List the registered days and which of their parts are implemented
+    List,
+    /// Create a new day from the template and register it
+    New {
+        /// The day to create
+        day: u32,
+    },
+    /// Download the input of a day, or of every registered day, unless it's already there
+    Fetch(FetchArgs),
+}
+
+#[derive(Args, Debug, PartialEq)]
+struct RunArgs {
+    /// The day to run
+    #[arg(required_unless_present = "all", conflicts_with = "all")]
+    day: Option<u32>,
+    /// Only run one of the parts
+    #[arg(long, value_parser = clap::value_parser!(u32).range(1..=2))]
+    part: Option<u32>,
+    /// Run every registered day
+    #[arg(long)]
+    all: bool,
+    /// Read the input from this file, or from stdin if it is -
+    #[arg(long, value_name = "PATH", conflicts_with = "all")]
+    input: Option<String>,
+    /// Record the answers as the accepted ones
+    #[arg(long)]
+    record: bool,
+    /// Print a description of the parsed input, like a graph of it, instead of running the parts
+    #[arg(long, conflicts_with_all = ["all", "part", "record"])]
+    explain: bool,
+}
+
+#[derive(Args, Debug, PartialEq)]
+struct BenchArgs {
+    /// The day to time
+    #[arg(required_unless_present = "all", conflicts_with = "all")]
+    day: Option<u32>,
+    /// Only time one of the parts
+    #[arg(long, value_parser = clap::value_parser!(u32).range(1..=2))]
+    part: Option<u32>,
+    /// Time every registered day that has an input
+    #[arg(long)]
+    all: bool,
+    /// Read the input from this file, or from stdin if it is -
+    #[arg(long, value_name = "PATH", conflicts_with = "all")]
+    input: Option<String>,
+    /// How many times to run each part
+    #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+    runs: u32,
+    /// How to print the timings
+    #[arg(long, value_enum, default_value_t = Format::Table)]
+    format: Format,
+    /// Compare the timings with a baseline saved by an earlier run
+    #[arg(long, value_name = "PATH")]
+    baseline: Option<PathBuf>,
+    /// Save the timings as a baseline for later runs
+    #[arg(long, value_name = "PATH")]
+    save_baseline: Option<PathBuf>,
+    /// How many percent slower than the baseline a median can get before it's a regression
+    #[arg(long, default_value_t = 10.0, requires = "baseline")]
+    threshold: f64,
+}
+
+#[derive(Args, Debug, PartialEq)]
+struct FetchArgs {
+    /// The day to fetch
+    #[arg(required_unless_present = "all", conflicts_with = "all")]
+    day: Option<u32>,
+    /// Fetch every registered day
+    #[arg(long)]
+    all: bool,
+    /// The config with the session token, defaults to advent.toml in the workspace
+    #[arg(long, value_name = "PATH")]
+    config: Option<PathBuf>,
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq)]
+enum Format {
+    Table,
+    Json,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let answers_path = cli.answers.unwrap_or_else(answers::default_path);
+
+    let result = match cli.command {
+        Command::Run(args) => run(&args, &answers_path),
+        Command::Verify => verify(&answers_path),
+        Command::Bench(args) => bench(&args),
+        Command::List => {
+            print!("{}", list(&days::days()));
+            Ok(())
+        }
+        Command::New { day } => new(day),
+        Command::Fetch(args) => fetch(&args),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("{}", err.render());
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(args: &RunArgs, answers_path: &Path) -> Result<()> {
+    let parts = args.part.map_or(vec![1, 2], |part| vec![part]);
+    let days = select_days(args.day, args.all)?;
+    if args.explain {
+        return days
+            .iter()
+            .try_for_each(|day| explain(day, args.input.as_deref()));
+    }
+    let mut answers = if args.record {
+        Some(Answers::load(answers_path)?)
+    } else {
+        None
+    };
+
+    // The days can run at the same time, so each one writes its output to print once it's done,
+    // in the order of the days
+    let runs = parallel::map(&days, |day| {
+        let mut output = String::new();
+        let solved = run_day(day, &parts, args.input.as_deref(), &mut output);
+        (output, solved)
+    });
+
+    // Keep going when a day fails, so one missing input doesn't stop the rest from running
+    let mut failed = Vec::new();
+    for (day, (output, solved)) in days.iter().zip(runs) {
+        print!("{}", output);
+        match solved {
+            Ok(solved) => {
+                if let Some(answers) = answers.as_mut() {
+                    for (part, answer) in solved {
+                        answers.record(day.number, part, answer.to_string());
+                    }
+                }
+            }
+            Err(err) => failed.push(err),
+        }
+    }
+
+    if let Some(answers) = answers {
+        answers.save(answers_path)?;
+        println!("Recorded answers in {}", answers_path.display());
+    }
+
+    match failed.len() {
+        0 => Ok(()),
+        1 if days.len() == 1 => Err(failed.remove(0)),
+        _ => {
+            for err in &failed {
+                eprintln!("{}", err.render());
+            }
+            Err(error!("{} of {} days failed", failed.len(), days.len()))
+        }
+    }
+}
+
+/// The days to run, either every registered day or a single one
+fn select_days(day: Option<u32>, all: bool) -> Result<Vec<Day>> {
+    if all {
+        return Ok(days::days());
+    }
+    let number = day.ok_or(error!("No day given"))?;
+    Ok(vec![
+        days::find(number).ok_or(error!("Day {} is not registered", number))?
+    ])
+}
+
+/// Run the parts of a day, writing out and returning the answers of the implemented ones
+fn run_day(
+    day: &Day,
+    parts: &[u32],
+    input: Option<&str>,
+    output: &mut String,
+) -> Result<Vec<(u32, Answer)>> {
+    // Writing to a string can't fail
+    let _ = writeln!(output, "# Day {}", day.number);
+    let input = InputSource::resolve(day.number, input).read(day.number)?;
+    let parsed = parse_input(day, &input)?;
+
+    let mut solved = Vec::new();
+    for &part in parts {
+        let _ = writeln!(output, "## Part {}", part);
+        match run_part(day, part, parsed.as_ref())
+            .with_context(|| format!("day {} part {}", day.number, part))?
+        {
+            Some(answer) => {
+                let _ = writeln!(output, " > {}", answer);
+                solved.push((part, answer));
+            }
+            None => {
+                let _ = writeln!(output, " > not implemented");
+            }
+        }
+    }
+
+    Ok(solved)
+}
+
+/// Print the description a day gives of its parsed input
+fn explain(day: &Day, input: Option<&str>) -> Result<()> {
+    let input = InputSource::resolve(day.number, input).read(day.number)?;
+    let parsed = parse_input(day, &input)?;
+    match (day.explain)(parsed.as_ref())
+        .with_context(|| format!("day {} explanation", day.number))?
+    {
+        Some(explanation) => {
+            println!("{}", explanation.trim_end());
+            Ok(())
+        }
+        None => Err(error!("Day {} has nothing to explain", day.number)),
+    }
+}
+
+/// Parse the input of a day, to be shared by its parts
+fn parse_input(day: &Day, input: &str) -> Result<days::Parsed> {
+    (day.parse)(input).with_context(|| format!("day {} input", day.number))
+}
+
+/// Run a part of a day on the parsed input, or None if the part isn't implemented yet
+fn run_part(day: &Day, part: u32, parsed: &dyn Any) -> Result<Option<Answer>> {
+    day.part(part)
+        .map(|solve| solve(parsed, &Params::default()))
+        .transpose()
+}
+
+/// The outcome of verifying a part of a day
+#[derive(Debug)]
+enum Verdict {
+    Checked { answer: Answer, status: Status },
+    Unimplemented,
+    NoInput,
+    Failed(AdventError),
+}
+
+impl Verdict {
+    /// If the verdict should fail the verification
+    fn is_failure(&self) -> bool {
+        matches!(
+            self,
+            Verdict::Checked {
+                status: Status::Mismatch { .. },
+                ..
+            } | Verdict::Failed(_)
+        )
+    }
+}
+
+fn verify(answers_path: &Path) -> Result<()> {
+    let answers = Answers::load(answers_path)?;
+
+    let days = days::days();
+    let verdicts: Vec<_> = parallel::map(&days, |day| {
+        let input = InputSource::resolve(day.number, None).read(day.number);
+        verify_day(day, input, &answers)
+    })
+    .flatten()
+    .collect();
+    print!("{}", verdict_table(&verdicts));
+
+    let failures = verdicts
+        .iter()
+        .filter(|(_, _, verdict)| verdict.is_failure())
+        .count();
+    if failures > 0 {
+        return Err(error!("{} parts failed verification", failures));
+    }
+    Ok(())
+}
+
+/// Run both parts of a day and compare them with the recorded answers
+fn verify_day(day: &Day, input: Result<String>, answers: &Answers) -> Vec<(u32, u32, Verdict)> {
+    let input = match input {
+        Ok(input) => input,
+        Err(AdventError::MissingInput { .. }) => {
+            return (1..=2)
+                .map(|part| (day.number, part, Verdict::NoInput))
+                .collect()
+        }
+        Err(err) => return vec![(day.number, 1, Verdict::Failed(err))],
+    };
+    let parsed = match parse_input(day, &input) {
+        Ok(parsed) => parsed,
+        Err(err) => return vec![(day.number, 1, Verdict::Failed(err))],
+    };
+
+    (1..=2)
+        .map(|part| {
+            let verdict = match run_part(day, part, parsed.as_ref()) {
+                Ok(Some(answer)) => Verdict::Checked {
+                    status: answers.check(day.number, part, &answer.to_string()),
+                    answer,
+                },
+                Ok(None) => Verdict::Unimplemented,
+                Err(err) => Verdict::Failed(err),
+            };
+            (day.number, part, verdict)
+        })
+        .collect()
+}
+
+/// A table of the verdicts, one part per row
+fn verdict_table(verdicts: &[(u32, u32, Verdict)]) -> String {
+    let mut output = String::from("Day  Part  Status         Answer\n");
+    for (day, part, verdict) in verdicts {
+        let (status, detail) = match verdict {
+            Verdict::Checked {
+                answer,
+                status: Status::Mismatch { expected },
+            } => (
+                "mismatch".to_string(),
+                format!("{} (expected {})", answer, expected),
+            ),
+            Verdict::Checked { answer, status } => (status.to_string(), answer.to_string()),
+            Verdict::Unimplemented => ("unimplemented".to_string(), String::new()),
+            Verdict::NoInput => ("no input".to_string(), String::new()),
+            Verdict::Failed(err) => ("error".to_string(), err.to_string()),
+        };
+        output.push_str(format!("{:>3}  {:>4}  {:<13}  {}", day, part, status, detail).trim_end());
+        output.push('\n');
+    }
+    output
+}
+
+fn bench(args: &BenchArgs) -> Result<()> {
+    let parts = args.part.map_or(vec![1, 2], |part| vec![part]);
+    let baseline = args.baseline.as_deref().map(Report::load).transpose()?;
+
+    let mut report = Report::default();
+    for day in select_days(args.day, args.all)? {
+        let input = match InputSource::resolve(day.number, args.input.as_deref()).read(day.number) {
+            Ok(input) => input,
+            // Timing every day shouldn't need every input
+            Err(AdventError::MissingInput { .. }) if args.all => {
+                eprintln!("Skipping day {}, it has no input", day.number);
+                continue;
+            }
+            Err(err) => return Err(err),
+        };
+        let measurements = bench::bench_day(&day, &parts, &input, args.runs as usize)?;
+        report.measurements.extend(measurements);
+    }
+
+    match args.format {
+        Format::Table => print!("{}", report.table(baseline.as_ref(), args.threshold)),
+        Format::Json => println!("{}", report.to_json()),
+    }
+
+    if let Some(path) = &args.save_baseline {
+        report.save(path)?;
+        eprintln!("Saved the baseline in {}", path.display());
+    }
+
+    let regressions = baseline.map_or(0, |baseline| {
+        report.regressions(&baseline, args.threshold).len()
+    });
+    if regressions > 0 {
+        return Err(error!(
+            "{} timings regressed by more than {}%",
+            regressions, args.threshold
+        ));
+    }
+    Ok(())
+}
+
+fn new(day: u32) -> Result<()> {
+    let root = scaffold::workspace_root();
+    for path in scaffold::new_day(&root, day)? {
+        println!(
+            "Wrote {}",
+            path.strip_prefix(&root).unwrap_or(&path).display()
+        );
+    }
+    Ok(())
+}
+
+fn fetch(args: &FetchArgs) -> Result<()> {
+    // Unlike running, a single day can be fetched before it has been created
+    let days: Vec<_> = if args.all {
+        days::days().iter().map(|day| day.number).collect()
+    } else {
+        args.day.into_iter().collect()
+    };
+    let config_path = args
+        .config
+        .clone()
+        .unwrap_or_else(fetch::default_config_path);
+    let fetcher = Fetcher::new(
+        Config::load(&config_path)?,
+        fetch::Ureq::new(),
+        fetch::default_stamp_path(),
+    );
+
+    let inputs_dir = input::inputs_dir();
+    for day in days {
+        let path = input::stored_path(day, inputs_dir.as_deref());
+        match fetcher
+            .fetch(day, &path)
+            .with_context(|| format!("fetching day {}", day))?
+        {
+            Fetched::Cached => println!("Day {} is already in {}", day, path.display()),
+            Fetched::Downloaded => println!("Fetched day {} into {}", day, path.display()),
+        }
+    }
+    Ok(())
+}
+
+/// A table of the registered days, showing which parts are implemented
+fn list(days: &[Day]) -> String {
+    let status = |day: &Day, part| match day.part(part) {
+        Some(_) => "done",
+        None => "-",
+    };
+
+    let mut output = String::from("Day  Part 1  Part 2\n");
+    for day in days {
+        output.push_str(&format!(
+            "{:>3}  {:<6}  {}\n",
+            day.number,
+            status(day, 1),
+            status(day, 2)
+        ));
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> std::result::Result<Command, clap::Error> {
+        Cli::try_parse_from(std::iter::once("advent").chain(args.iter().copied()))
+            .map(|cli| cli.command)
+    }
+
+    #[test]
+    fn test_parse_run() {
+        assert_eq!(
+            parse(&["run", "17"]).unwrap(),
+            Command::Run(RunArgs {
+                day: Some(17),
+                part: None,
+                all: false,
+                input: None,
+                record: false,
+                explain: false,
+            })
+        );
+        assert_eq!(
+            parse(&["run", "17", "--part", "2", "--input", "-"]).unwrap(),
+            Command::Run(RunArgs {
+                day: Some(17),
+                part: Some(2),
+                all: false,
+                input: Some("-".to_string()),
+                record: false,
+                explain: false,
+            })
+        );
+        assert_eq!(
+            parse(&["run", "--all", "--record"]).unwrap(),
+            Command::Run(RunArgs {
+                day: None,
+                part: None,
+                all: true,
+                input: None,
+                record: true,
+                explain: false,
+            })
+        );
+        assert_eq!(
+            parse(&["run", "20", "--explain"]).unwrap(),
+            Command::Run(RunArgs {
+                day: Some(20),
+                part: None,
+                all: false,
+                input: None,
+                record: false,
+                explain: true,
+            })
+        );
+        assert_eq!(parse(&["list"]).unwrap(), Command::List);
+        assert_eq!(parse(&["new", "21"]).unwrap(), Command::New { day: 21 });
+        assert_eq!(
+            parse(&["fetch", "21", "--config", "config.toml"]).unwrap(),
+            Command::Fetch(FetchArgs {
+                day: Some(21),
+                all: false,
+                config: Some(PathBuf::from("config.toml")),
+            })
+        );
+        assert_eq!(
+            parse(&["verify", "--answers", "answers.toml"]).unwrap(),
+            Command::Verify
+        );
+    }
+
+    #[test]
+    fn test_parse_run_invalid() {
+        assert!(parse(&["run"]).is_err());
+        assert!(parse(&["run", "17", "--all"]).is_err());
+        assert!(parse(&["run", "17", "--part", "3"]).is_err());
+        assert!(parse(&["run", "--all", "--input", "input.txt"]).is_err());
+        assert!(parse(&["run", "--all", "--explain"]).is_err());
+        assert!(parse(&["run", "20", "--part", "1", "--explain"]).is_err());
+        assert!(parse(&["fetch"]).is_err());
+        assert!(parse(&["fetch", "3", "--all"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_bench() {
+        assert_eq!(
+            parse(&["bench", "5"]).unwrap(),
+            Command::Bench(BenchArgs {
+                day: Some(5),
+                part: None,
+                all: false,
+                input: None,
+                runs: 10,
+                format: Format::Table,
+                baseline: None,
+                save_baseline: None,
+                threshold: 10.0,
+            })
+        );
+        assert_eq!(
+            parse(&[
+                "bench",
+                "--all",
+                "--runs",
+                "3",
+                "--format",
+                "json",
+                "--baseline",
+                "before.json",
+                "--threshold",
+                "25",
+                "--save-baseline",
+                "after.json",
+            ])
+            .unwrap(),
+            Command::Bench(BenchArgs {
+                day: None,
+                part: None,
+                all: true,
+                input: None,
+                runs: 3,
+                format: Format::Json,
+                baseline: Some(PathBuf::from("before.json")),
+                save_baseline: Some(PathBuf::from("after.json")),
+                threshold: 25.0,
+            })
+        );
+        assert!(parse(&["bench", "5", "--runs", "0"]).is_err());
+        assert!(parse(&["bench", "5", "--threshold", "5"]).is_err());
+    }
+
+    #[test]
+    fn test_run_part() {
+        let registered = days::find(1).unwrap();
+        let day = Day {
+            parts: [registered.parts[0], None],
+            ..registered
+        };
+        let parsed = parse_input(&day, "1abc2\npqr3stu8vwx").unwrap();
+
+        assert_eq!(run_part(&day, 1, parsed.as_ref()).unwrap(), Some(50.into()));
+        assert_eq!(run_part(&day, 2, parsed.as_ref()).unwrap(), None);
+    }
+
+    #[test]
+    fn test_list() {
+        let days: Vec<_> = days::days().into_iter().skip(18).collect();
+
+        assert_eq!(
+            list(&days),
+            "Day  Part 1  Part 2\n 19  done    done\n 20  done    done\n"
+        );
+    }
+
+    #[test]
+    fn test_verify_day() {
+        let day = days::find(1).unwrap();
+        let mut answers = Answers::default();
+        answers.record(1, 1, "89");
+        answers.record(1, 2, "1");
+
+        let verdicts = verify_day(&day, Ok("1abc2\ntreb7uchet".to_string()), &answers);
+
+        assert_eq!(
+            verdict_table(&verdicts),
+            [
+                "Day  Part  Status         Answer",
+                "  1     1  match          89",
+                "  1     2  mismatch       89 (expected 1)",
+                "",
+            ]
+            .join("\n")
+        );
+        assert!(!verdicts[0].2.is_failure());
+        assert!(verdicts[1].2.is_failure());
+    }
+
+    #[test]
+    fn test_verify_day_without_input_or_answers() {
+        let day = days::find(20).unwrap();
+        let missing = Err(AdventError::MissingInput {
+            day: 20,
+            path: PathBuf::from("prob20/input.txt"),
+        });
+
+        let verdicts = verify_day(&day, missing, &Answers::default());
+        assert_eq!(
+            verdict_table(&verdicts),
+            "Day  Part  Status         Answer\n 20     1  no input\n 20     2  no input\n"
+        );
+        assert!(verdicts.iter().all(|(_, _, verdict)| !verdict.is_failure()));
+
+        let verdicts = verify_day(
+            &day,
+            Ok("broadcaster -> a\n%a -> inv\n&inv -> rx".to_string()),
+            &Answers::default(),
+        );
+        assert!(verdicts.iter().all(|(_, _, verdict)| matches!(
+            verdict,
+            Verdict::Checked {
+                status: Status::Missing,
+                ..
+            }
+        )));
+    }
+}
@@ -0,0 +1,323 @@
+use advent::prelude::*;
+use std::fs;
+use std::path::{Path, PathBuf};
+use toml_edit::{DocumentMut, InlineTable};
+
+/// The root of the workspace, where the days and the template are
+pub fn workspace_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .unwrap_or(Path::new(".."))
+        .to_path_buf()
+}
+
+/// Create a new day from the template and register it in the workspace and with the runner
+///
+/// Nothing is written until everything that needs updating has been read and checked, so a day
+/// that already exists or a manifest that can't be updated leaves the workspace as it was.
+/// Returns the files that were created or changed.
+pub fn new_day(root: &Path, day: u32) -> Result<Vec<PathBuf>> {
+    let name = format!("prob{}", day);
+    let dir = root.join(&name);
+    if dir.exists() {
+        return Err(error!("{} already exists", dir.display()));
+    }
+
+    let workspace_path = root.join("Cargo.toml");
+    let runner_path = root.join("runner").join("Cargo.toml");
+    let registry_path = root.join("runner").join("src").join("days.rs");
+
+    let workspace = add_member(&read(&workspace_path)?, &name)
+        .with_context(|| format!("updating {}", workspace_path.display()))?;
+    let runner = add_dependency(&read(&runner_path)?, &name)
+        .with_context(|| format!("updating {}", runner_path.display()))?;
+    let registry = register_day(&read(&registry_path)?, day)
+        .with_context(|| format!("updating {}", registry_path.display()))?;
+
+    let mut written = copy_template(&root.join(".template"), &dir)?;
+    let manifest = dir.join("Cargo.toml");
+    fs::write(&manifest, day_manifest(&name))?;
+    written.push(manifest);
+
+    for (path, contents) in [
+        (workspace_path, workspace),
+        (runner_path, runner),
+        (registry_path, registry),
+    ] {
+        fs::write(&path, contents)?;
+        written.push(path);
+    }
+
+    Ok(written)
+}
+
+fn read(path: &Path) -> Result<String> {
+    fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))
+}
+
+/// The manifest of a new day, which only needs the core crate to begin with
+fn day_manifest(name: &str) -> String {
+    format!(
+        "[package]\n\
+         name = \"{}\"\n\
+         version = \"0.1.0\"\n\
+         edition = \"2021\"\n\
+         \n\
+         # See more keys and their definitions at https://doc.rust-lang.org/cargo/reference/manifest.html\n\
+         \n\
+         [dependencies]\n\
+         advent = {{ path = \"../core\" }}\n",
+        name
+    )
+}
+
+/// Copy the template into a new directory, leaving out the input since it's never committed
+fn copy_template(template: &Path, dir: &Path) -> Result<Vec<PathBuf>> {
+    let entries = fs::read_dir(template)
+        .with_context(|| format!("reading the template in {}", template.display()))?;
+    fs::create_dir_all(dir)?;
+
+    let mut created = Vec::new();
+    for entry in entries {
+        let entry = entry?;
+        let target = dir.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            created.extend(copy_template(&entry.path(), &target)?);
+        } else if entry.file_name() != "input.txt" {
+            fs::copy(entry.path(), &target)?;
+            created.push(target);
+        }
+    }
+    created.sort();
+    Ok(created)
+}
+
+/// The day number of a crate called `probN`
+fn day_number(name: &str) -> Option<u32> {
+    name.strip_prefix("prob")?.parse().ok()
+}
+
+/// Add a day to the members of the workspace manifest, keeping the days in order
+fn add_member(manifest: &str, name: &str) -> Result<String> {
+    let mut document: DocumentMut = manifest
+        .parse()
+        .map_err(|e: toml_edit::TomlError| parse_error!(e))?;
+    let members = document["workspace"]["members"]
+        .as_array_mut()
+        .ok_or(parse_error!("Expected a list of workspace members"))?;
+
+    if members.iter().any(|member| member.as_str() == Some(name)) {
+        return Err(error!("{} is already a workspace member", name));
+    }
+
+    let day = day_number(name);
+    let names: Vec<_> = members.iter().map(|member| member.as_str()).collect();
+    // Either before the first later day, or after the last day there is
+    let index = names
+        .iter()
+        .position(|member| member.and_then(day_number) > day)
+        .or_else(|| {
+            names
+                .iter()
+                .rposition(|member| member.and_then(day_number).is_some())
+                .map(|index| index + 1)
+        })
+        .unwrap_or(names.len());
+
+    // Lay the new member out like its neighbour, which is usually on a line of its own
+    let neighbour = members.get(index.saturating_sub(1)).or(members.get(index));
+    let decor = neighbour.map(|value| value.decor().clone());
+    members.insert(index, name);
+    if let (Some(decor), Some(member)) = (decor, members.get_mut(index)) {
+        *member.decor_mut() = decor;
+    }
+
+    Ok(document.to_string())
+}
+
+/// Add a day as a dependency of the runner
+fn add_dependency(manifest: &str, name: &str) -> Result<String> {
+    let mut document: DocumentMut = manifest
+        .parse()
+        .map_err(|e: toml_edit::TomlError| parse_error!(e))?;
+    let dependencies = document["dependencies"]
+        .as_table_mut()
+        .ok_or(parse_error!("Expected a table of dependencies"))?;
+
+    if dependencies.contains_key(name) {
+        return Err(error!("{} is already a dependency", name));
+    }
+
+    let mut dependency = InlineTable::new();
+    dependency.insert("path", format!("../{}", name).into());
+    dependencies.insert(name, toml_edit::value(dependency));
+
+    Ok(document.to_string())
+}
+
+/// Add a day to the registry in `days.rs`, before the first later day if there is one
+///
+/// Neither part is implemented yet, so the day is registered with both listed as `unimplemented`
+/// until they are.
+fn register_day(registry: &str, day: u32) -> Result<String> {
+    let start = registry
+        .find("pub fn days()")
+        .ok_or(parse_error!("Unable to find the days() function"))?;
+    let end = registry[start..]
+        .find("\n    ]")
+        .map(|idx| start + idx + 1)
+        .ok_or(parse_error!("Unable to find the end of the list of days"))?;
+
+    let registered = |line: &str| -> Option<u32> {
+        let (number, _) = line.trim().strip_prefix("day!(")?.split_once(',')?;
+        number.parse().ok()
+    };
+
+    let mut offset = start;
+    let mut comment = None;
+    let mut insert_at = end;
+    for line in registry[start..end].split_inclusive('\n') {
+        match registered(line) {
+            Some(number) if number == day => {
+                return Err(error!("Day {} is already registered", day));
+            }
+            Some(number) if number > day => {
+                // Any comment above the later day belongs to it, so the new day goes above that
+                insert_at = comment.unwrap_or(offset);
+                break;
+            }
+            _ if line.trim().starts_with("//") => {
+                comment.get_or_insert(offset);
+            }
+            _ => comment = None,
+        }
+        offset += line.len();
+    }
+
+    Ok(format!(
+        "{}        day!({}, prob{}, unimplemented: part1, part2),\n{}",
+        &registry[..insert_at],
+        day,
+        day,
+        &registry[insert_at..]
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WORKSPACE: &str =
+        "[workspace]\nresolver = \"2\"\n\nmembers = [\n  \"core\",\n  \"prob1\",\n  \"prob3\",\n  \"runner\",\n]\n";
+    const RUNNER: &str =
+        "[package]\nname = \"runner\"\n\n[dependencies]\nadvent = { path = \"../core\" }\nprob1 = { path = \"../prob1\" }\n";
+    const REGISTRY: &str = "pub fn days() -> Vec<Day> {\n    vec![\n        day!(1, prob1),\n        // Part 2 isn't done\n        day!(3, prob3, unimplemented: part2),\n    ]\n}\n";
+
+    /// A workspace with a template, the manifests and the registry
+    fn workspace() -> tempfile::TempDir {
+        let root = tempfile::tempdir().unwrap();
+        let path = root.path();
+        fs::create_dir_all(path.join(".template/src")).unwrap();
+        fs::write(path.join(".template/src/lib.rs"), "pub struct Day;\n").unwrap();
+        fs::write(path.join(".template/test.txt"), "").unwrap();
+        fs::write(path.join(".template/input.txt"), "").unwrap();
+        fs::create_dir_all(path.join("runner/src")).unwrap();
+        fs::write(path.join("Cargo.toml"), WORKSPACE).unwrap();
+        fs::write(path.join("runner/Cargo.toml"), RUNNER).unwrap();
+        fs::write(path.join("runner/src/days.rs"), REGISTRY).unwrap();
+        fs::create_dir_all(path.join("prob1")).unwrap();
+        root
+    }
+
+    #[test]
+    fn test_new_day() {
+        let root = workspace();
+        let path = root.path();
+
+        let written = new_day(path, 4).unwrap();
+
+        assert_eq!(
+            written,
+            vec![
+                path.join("prob4/src/lib.rs"),
+                path.join("prob4/test.txt"),
+                path.join("prob4/Cargo.toml"),
+                path.join("Cargo.toml"),
+                path.join("runner/Cargo.toml"),
+                path.join("runner/src/days.rs"),
+            ]
+        );
+        assert!(!path.join("prob4/input.txt").exists());
+        assert_eq!(
+            fs::read_to_string(path.join("prob4/src/lib.rs")).unwrap(),
+            "pub struct Day;\n"
+        );
+
+        let manifest = fs::read_to_string(path.join("prob4/Cargo.toml")).unwrap();
+        assert!(manifest.starts_with("[package]\nname = \"prob4\"\n"));
+        assert!(manifest.ends_with("[dependencies]\nadvent = { path = \"../core\" }\n"));
+
+        let workspace = fs::read_to_string(path.join("Cargo.toml")).unwrap();
+        assert!(workspace.contains("  \"prob3\",\n  \"prob4\",\n  \"runner\",\n]"));
+
+        let runner = fs::read_to_string(path.join("runner/Cargo.toml")).unwrap();
+        assert!(runner.ends_with("prob4 = { path = \"../prob4\" }\n"));
+
+        let registry = fs::read_to_string(path.join("runner/src/days.rs")).unwrap();
+        assert!(registry
+            .contains("part2),\n        day!(4, prob4, unimplemented: part1, part2),\n    ]\n}"));
+    }
+
+    #[test]
+    fn test_new_day_already_exists() {
+        let root = workspace();
+
+        let err = new_day(root.path(), 1).unwrap_err();
+
+        assert!(err.to_string().ends_with("prob1 already exists"));
+        assert_eq!(
+            fs::read_to_string(root.path().join("Cargo.toml")).unwrap(),
+            WORKSPACE
+        );
+    }
+
+    #[test]
+    fn test_new_day_already_registered() {
+        let root = workspace();
+
+        // prob3 is in the manifests, but its directory is missing
+        let err = new_day(root.path(), 3).unwrap_err();
+
+        assert!(err
+            .to_string()
+            .contains("prob3 is already a workspace member"));
+        assert!(!root.path().join("prob3").exists());
+    }
+
+    #[test]
+    fn test_add_member_in_order() {
+        let workspace = add_member(WORKSPACE, "prob2").unwrap();
+
+        assert!(workspace.contains("  \"prob1\",\n  \"prob2\",\n  \"prob3\",\n"));
+    }
+
+    #[test]
+    fn test_register_day_in_order() {
+        let registry = register_day(REGISTRY, 2).unwrap();
+
+        assert_eq!(
+            registry,
+            "pub fn days() -> Vec<Day> {\n    vec![\n        day!(1, prob1),\n        day!(2, prob2, unimplemented: part1, part2),\n        // Part 2 isn't done\n        day!(3, prob3, unimplemented: part2),\n    ]\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_register_day_in_runner() {
+        let registry = register_day(include_str!("days.rs"), 21).unwrap();
+
+        assert!(registry.contains(
+            "day!(20, prob20),\n        day!(21, prob21, unimplemented: part1, part2),\n    ]"
+        ));
+        assert!(register_day(include_str!("days.rs"), 20).is_err());
+    }
+}
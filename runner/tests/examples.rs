@@ -0,0 +1,46 @@
+//! Runs the examples listed in each day's `examples.toml`, as a test for each example
+//!
+//! Filter them like any other tests, e.g. `cargo test -p runner --test examples -- prob11::`
+
+use libtest_mimic::{Arguments, Trial};
+use runner::days;
+use runner::examples::{Examples, MANIFEST};
+use std::path::Path;
+
+fn main() {
+    let args = Arguments::from_args();
+    // The runner is in the root of the workspace, next to the days
+    let workspace = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .unwrap_or(Path::new(".."));
+
+    let mut trials = Vec::new();
+    for day in days::days() {
+        let dir = workspace.join(format!("prob{}", day.number));
+        match Examples::load(&dir) {
+            Ok(examples) => {
+                for (name, example) in examples.named() {
+                    let dir = dir.clone();
+                    // A new day's examples wait for its parts to be implemented
+                    let unimplemented = day.part(example.part).is_none();
+                    trials.push(
+                        Trial::test(format!("prob{}::{}", day.number, name), move || {
+                            example.check(&day, &dir).map_err(|e| e.render().into())
+                        })
+                        .with_ignored_flag(unimplemented),
+                    );
+                }
+            }
+            // A broken manifest fails as a test of its own, rather than stopping the rest
+            Err(err) => {
+                let message = err.render();
+                trials.push(Trial::test(
+                    format!("prob{}::{}", day.number, MANIFEST),
+                    move || Err(message.into()),
+                ));
+            }
+        }
+    }
+
+    libtest_mimic::run(&args, trials).exit();
+}
@@ -0,0 +1,231 @@
+//! Feeds every day's parser arbitrary input and mutations of its examples, checking that bad input
+//! is always an error rather than a panic, an overflow or a parser that never finishes. Whatever
+//! parses is run through the parts as well, which have to hold up to the same inputs.
+//!
+//! Each day gets a test for each kind of input, e.g. `prob7::mutated_examples`. Set
+//! `PROPTEST_CASES` to run more cases than the default 256.
+
+use advent::prelude::Params;
+use libtest_mimic::{Arguments, Failed, Trial};
+use proptest::collection::vec;
+use proptest::prelude::*;
+use proptest::sample::{select, Index};
+use proptest::test_runner::{Config, TestCaseError, TestError, TestRunner};
+use runner::days::{self, Day};
+use runner::examples::Examples;
+use std::path::Path;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::time::Duration;
+
+/// How long a parser or a part gets before it's considered stuck
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+/// A change to an example input, aimed at the edge cases parsers tend to miss
+#[derive(Debug, Clone)]
+enum Mutation {
+    /// Remove a run of characters
+    Delete(Index, usize),
+    /// Insert any character
+    Insert(Index, char),
+    /// Copy a character from elsewhere in the input, so it's one the parser knows about
+    Copy(Index, Index),
+    /// Repeat a line
+    DuplicateLine(Index),
+    /// Swap two lines
+    SwapLines(Index, Index),
+    /// Replace a number with one that is out of range, negative or zero
+    Number(Index, i128),
+    /// Cut the input short
+    Truncate(Index),
+}
+
+fn mutation() -> impl Strategy<Value = Mutation> {
+    let number = prop_oneof![
+        Just(0),
+        Just(-1),
+        Just(i128::from(i64::MAX)),
+        Just(i128::from(u64::MAX) + 1),
+        any::<i128>(),
+        -100..100i128,
+    ];
+    prop_oneof![
+        (any::<Index>(), 1..10usize).prop_map(|(at, len)| Mutation::Delete(at, len)),
+        (any::<Index>(), any::<char>()).prop_map(|(at, c)| Mutation::Insert(at, c)),
+        (any::<Index>(), any::<Index>()).prop_map(|(to, from)| Mutation::Copy(to, from)),
+        any::<Index>().prop_map(Mutation::DuplicateLine),
+        (any::<Index>(), any::<Index>()).prop_map(|(a, b)| Mutation::SwapLines(a, b)),
+        (any::<Index>(), number).prop_map(|(nth, n)| Mutation::Number(nth, n)),
+        any::<Index>().prop_map(Mutation::Truncate),
+    ]
+}
+
+impl Mutation {
+    fn apply(&self, input: &str) -> String {
+        let mut chars: Vec<char> = input.chars().collect();
+        let mut lines: Vec<&str> = input.split('\n').collect();
+        // Positions between characters, including both ends
+        let len = chars.len();
+        let position = |at: &Index| at.index(len + 1);
+
+        match self {
+            Mutation::Delete(at, count) => {
+                let start = position(at);
+                chars.drain(start..(start + count).min(len));
+            }
+            Mutation::Insert(at, c) => chars.insert(position(at), *c),
+            Mutation::Copy(to, from) if len > 0 => chars[to.index(len)] = chars[from.index(len)],
+            Mutation::Copy(..) => {}
+            Mutation::DuplicateLine(line) => {
+                let line = line.index(lines.len());
+                lines.insert(line, lines[line]);
+                return lines.join("\n");
+            }
+            Mutation::SwapLines(a, b) => {
+                let (a, b) = (a.index(lines.len()), b.index(lines.len()));
+                lines.swap(a, b);
+                return lines.join("\n");
+            }
+            Mutation::Number(nth, n) => {
+                let numbers = number_spans(&chars);
+                if !numbers.is_empty() {
+                    let (start, end) = numbers[nth.index(numbers.len())];
+                    chars.splice(start..end, n.to_string().chars());
+                }
+            }
+            Mutation::Truncate(at) => chars.truncate(position(at)),
+        }
+        chars.into_iter().collect()
+    }
+}
+
+/// Where the runs of digits are, as ranges of character positions
+fn number_spans(chars: &[char]) -> Vec<(usize, usize)> {
+    let mut spans = Vec::new();
+    let mut start = None;
+    for (idx, c) in chars.iter().enumerate() {
+        match (c.is_ascii_digit(), start) {
+            (true, None) => start = Some(idx),
+            (false, Some(from)) => {
+                spans.push((from, idx));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(from) = start {
+        spans.push((from, chars.len()));
+    }
+    spans
+}
+
+/// Parse in a thread of its own and run the parts on whatever parses, failing if any of them
+/// panics or doesn't finish in time
+///
+/// Whether the input parses or the parts find an answer doesn't matter, only that they return at all
+fn check_day(day: Day, input: String) -> Result<(), TestCaseError> {
+    // The thread says what it starts on, and None once it's done
+    let (sender, receiver) = mpsc::channel();
+    std::thread::spawn(move || {
+        let _ = sender.send(Some("parser"));
+        if let Ok(parsed) = (day.parse)(&input) {
+            for (number, name) in [(1, "part 1"), (2, "part 2")] {
+                if let Some(solve) = day.part(number) {
+                    let _ = sender.send(Some(name));
+                    let _ = solve(parsed.as_ref(), &Params::default());
+                }
+            }
+        }
+        let _ = sender.send(None);
+    });
+
+    let mut running = "parser";
+    loop {
+        match receiver.recv_timeout(TIMEOUT) {
+            Ok(Some(next)) => running = next,
+            Ok(None) => return Ok(()),
+            Err(RecvTimeoutError::Disconnected) => {
+                return Err(TestCaseError::fail(format!("the {} panicked", running)))
+            }
+            Err(RecvTimeoutError::Timeout) => {
+                return Err(TestCaseError::fail(format!(
+                    "the {} didn't finish within {:?}",
+                    running, TIMEOUT
+                )))
+            }
+        }
+    }
+}
+
+/// Run a property over generated inputs, failing with the smallest input that breaks it
+fn run<S: Strategy<Value = String>>(day: Day, strategy: S) -> Result<(), Failed> {
+    // There is no source file to persist failures next to, so the failing input is reported instead
+    let config = Config {
+        failure_persistence: None,
+        ..Config::default()
+    };
+    let mut runner = TestRunner::new(config);
+    runner
+        .run(&strategy, |input| check_day(day, input))
+        .map_err(|e| match e {
+            TestError::Fail(reason, input) => format!("{} on {:?}", reason, input).into(),
+            TestError::Abort(reason) => reason.to_string().into(),
+        })
+}
+
+fn main() {
+    let args = Arguments::from_args();
+    // The runner is in the root of the workspace, next to the days
+    let workspace = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .unwrap_or(Path::new(".."));
+
+    let mut trials = Vec::new();
+    for day in days::days() {
+        let name = |test: &str| format!("prob{}::{}", day.number, test);
+
+        trials.push(Trial::test(name("arbitrary_bytes"), move || {
+            run(
+                day,
+                vec(any::<u8>(), 0..500)
+                    .prop_map(|bytes| String::from_utf8_lossy(&bytes).into_owned()),
+            )
+        }));
+
+        // The examples show what the parser expects, so most inputs get past the first line
+        let dir = workspace.join(format!("prob{}", day.number));
+        let inputs: Vec<String> = Examples::load(&dir)
+            .and_then(|examples| {
+                examples
+                    .examples
+                    .iter()
+                    .map(|example| example.read_input(&dir))
+                    .collect()
+            })
+            .unwrap_or_default();
+        if inputs.is_empty() {
+            continue;
+        }
+
+        let mut alphabet: Vec<char> = inputs.iter().flat_map(|input| input.chars()).collect();
+        alphabet.sort_unstable();
+        alphabet.dedup();
+        trials.push(Trial::test(name("example_alphabet"), move || {
+            run(
+                day,
+                vec(select(alphabet), 0..500).prop_map(|chars| chars.into_iter().collect()),
+            )
+        }));
+
+        trials.push(Trial::test(name("mutated_examples"), move || {
+            let strategy =
+                (select(inputs), vec(mutation(), 1..8)).prop_map(|(input, mutations)| {
+                    mutations
+                        .iter()
+                        .fold(input, |input, mutation| mutation.apply(&input))
+                });
+            run(day, strategy)
+        }));
+    }
+
+    libtest_mimic::run(&args, trials).exit();
+}